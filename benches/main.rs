@@ -47,6 +47,22 @@ fn ints_python(bench: &mut Bencher) {
     bench.iter(|| black_box(validator.validate_python(py, input, None, None).unwrap()))
 }
 
+#[bench]
+fn str_pattern_python(bench: &mut Bencher) {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    // the regex is compiled once when the validator is built, not on each call to `validate_python`
+    let validator = build_schema_validator(py, r"{'type': 'str', 'pattern': r'^\w+@\w+\.com$'}");
+
+    let input = PyString::new(py, "testing@example.com");
+    let result = validator.validate_python(py, input, None, None).unwrap();
+    let result_str: String = result.extract(py).unwrap();
+    assert_eq!(result_str, "testing@example.com");
+
+    let input = black_box(input);
+    bench.iter(|| black_box(validator.validate_python(py, input, None, None).unwrap()))
+}
+
 #[bench]
 fn list_int_json(bench: &mut Bencher) {
     let gil = Python::acquire_gil();
@@ -445,3 +461,28 @@ fn complete_model(bench: &mut Bencher) {
         black_box(validator.validate_python(py, input, None, None).unwrap());
     })
 }
+
+#[bench]
+fn deeply_nested_clone(bench: &mut Bencher) {
+    let gil = Python::acquire_gil();
+    let py = gil.python();
+    // a model nested 10 levels deep, the validator tree is shared so cloning shouldn't depend on its size
+    let code = r#"
+schema = {'type': 'typed-dict', 'fields': {'x': {'schema': 'int'}}}
+for _ in range(10):
+    schema = {
+        'type': 'typed-dict',
+        'fields': {
+            'x': {'schema': 'int'},
+            'items': {'schema': {'type': 'list', 'items_schema': {'type': 'dict', 'values_schema': 'str'}}},
+            'child': {'schema': {'type': 'nullable', 'schema': schema}},
+        },
+    }
+"#;
+    let locals = PyDict::new(py);
+    py.run(code, None, Some(locals)).unwrap();
+    let schema = locals.get_item("schema").unwrap();
+    let validator = SchemaValidator::py_new(py, schema, None).unwrap();
+
+    bench.iter(|| black_box(validator.clone()))
+}
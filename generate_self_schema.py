@@ -66,7 +66,7 @@ def get_schema(obj):
             'values_schema': get_schema(obj.__args__[1]),
         }
     elif issubclass(origin, Type):
-        # can't really use 'is-instance' since this is used for the class_ parameter of
+        # can't really use 'is-instance' since this is used for the cls parameter of
         # 'is-instance' validators
         return 'any'
     else:
@@ -1,4 +1,22 @@
-from ._pydantic_core import PydanticValueError, SchemaError, SchemaValidator, ValidationError, __version__
+from ._pydantic_core import (
+    ParsedJson,
+    PydanticValueError,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    __version__,
+    parse_json,
+)
 from ._types import Config, Schema
 
-__all__ = '__version__', 'Config', 'Schema', 'SchemaValidator', 'SchemaError', 'ValidationError', 'PydanticValueError'
+__all__ = (
+    '__version__',
+    'Config',
+    'Schema',
+    'SchemaValidator',
+    'SchemaError',
+    'ValidationError',
+    'PydanticValueError',
+    'ParsedJson',
+    'parse_json',
+)
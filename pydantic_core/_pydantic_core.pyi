@@ -1,5 +1,6 @@
 import sys
-from typing import Any, Dict, List, Optional, TypedDict, Union
+from datetime import datetime
+from typing import Any, Dict, Iterator, List, Optional, Tuple, TypedDict, Union
 
 from pydantic_core._types import Config, Schema
 
@@ -8,36 +9,98 @@ if sys.version_info < (3, 11):
 else:
     from typing import NotRequired
 
-__all__ = '__version__', 'SchemaValidator', 'SchemaError', 'ValidationError', 'PydanticValueError'
+__all__ = (
+    '__version__',
+    'SchemaValidator',
+    'ParsedJson',
+    'parse_json',
+    'list_error_codes',
+    'SchemaError',
+    'ValidationError',
+    'PydanticValueError',
+)
 __version__: str
 
 class SchemaValidator:
     def __init__(self, schema: Schema, config: Optional[Config] = None) -> None: ...
-    def validate_python(self, input: Any, strict: Optional[bool] = None, context: Any = None) -> Any: ...
-    def isinstance_python(self, input: Any, strict: Optional[bool] = None, context: Any = None) -> bool: ...
+    def validate_python(
+        self, input: Any, strict: Optional[bool] = None, context: Any = None, now: Union[datetime, float, None] = None
+    ) -> Any: ...
+    def isinstance_python(
+        self, input: Any, strict: Optional[bool] = None, context: Any = None, now: Union[datetime, float, None] = None
+    ) -> bool: ...
     def validate_json(
-        self, input: Union[str, bytes, bytearray], strict: Optional[bool] = None, context: Any = None
+        self,
+        input: Union[str, bytes, bytearray],
+        strict: Optional[bool] = None,
+        context: Any = None,
+        now: Union[datetime, float, None] = None,
     ) -> Any: ...
+    def validate_json_with_warnings(
+        self,
+        input: Union[str, bytes, bytearray],
+        strict: Optional[bool] = None,
+        context: Any = None,
+        now: Union[datetime, float, None] = None,
+    ) -> Tuple[Any, List[ValidationWarning]]: ...
     def isinstance_json(
-        self, input: Union[str, bytes, bytearray], strict: Optional[bool] = None, context: Any = None
+        self,
+        input: Union[str, bytes, bytearray],
+        strict: Optional[bool] = None,
+        context: Any = None,
+        now: Union[datetime, float, None] = None,
     ) -> bool: ...
+    def validate_json_iter(
+        self,
+        input: Union[str, bytes, bytearray],
+        strict: Optional[bool] = None,
+        context: Any = None,
+        now: Union[datetime, float, None] = None,
+    ) -> JsonArrayIterator: ...
+    def validate_parsed_json(
+        self,
+        input: ParsedJson,
+        strict: Optional[bool] = None,
+        context: Any = None,
+        now: Union[datetime, float, None] = None,
+    ) -> Any: ...
     def validate_assignment(self, field: str, input: Any, data: Dict[str, Any]) -> Dict[str, Any]: ...
 
-class SchemaError(Exception):
+class JsonArrayIterator(Iterator[Any]):
+    def __next__(self) -> Any: ...
+
+class ParsedJson: ...
+
+def parse_json(input: Union[str, bytes, bytearray], reject_duplicate_keys: bool = False) -> ParsedJson: ...
+
+def list_error_codes() -> Dict[str, str]: ...
+
+class SchemaError(ValueError):
     pass
 
 class ErrorDetails(TypedDict):
     kind: str
-    loc: List[Union[int, str]]
+    loc: Tuple[Union[int, str], ...]
     message: str
     input_value: Any
     context: NotRequired[Dict[str, Any]]
 
+class ValidationWarning(TypedDict):
+    loc: Tuple[Union[int, str], ...]
+    message: str
+    input_value: Any
+
 class ValidationError(ValueError):
     title: str
 
     def error_count(self) -> int: ...
-    def errors(self) -> List[ErrorDetails]: ...
+    def errors(self, max_input_length: Optional[int] = 200) -> List[ErrorDetails]: ...
+    def grouped_errors(
+        self, max_input_length: Optional[int] = 200
+    ) -> Dict[Union[int, str, None], List[ErrorDetails]]: ...
+    def json(
+        self, indent: Optional[int] = None, include_input: bool = True, max_input_length: Optional[int] = 200
+    ) -> str: ...
 
 class PydanticValueError(ValueError):
     kind: str
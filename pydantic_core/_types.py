@@ -15,17 +15,27 @@ else:
     from typing import Literal, TypedDict
 
 
-class AnySchema(TypedDict):
+# keys which can be set on any schema to replace the validator's own errors with a custom error
+class CustomErrorSchema(TypedDict, total=False):
+    custom_error_kind: str
+    custom_error_message: str
+    custom_error_context: Dict[str, Any]
+
+
+class AnySchema(CustomErrorSchema):
     type: Literal['any']
 
 
-class BoolSchema(TypedDict, total=False):
+class BoolSchema(CustomErrorSchema, total=False):
     type: Required[Literal['bool']]
     strict: bool
+    allow_int: bool  # default: True
+    allow_str: bool  # default: True
     ref: str
 
 
 class Config(TypedDict, total=False):
+    title: str  # used in validation error messages, default: the name of the validator
     strict: bool
     # higher priority configs take precedence of over lower, if priority matches the two configs are merged, default 0
     config_choose_priority: int
@@ -38,15 +48,24 @@ class Config(TypedDict, total=False):
     # used on typed-dicts and tagged union keys
     from_attributes: bool
     revalidate_models: bool
+    # maximum depth of nested recursive validators, default 255
+    max_recursion_depth: int
     # fields related to string fields only
     str_max_length: int
     str_min_length: int
     str_strip_whitespace: bool
     str_to_lower: bool
     str_to_upper: bool
+    str_coerce_numbers_to_str: bool  # default: True
+    # fields related to float fields only
+    float_allow_inf_nan: bool  # default: True
+    # fields related to decimal fields only, unlike floats NaN and infinity are rejected by default
+    decimal_allow_inf_nan: bool  # default: False
+    # used when parsing JSON, if set a repeated key in a JSON object is an error, otherwise the last value is used
+    json_reject_duplicate_keys: bool  # default: False
 
 
-class DictSchema(TypedDict, total=False):
+class DictSchema(CustomErrorSchema, total=False):
     type: Required[Literal['dict']]
     keys_schema: Schema  # default: AnySchema
     values_schema: Schema  # default: AnySchema
@@ -56,7 +75,7 @@ class DictSchema(TypedDict, total=False):
     ref: str
 
 
-class FloatSchema(TypedDict, total=False):
+class FloatSchema(CustomErrorSchema, total=False):
     type: Required[Literal['float']]
     multiple_of: float
     le: float
@@ -64,10 +83,21 @@ class FloatSchema(TypedDict, total=False):
     lt: float
     gt: float
     strict: bool
+    strict_float_reject_int: bool  # default: False
+    allow_inf_nan: bool  # default: True
     ref: str
 
 
-class FunctionSchema(TypedDict):
+class DecimalSchema(CustomErrorSchema, total=False):
+    type: Required[Literal['decimal']]
+    max_digits: int
+    decimal_places: int
+    allow_inf_nan: bool  # default: False
+    strict: bool
+    ref: str
+
+
+class FunctionSchema(CustomErrorSchema):
     type: Literal['function']
     mode: Literal['before', 'after', 'wrap']
     function: Callable[..., Any]
@@ -75,14 +105,14 @@ class FunctionSchema(TypedDict):
     ref: NotRequired[str]
 
 
-class FunctionPlainSchema(TypedDict):
+class FunctionPlainSchema(CustomErrorSchema):
     type: Literal['function']
     mode: Literal['plain']
     function: Callable[..., Any]
     ref: NotRequired[str]
 
 
-class IntSchema(TypedDict, total=False):
+class IntSchema(CustomErrorSchema, total=False):
     type: Required[Literal['int']]
     multiple_of: int
     le: int
@@ -93,25 +123,36 @@ class IntSchema(TypedDict, total=False):
     ref: str
 
 
-class ListSchema(TypedDict, total=False):
+class ListSchema(CustomErrorSchema, total=False):
     type: Required[Literal['list']]
     items_schema: Schema  # default: AnySchema
     min_items: int
     max_items: int
+    unique_items: bool  # default: False
+    coerce_single_to_list: bool  # default: False
+    fail_fast: bool  # default: False
     strict: bool
     ref: str
 
 
-class LiteralSchema(TypedDict):
+class LiteralSchema(CustomErrorSchema):
     type: Literal['literal']
     expected: List[Any]
     ref: NotRequired[str]
 
 
-class ModelClassSchema(TypedDict):
+class ConstantSchema(CustomErrorSchema, total=False):
+    type: Required[Literal['constant']]
+    value: Required[Any]
+    strict: bool
+    ref: str
+
+
+class ModelClassSchema(CustomErrorSchema):
     type: Literal['model-class']
     class_type: type
     schema: TypedDictSchema
+    post_init: NotRequired[str]
     strict: NotRequired[bool]
     ref: NotRequired[str]
     config: NotRequired[Config]
@@ -121,11 +162,14 @@ class TypedDictField(TypedDict, total=False):
     schema: Required[Schema]
     required: bool
     default: Any
-    default_factory: Callable[[], Any]
+    default_factory: Callable[..., Any]
+    default_factory_takes_data: bool  # default: False
+    frozen: bool  # default: False
+    strict: bool
     alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
 
 
-class TypedDictSchema(TypedDict, total=False):
+class TypedDictSchema(CustomErrorSchema, total=False):
     type: Required[Literal['typed-dict']]
     fields: Required[Dict[str, TypedDictField]]
     strict: bool
@@ -139,42 +183,55 @@ class TypedDictSchema(TypedDict, total=False):
     from_attributes: bool
 
 
-class NoneSchema(TypedDict):
+class NoneSchema(CustomErrorSchema):
     type: Literal['none']
     ref: NotRequired[str]
 
 
-class NullableSchema(TypedDict, total=False):
+class DefaultSchema(CustomErrorSchema, total=False):
+    type: Required[Literal['default']]
+    schema: Required[Schema]
+    default: Any
+    default_factory: Callable[..., Any]
+    default_factory_takes_data: bool  # default: False
+    on_none: bool  # default: False
+    validate_default: bool  # default: False
+    ref: str
+
+
+class NullableSchema(CustomErrorSchema, total=False):
     type: Required[Literal['nullable']]
     schema: Required[Schema]
     strict: bool
     ref: str
 
 
-class RecursiveReferenceSchema(TypedDict):
+class RecursiveReferenceSchema(CustomErrorSchema):
     type: Literal['recursive-ref']
     schema_ref: str
 
 
-class SetSchema(TypedDict, total=False):
+class SetSchema(CustomErrorSchema, total=False):
     type: Required[Literal['set']]
     items_schema: Schema  # default: AnySchema
     min_items: int
     max_items: int
+    fail_fast: bool  # default: False
     strict: bool
     ref: str
 
 
-class FrozenSetSchema(TypedDict, total=False):
+class FrozenSetSchema(CustomErrorSchema, total=False):
     type: Required[Literal['frozenset']]
     items_schema: Schema  # default: AnySchema
     min_items: int
     max_items: int
+    fail_fast: bool  # default: False
     strict: bool
     ref: str
 
 
-class StringSchema(TypedDict, total=False):
+class StringSchema(CustomErrorSchema, total=False):
     type: Required[Literal['str']]
     pattern: str
     max_length: int
@@ -182,18 +239,22 @@ class StringSchema(TypedDict, total=False):
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
+    allowed: List[str]
+    coerce_numbers_to_str: bool  # default: True
+    encoding: str  # default: 'utf-8', used to decode bytes in lax mode
     strict: bool
     ref: str
 
 
-class UnionSchema(TypedDict, total=False):
+class UnionSchema(CustomErrorSchema, total=False):
     type: Required[Literal['union']]
     choices: Required[List[Schema]]
+    mode: Literal['smart', 'left_to_right']  # default: 'smart'
     strict: bool
     ref: str
 
 
-class TaggedUnionSchema(TypedDict):
+class TaggedUnionSchema(CustomErrorSchema):
     type: Literal['tagged-union']
     choices: Dict[str, Schema]
     discriminator: Union[str, List[Union[str, int]], List[List[Union[str, int]]], Callable[[Any], Optional[str]]]
@@ -201,27 +262,35 @@ class TaggedUnionSchema(TypedDict):
     ref: NotRequired[str]
 
 
-class BytesSchema(TypedDict, total=False):
+class BytesSchema(CustomErrorSchema, total=False):
     type: Required[Literal['bytes']]
     max_length: int
     min_length: int
     strict: bool
+    strict_bytes_type: bool  # default: False
+    output_encoding: Literal['hex', 'base64']
     ref: str
 
 
-class DateSchema(TypedDict, total=False):
+class DateSchema(CustomErrorSchema, total=False):
     type: Required[Literal['date']]
     strict: bool
+    from_datetime: Literal['exact', 'truncate', 'truncate_ignore_tz']  # default: 'exact'
     le: date
     ge: date
     lt: date
     gt: date
+    now_op: Literal['past', 'future']
+    # defaults to the local timezone's offset
+    now_utc_offset: int
     ref: str
 
 
-class TimeSchema(TypedDict, total=False):
+class TimeSchema(CustomErrorSchema, total=False):
     type: Required[Literal['time']]
     strict: bool
+    require_tz: bool  # default: False
+    forbid_tz: bool  # default: False
     le: time
     ge: time
     lt: time
@@ -229,17 +298,21 @@ class TimeSchema(TypedDict, total=False):
     ref: str
 
 
-class DatetimeSchema(TypedDict, total=False):
+class DatetimeSchema(CustomErrorSchema, total=False):
     type: Required[Literal['datetime']]
     strict: bool
-    le: datetime
-    ge: datetime
-    lt: datetime
-    gt: datetime
+    # bounds may be datetimes or ISO 8601 strings
+    le: Union[datetime, str]
+    ge: Union[datetime, str]
+    lt: Union[datetime, str]
+    gt: Union[datetime, str]
+    now_op: Literal['past', 'future']
+    # used for naive datetimes, defaults to the local timezone's offset
+    now_utc_offset: int
     ref: str
 
 
-class TimedeltaSchema(TypedDict, total=False):
+class TimedeltaSchema(CustomErrorSchema, total=False):
     type: Required[Literal['timedelta']]
     strict: bool
     le: timedelta
@@ -249,7 +322,7 @@ class TimedeltaSchema(TypedDict, total=False):
     ref: str
 
 
-class TuplePositionalSchema(TypedDict, total=False):
+class TuplePositionalSchema(CustomErrorSchema, total=False):
     type: Required[Literal['tuple']]
     mode: Required[Literal['positional']]
     items_schema: Required[List[Schema]]
@@ -258,7 +331,7 @@ class TuplePositionalSchema(TypedDict, total=False):
     ref: str
 
 
-class TupleVariableSchema(TypedDict, total=False):
+class TupleVariableSchema(CustomErrorSchema, total=False):
     type: Required[Literal['tuple']]
     mode: Literal['variable']
     items_schema: Schema
@@ -268,15 +341,36 @@ class TupleVariableSchema(TypedDict, total=False):
     ref: str
 
 
-class IsInstanceSchema(TypedDict):
+class IsInstanceSchema(CustomErrorSchema):
     type: Literal['is-instance']
-    class_: Type[Any]
+    cls: NotRequired[Type[Any]]
+    class_: NotRequired[Type[Any]]  # deprecated, use `cls`
 
 
-class CallableSchema(TypedDict):
+class EnumSchema(CustomErrorSchema):
+    type: Literal['enum']
+    cls: Type[Any]
+    strict: NotRequired[bool]
+    ref: NotRequired[str]
+
+
+class CallableSchema(CustomErrorSchema):
     type: Literal['callable']
 
 
+class ChainSchema(CustomErrorSchema):
+    type: Literal['chain']
+    steps: List[Schema]
+    ref: NotRequired[str]
+
+
+class JsonSchema(CustomErrorSchema, total=False):
+    type: Required[Literal['json']]
+    schema: Schema  # default: AnySchema
+    strict: bool
+    ref: str
+
+
 # pydantic allows types to be defined via a simple string instead of dict with just `type`, e.g.
 # 'int' is equivalent to {'type': 'int'}, this only applies to schema types which do not have other required fields
 BareType = Literal[
@@ -288,6 +382,7 @@ BareType = Literal[
     'int',
     'bool',
     'float',
+    'decimal',
     'dict',
     'list',
     'tuple',
@@ -298,6 +393,7 @@ BareType = Literal[
     'datetime',
     'timedelta',
     'callable',
+    'json',
 ]
 
 # generate_self_schema.py is hard coded to convert this Union[BareType, Union[...rest]] where the second union is tagged
@@ -309,11 +405,13 @@ Schema = Union[
     BytesSchema,
     DictSchema,
     FloatSchema,
+    DecimalSchema,
     FunctionSchema,
     FunctionPlainSchema,
     IntSchema,
     ListSchema,
     LiteralSchema,
+    ConstantSchema,
     TypedDictSchema,
     ModelClassSchema,
     NoneSchema,
@@ -332,4 +430,8 @@ Schema = Union[
     TimedeltaSchema,
     IsInstanceSchema,
     CallableSchema,
+    DefaultSchema,
+    EnumSchema,
+    ChainSchema,
+    JsonSchema,
 ]
@@ -1,7 +1,7 @@
 use std::error::Error;
 use std::fmt;
 
-use pyo3::exceptions::{PyException, PyKeyError};
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 use pyo3::{intern, FromPyObject, PyErrArguments};
@@ -24,7 +24,7 @@ impl<'py> SchemaDict<'py> for PyDict {
         T: FromPyObject<'py>,
     {
         match self.get_item(key) {
-            Some(t) => Ok(Some(<T>::extract(t)?)),
+            Some(t) => <T>::extract(t).map(Some).map_err(|err| invalid_value_err(key, err)),
             None => Ok(None),
         }
     }
@@ -34,12 +34,17 @@ impl<'py> SchemaDict<'py> for PyDict {
         T: FromPyObject<'py>,
     {
         match self.get_item(key) {
-            Some(t) => <T>::extract(t),
-            None => py_error!(PyKeyError; "{}", key),
+            Some(t) => <T>::extract(t).map_err(|err| invalid_value_err(key, err)),
+            None => py_error!(r#"Missing required key "{}""#, key),
         }
     }
 }
 
+/// errors from extracting schema values include the key, so it's clear which part of the schema is invalid
+fn invalid_value_err(key: &PyString, err: PyErr) -> PyErr {
+    SchemaError::new_err(format!(r#"Invalid value for "{}": {}"#, key, err.value(key.py())))
+}
+
 impl<'py> SchemaDict<'py> for Option<&PyDict> {
     fn get_as<T>(&'py self, key: &PyString) -> PyResult<Option<T>>
     where
@@ -58,7 +63,7 @@ impl<'py> SchemaDict<'py> for Option<&PyDict> {
     {
         match self {
             Some(d) => d.get_as_req(key),
-            None => py_error!(PyKeyError; "{}", key),
+            None => py_error!(r#"Missing required key "{}""#, key),
         }
     }
 }
@@ -89,7 +94,7 @@ pub fn is_strict(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
 
 // we could perhaps do clever things here to store each schema error, or have different types for the top
 // level error group, and other errors, we could perhaps also support error groups!?
-#[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
+#[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
 pub struct SchemaError {
     message: String,
 }
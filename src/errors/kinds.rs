@@ -1,7 +1,8 @@
+use num_bigint::BigInt;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use strum::{Display, EnumMessage};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
 use super::PydanticValueError;
 
@@ -10,19 +11,37 @@ use super::PydanticValueError;
 /// * the variables in the message need to match the enum struct
 /// * you need to add an entry to the `render` enum to render the error message as a template
 /// * you need to add an entry to the `py_dict` enum to generate `ctx` for error messages
-#[derive(Debug, Display, EnumMessage, Clone)]
-#[strum(serialize_all = "snake_case")]
+///
+/// Every error also needs a code in `ErrorCode::as_str`.
+#[derive(Debug, EnumMessage, EnumDiscriminants, Clone)]
+#[strum_discriminants(name(ErrorCode), derive(EnumIter))]
 pub enum ErrorKind {
     #[strum(message = "Invalid input")]
     InvalidInput,
     #[strum(message = "Invalid JSON: {error}")]
     InvalidJson {
         error: String,
-    },
+        line: usize,
+        column: usize,
+        position: usize,
+    },
+    #[strum(message = "Invalid JSON: duplicate key '{key}' at line {line} column {column}")]
+    JsonDuplicateKey {
+        key: String,
+        line: usize,
+        column: usize,
+        position: usize,
+    },
+    #[strum(message = "JSON input must be str, bytes or bytearray")]
+    JsonType,
     // ---------------------
     // recursion error
     #[strum(message = "Recursion error - cyclic reference detected")]
     RecursionLoop,
+    #[strum(message = "Recursion error - maximum depth of {max_depth} exceeded")]
+    TooDeep {
+        max_depth: u16,
+    },
     // ---------------------
     // typed dict specific errors
     #[strum(message = "Value must be a valid dictionary or instance to extract fields from")]
@@ -31,6 +50,8 @@ pub enum ErrorKind {
     Missing,
     #[strum(message = "Extra values are not permitted")]
     ExtraForbidden,
+    #[strum(message = "Field is frozen")]
+    FrozenField,
     #[strum(message = "Model keys must be strings")]
     InvalidKey,
     #[strum(message = "Error extracting attribute: {error}")]
@@ -74,32 +95,42 @@ pub enum ErrorKind {
     #[strum(message = "Input must have at least {min_length} items")]
     TooShort {
         min_length: usize,
+        input_length: usize,
     },
     #[strum(message = "Input must have at most {max_length} items")]
     TooLong {
         max_length: usize,
+        /// `None` for iterators, which are only consumed until they're known to be too long
+        input_length: Option<usize>,
     },
     // ---------------------
     // string errors
     #[strum(message = "Value must be a valid string")]
     StrType,
-    #[strum(message = "Value must be a valid string, unable to parse raw data as a unicode string")]
-    StrUnicode,
     #[strum(
-        message = "String must have at least {min_length} characters",
-        serialize = "too_short"
+        message = "Value must be a valid string, unable to parse raw data as a unicode string at position {position}"
     )]
+    StrUnicode {
+        position: usize,
+    },
+    #[strum(message = "String must have at least {min_length} characters")]
     StrTooShort {
         min_length: usize,
+        input_length: usize,
     },
-    #[strum(message = "String must have at most {max_length} characters", serialize = "too_long")]
+    #[strum(message = "String must have at most {max_length} characters")]
     StrTooLong {
         max_length: usize,
+        input_length: usize,
     },
     #[strum(message = "String must match pattern '{pattern}'")]
     StrPatternMismatch {
         pattern: String,
     },
+    #[strum(message = "String must be one of: {allowed}")]
+    StrNotInAllowed {
+        allowed: String,
+    },
     // ---------------------
     // dict errors
     #[strum(message = "Value must be a valid dictionary")]
@@ -112,14 +143,25 @@ pub enum ErrorKind {
     // list errors
     #[strum(message = "Value must be a valid list/array")]
     ListType,
+    #[strum(message = "List must have unique items, item {index} is a duplicate")]
+    ListUniqueItems {
+        index: usize,
+    },
     // ---------------------
     // tuple errors
     #[strum(message = "Value must be a valid tuple")]
     TupleType,
+    #[strum(message = "Tuple must have exactly {expected_length} items, got {input_length}")]
+    TupleLengthMismatch {
+        expected_length: usize,
+        input_length: usize,
+    },
     // ---------------------
     // set errors
     #[strum(message = "Value must be a valid set")]
     SetType,
+    #[strum(message = "Set items must be hashable")]
+    SetItemNotHashable,
     // ---------------------
     // bool errors
     #[strum(message = "Value must be a valid boolean")]
@@ -138,28 +180,32 @@ pub enum ErrorKind {
     IntNan {
         nan_value: &'static str,
     },
-    #[strum(serialize = "multiple_of", message = "Value must be a multiple of {multiple_of}")]
+    #[strum(message = "Value must be a valid integer, number is out of range")]
+    IntOverflow,
+    #[strum(message = "Value must be a multiple of {multiple_of}")]
     IntMultipleOf {
-        multiple_of: i64,
+        multiple_of: BigInt,
+        value: BigInt,
     },
-    #[strum(serialize = "greater_than", message = "Value must be greater than {gt}")]
+    #[strum(message = "Value must be greater than {gt}")]
     IntGreaterThan {
-        gt: i64,
+        gt: BigInt,
+        value: BigInt,
     },
-    #[strum(
-        serialize = "greater_than_equal",
-        message = "Value must be greater than or equal to {ge}"
-    )]
+    #[strum(message = "Value must be greater than or equal to {ge}")]
     IntGreaterThanEqual {
-        ge: i64,
+        ge: BigInt,
+        value: BigInt,
     },
-    #[strum(serialize = "less_than", message = "Value must be less than {lt}")]
+    #[strum(message = "Value must be less than {lt}")]
     IntLessThan {
-        lt: i64,
+        lt: BigInt,
+        value: BigInt,
     },
-    #[strum(serialize = "less_than_equal", message = "Value must be less than or equal to {le}")]
+    #[strum(message = "Value must be less than or equal to {le}")]
     IntLessThanEqual {
-        le: i64,
+        le: BigInt,
+        value: BigInt,
     },
     // ---------------------
     // float errors
@@ -167,40 +213,66 @@ pub enum ErrorKind {
     FloatType,
     #[strum(message = "Value must be a valid number, unable to parse string as an number")]
     FloatParsing,
-    #[strum(serialize = "multiple_of", message = "Value must be a multiple of {multiple_of}")]
+    #[strum(message = "Value must be a finite number, got {value}")]
+    FloatFiniteNumber {
+        value: &'static str,
+    },
+    #[strum(message = "Value must be a multiple of {multiple_of}")]
     FloatMultipleOf {
         multiple_of: f64,
+        value: f64,
     },
-    #[strum(serialize = "greater_than", message = "Value must be greater than {gt}")]
+    #[strum(message = "Value must be greater than {gt}")]
     FloatGreaterThan {
         gt: f64,
+        value: f64,
     },
-    #[strum(
-        serialize = "greater_than_equal",
-        message = "Value must be greater than or equal to {ge}"
-    )]
+    #[strum(message = "Value must be greater than or equal to {ge}")]
     FloatGreaterThanEqual {
         ge: f64,
+        value: f64,
     },
-    #[strum(serialize = "less_than", message = "Value must be less than {lt}")]
+    #[strum(message = "Value must be less than {lt}")]
     FloatLessThan {
         lt: f64,
+        value: f64,
     },
-    #[strum(serialize = "less_than_equal", message = "Value must be less than or equal to {le}")]
+    #[strum(message = "Value must be less than or equal to {le}")]
     FloatLessThanEqual {
         le: f64,
+        value: f64,
+    },
+    // ---------------------
+    // decimal errors
+    #[strum(message = "Value must be a valid decimal")]
+    DecimalType,
+    #[strum(message = "Value must be a valid decimal, unable to parse input as a decimal")]
+    DecimalParsing,
+    #[strum(message = "Value must be a finite decimal")]
+    DecimalFinite,
+    #[strum(message = "Value must have no more than {max_digits} digits in total")]
+    DecimalMaxDigits {
+        max_digits: usize,
+        digits: usize,
+    },
+    #[strum(message = "Value must have no more than {decimal_places} decimal places")]
+    DecimalMaxPlaces {
+        decimal_places: usize,
+        decimals: usize,
     },
     // ---------------------
     // bytes errors
     #[strum(message = "Value must be a valid bytes")]
     BytesType,
-    #[strum(message = "Data must have at least {min_length} bytes", serialize = "too_short")]
+    #[strum(message = "Data must have at least {min_length} bytes")]
     BytesTooShort {
         min_length: usize,
+        input_length: usize,
     },
-    #[strum(message = "Data must have at most {max_length} bytes", serialize = "too_long")]
+    #[strum(message = "Data must have at most {max_length} bytes")]
     BytesTooLong {
         max_length: usize,
+        input_length: usize,
     },
     // ---------------------
     // python errors from functions
@@ -212,21 +284,27 @@ pub enum ErrorKind {
     AssertionError {
         error: String,
     },
-    // Note: strum message and serialize are not used here
+    // Note: strum message is not used here and `kind` comes from the `PydanticValueError`
     CustomError {
         value_error: PydanticValueError,
     },
     // ---------------------
     // literals
-    #[strum(serialize = "literal_error", message = "Value must be {expected}")]
+    #[strum(message = "Value must be {expected}")]
     LiteralSingleError {
         expected: String,
     },
-    #[strum(serialize = "literal_error", message = "Value must be one of: {expected}")]
+    #[strum(message = "Value must be one of: {expected}")]
     LiteralMultipleError {
         expected: String,
     },
     // ---------------------
+    // enums
+    #[strum(message = "Value must be a member of the enum, one of: {expected}")]
+    EnumMember {
+        expected: String,
+    },
+    // ---------------------
     // date errors
     #[strum(message = "Value must be a valid date")]
     DateType,
@@ -240,6 +318,22 @@ pub enum ErrorKind {
     },
     #[strum(message = "Datetimes provided to dates must have zero time - e.g. be exact dates")]
     DateFromDatetimeInexact,
+    #[strum(message = "Datetimes provided to dates must not have a timezone to be truncated")]
+    DateFromDatetimeTzAware,
+    #[strum(message = "Value must be {comparison} {bound}")]
+    DateTooEarly {
+        bound: String,
+        inclusive: bool,
+    },
+    #[strum(message = "Value must be {comparison} {bound}")]
+    DateTooLate {
+        bound: String,
+        inclusive: bool,
+    },
+    #[strum(message = "Date must be in the future")]
+    DateInPast,
+    #[strum(message = "Date must be in the past")]
+    DateInFuture,
     // ---------------------
     // date errors
     #[strum(message = "Value must be a valid time")]
@@ -248,21 +342,36 @@ pub enum ErrorKind {
     TimeParsing {
         error: &'static str,
     },
+    #[strum(message = "Time must have timezone information")]
+    TimeTzRequired,
+    #[strum(message = "Time must not have timezone information")]
+    TimeTzForbidden,
     // ---------------------
     // datetime errors
-    #[strum(serialize = "datetime_type", message = "Value must be a valid datetime")]
+    #[strum(message = "Value must be a valid datetime")]
     DateTimeType,
-    #[strum(serialize = "datetime_parsing", message = "Value must be a valid datetime, {error}")]
+    #[strum(message = "Value must be a valid datetime, {error}")]
     DateTimeParsing {
         error: &'static str,
     },
-    #[strum(
-        serialize = "datetime_object_invalid",
-        message = "Invalid datetime object, got {error}"
-    )]
+    #[strum(message = "Invalid datetime object, got {error}")]
     DateTimeObjectInvalid {
         error: String,
     },
+    #[strum(message = "Value must be {comparison} {bound}")]
+    DateTimeTooEarly {
+        bound: String,
+        inclusive: bool,
+    },
+    #[strum(message = "Value must be {comparison} {bound}")]
+    DateTimeTooLate {
+        bound: String,
+        inclusive: bool,
+    },
+    #[strum(message = "Datetime must be in the future")]
+    DateTimeInPast,
+    #[strum(message = "Datetime must be in the past")]
+    DateTimeInFuture,
     // ---------------------
     // timedelta errors
     #[strum(message = "Value must be a valid timedelta")]
@@ -299,6 +408,121 @@ pub enum ErrorKind {
     },
 }
 
+impl ErrorCode {
+    /// the stable, machine readable code of the error, this is the error's `kind` and must not change
+    /// when variants are renamed or reordered, several variants may share the same code
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidInput => "invalid_input",
+            Self::InvalidJson => "invalid_json",
+            Self::JsonDuplicateKey => "json_duplicate_key",
+            Self::JsonType => "json_type",
+            Self::RecursionLoop => "recursion_loop",
+            Self::TooDeep => "too_deep",
+            Self::DictAttributesType => "dict_attributes_type",
+            Self::Missing => "missing",
+            Self::ExtraForbidden => "extra_forbidden",
+            Self::FrozenField => "frozen_field",
+            Self::InvalidKey => "invalid_key",
+            Self::GetAttributeError => "get_attribute_error",
+            Self::ModelClassType => "model_class_type",
+            Self::NoneRequired => "none_required",
+            Self::Bool => "bool",
+            Self::GreaterThan => "greater_than",
+            Self::GreaterThanEqual => "greater_than_equal",
+            Self::LessThan => "less_than",
+            Self::LessThanEqual => "less_than_equal",
+            Self::TooShort => "too_short",
+            Self::TooLong => "too_long",
+            Self::StrType => "str_type",
+            Self::StrUnicode => "str_unicode",
+            Self::StrTooShort => "too_short",
+            Self::StrTooLong => "too_long",
+            Self::StrPatternMismatch => "str_pattern_mismatch",
+            Self::StrNotInAllowed => "str_not_in_allowed",
+            Self::DictType => "dict_type",
+            Self::DictFromMapping => "dict_from_mapping",
+            Self::ListType => "list_type",
+            Self::ListUniqueItems => "list_unique_items",
+            Self::TupleType => "tuple_type",
+            Self::TupleLengthMismatch => "tuple_length_mismatch",
+            Self::SetType => "set_type",
+            Self::SetItemNotHashable => "set_item_not_hashable",
+            Self::BoolType => "bool_type",
+            Self::BoolParsing => "bool_parsing",
+            Self::IntType => "int_type",
+            Self::IntParsing => "int_parsing",
+            Self::IntFromFloat => "int_from_float",
+            Self::IntNan => "int_nan",
+            Self::IntOverflow => "int_overflow",
+            Self::IntMultipleOf => "multiple_of",
+            Self::IntGreaterThan => "greater_than",
+            Self::IntGreaterThanEqual => "greater_than_equal",
+            Self::IntLessThan => "less_than",
+            Self::IntLessThanEqual => "less_than_equal",
+            Self::FloatType => "float_type",
+            Self::FloatParsing => "float_parsing",
+            Self::FloatFiniteNumber => "float_finite_number",
+            Self::FloatMultipleOf => "multiple_of",
+            Self::FloatGreaterThan => "greater_than",
+            Self::FloatGreaterThanEqual => "greater_than_equal",
+            Self::FloatLessThan => "less_than",
+            Self::FloatLessThanEqual => "less_than_equal",
+            Self::DecimalType => "decimal_type",
+            Self::DecimalParsing => "decimal_parsing",
+            Self::DecimalFinite => "decimal_finite",
+            Self::DecimalMaxDigits => "decimal_max_digits",
+            Self::DecimalMaxPlaces => "decimal_max_places",
+            Self::BytesType => "bytes_type",
+            Self::BytesTooShort => "too_short",
+            Self::BytesTooLong => "too_long",
+            Self::ValueError => "value_error",
+            Self::AssertionError => "assertion_error",
+            Self::CustomError => "custom_error",
+            Self::LiteralSingleError => "literal_error",
+            Self::LiteralMultipleError => "literal_error",
+            Self::EnumMember => "enum_member",
+            Self::DateType => "date_type",
+            Self::DateParsing => "date_parsing",
+            Self::DateFromDatetimeParsing => "date_from_datetime_parsing",
+            Self::DateFromDatetimeInexact => "date_from_datetime_inexact",
+            Self::DateFromDatetimeTzAware => "date_from_datetime_tz_aware",
+            Self::DateTooEarly => "date_too_early",
+            Self::DateTooLate => "date_too_late",
+            Self::DateInPast => "date_in_past",
+            Self::DateInFuture => "date_in_future",
+            Self::TimeType => "time_type",
+            Self::TimeParsing => "time_parsing",
+            Self::TimeTzRequired => "time_tz_required",
+            Self::TimeTzForbidden => "time_tz_forbidden",
+            Self::DateTimeType => "datetime_type",
+            Self::DateTimeParsing => "datetime_parsing",
+            Self::DateTimeObjectInvalid => "datetime_object_invalid",
+            Self::DateTimeTooEarly => "datetime_too_early",
+            Self::DateTimeTooLate => "datetime_too_late",
+            Self::DateTimeInPast => "datetime_in_past",
+            Self::DateTimeInFuture => "datetime_in_future",
+            Self::TimeDeltaType => "time_delta_type",
+            Self::TimeDeltaParsing => "time_delta_parsing",
+            Self::FrozenSetType => "frozen_set_type",
+            Self::IsInstanceOf => "is_instance_of",
+            Self::CallableType => "callable_type",
+            Self::UnionTagInvalid => "union_tag_invalid",
+            Self::UnionTagNotFound => "union_tag_not_found",
+        }
+    }
+}
+
+/// Map of the name of every error to its code, used to check codes don't change
+#[pyfunction]
+pub fn list_error_codes(py: Python) -> PyResult<&PyDict> {
+    let dict = PyDict::new(py);
+    for code in ErrorCode::iter() {
+        dict.set_item(format!("{:?}", code), code.as_str())?;
+    }
+    Ok(dict)
+}
+
 macro_rules! render {
     ($error_kind:ident, $($value:ident),* $(,)?) => {
         Ok(
@@ -325,58 +549,143 @@ macro_rules! py_dict {
     ($py:ident, $($value:expr),* $(,)?) => {{
         let dict = PyDict::new($py);
         $(
-            dict.set_item(stringify!($value), $value.into_py($py))?;
+            dict.set_item(stringify!($value), $value.to_object($py))?;
         )*
         Ok(Some(dict.into_py($py)))
     }};
 }
 
 impl ErrorKind {
+    /// serde only provides the line and column of JSON errors, `position` is the byte offset of the error
+    /// calculated from them
+    pub fn invalid_json(error: serde_json::Error, json_data: &[u8]) -> Self {
+        let (line, column, position) = json_error_location(&error, json_data);
+        Self::InvalidJson {
+            error: error.to_string(),
+            line,
+            column,
+            position,
+        }
+    }
+
+    /// a repeated key in a JSON object, the location is where parsing stopped, after the repeated key
+    pub fn json_duplicate_key(key: String, error: serde_json::Error, json_data: &[u8]) -> Self {
+        let (line, column, position) = json_error_location(&error, json_data);
+        Self::JsonDuplicateKey {
+            key,
+            line,
+            column,
+            position,
+        }
+    }
+
+    /// whether this is a type mismatch error, if so the input's type is included in the error message and context
+    pub fn is_type_error(&self) -> bool {
+        matches!(
+            self,
+            Self::DictAttributesType
+                | Self::JsonType
+                | Self::StrType
+                | Self::DictType
+                | Self::ListType
+                | Self::TupleType
+                | Self::SetType
+                | Self::SetItemNotHashable
+                | Self::BoolType
+                | Self::IntType
+                | Self::FloatType
+                | Self::DecimalType
+                | Self::BytesType
+                | Self::DateType
+                | Self::TimeType
+                | Self::DateTimeType
+                | Self::TimeDeltaType
+                | Self::FrozenSetType
+                | Self::CallableType
+        )
+    }
+
+    /// see `ErrorCode::as_str`
+    pub fn code(&self) -> &'static str {
+        ErrorCode::from(self).as_str()
+    }
+
     pub fn kind(&self) -> String {
         match self {
             Self::CustomError { value_error } => value_error.kind(),
-            _ => self.to_string(),
+            _ => self.code().to_string(),
         }
     }
 
     pub fn render_message(&self, py: Python) -> PyResult<String> {
         match self {
-            Self::InvalidJson { error } => render!(self, error),
+            Self::TooDeep { max_depth } => to_string_render!(self, max_depth),
+            Self::TupleLengthMismatch {
+                expected_length,
+                input_length,
+            } => to_string_render!(self, expected_length, input_length),
+            Self::InvalidJson { error, .. } => render!(self, error),
+            Self::JsonDuplicateKey { key, line, column, .. } => {
+                to_string_render!(self, key, line, column)
+            }
             Self::GetAttributeError { error } => render!(self, error),
             Self::ModelClassType { class_name } => render!(self, class_name),
             Self::GreaterThan { gt } => render!(self, gt),
             Self::GreaterThanEqual { ge } => render!(self, ge),
             Self::LessThan { lt } => render!(self, lt),
             Self::LessThanEqual { le } => render!(self, le),
-            Self::TooShort { min_length } => to_string_render!(self, min_length),
-            Self::TooLong { max_length } => to_string_render!(self, max_length),
-            Self::StrTooShort { min_length } => to_string_render!(self, min_length),
-            Self::StrTooLong { max_length } => to_string_render!(self, max_length),
+            Self::TooShort { min_length, .. } => to_string_render!(self, min_length),
+            Self::TooLong { max_length, .. } => to_string_render!(self, max_length),
+            Self::StrTooShort { min_length, .. } => to_string_render!(self, min_length),
+            Self::StrTooLong { max_length, .. } => to_string_render!(self, max_length),
             Self::StrPatternMismatch { pattern } => render!(self, pattern),
+            Self::StrNotInAllowed { allowed } => render!(self, allowed),
+            Self::ListUniqueItems { index } => to_string_render!(self, index),
             Self::DictFromMapping { error } => render!(self, error),
             Self::IntNan { nan_value } => render!(self, nan_value),
-            Self::IntMultipleOf { multiple_of } => to_string_render!(self, multiple_of),
-            Self::IntGreaterThan { gt } => to_string_render!(self, gt),
-            Self::IntGreaterThanEqual { ge } => to_string_render!(self, ge),
-            Self::IntLessThan { lt } => to_string_render!(self, lt),
-            Self::IntLessThanEqual { le } => to_string_render!(self, le),
-            Self::FloatMultipleOf { multiple_of } => to_string_render!(self, multiple_of),
-            Self::FloatGreaterThan { gt } => to_string_render!(self, gt),
-            Self::FloatGreaterThanEqual { ge } => to_string_render!(self, ge),
-            Self::FloatLessThan { lt } => to_string_render!(self, lt),
-            Self::FloatLessThanEqual { le } => to_string_render!(self, le),
-            Self::BytesTooShort { min_length } => to_string_render!(self, min_length),
-            Self::BytesTooLong { max_length } => to_string_render!(self, max_length),
+            Self::IntMultipleOf { multiple_of, .. } => to_string_render!(self, multiple_of),
+            Self::IntGreaterThan { gt, .. } => to_string_render!(self, gt),
+            Self::IntGreaterThanEqual { ge, .. } => to_string_render!(self, ge),
+            Self::IntLessThan { lt, .. } => to_string_render!(self, lt),
+            Self::IntLessThanEqual { le, .. } => to_string_render!(self, le),
+            Self::FloatFiniteNumber { value } => render!(self, value),
+            Self::StrUnicode { position } => to_string_render!(self, position),
+            Self::FloatMultipleOf { multiple_of, .. } => to_string_render!(self, multiple_of),
+            Self::FloatGreaterThan { gt, .. } => to_string_render!(self, gt),
+            Self::FloatGreaterThanEqual { ge, .. } => to_string_render!(self, ge),
+            Self::FloatLessThan { lt, .. } => to_string_render!(self, lt),
+            Self::FloatLessThanEqual { le, .. } => to_string_render!(self, le),
+            Self::DecimalMaxDigits { max_digits, .. } => to_string_render!(self, max_digits),
+            Self::DecimalMaxPlaces { decimal_places, .. } => to_string_render!(self, decimal_places),
+            Self::BytesTooShort { min_length, .. } => to_string_render!(self, min_length),
+            Self::BytesTooLong { max_length, .. } => to_string_render!(self, max_length),
             Self::ValueError { error } => render!(self, error),
             Self::AssertionError { error } => render!(self, error),
             Self::CustomError { value_error } => value_error.message(py),
             Self::LiteralSingleError { expected } => render!(self, expected),
             Self::LiteralMultipleError { expected } => render!(self, expected),
+            Self::EnumMember { expected } => render!(self, expected),
             Self::DateParsing { error } => render!(self, error),
             Self::DateFromDatetimeParsing { error } => render!(self, error),
+            Self::DateTooEarly { bound, inclusive } => {
+                let comparison = if *inclusive { "on or after" } else { "after" };
+                render!(self, comparison, bound)
+            }
+            Self::DateTooLate { bound, inclusive } => {
+                let comparison = if *inclusive { "on or before" } else { "before" };
+                render!(self, comparison, bound)
+            }
             Self::TimeParsing { error } => render!(self, error),
             Self::DateTimeParsing { error } => render!(self, error),
             Self::DateTimeObjectInvalid { error } => render!(self, error),
+            Self::DateTimeTooEarly { bound, inclusive } => {
+                let comparison = if *inclusive { "on or after" } else { "after" };
+                render!(self, comparison, bound)
+            }
+            Self::DateTimeTooLate { bound, inclusive } => {
+                let comparison = if *inclusive { "on or before" } else { "before" };
+                render!(self, comparison, bound)
+            }
             Self::TimeDeltaParsing { error } => render!(self, error),
             Self::IsInstanceOf { class } => render!(self, class),
             Self::UnionTagInvalid {
@@ -391,42 +700,94 @@ impl ErrorKind {
 
     pub fn py_dict(&self, py: Python) -> PyResult<Option<Py<PyDict>>> {
         match self {
-            Self::InvalidJson { error } => py_dict!(py, error),
+            Self::TooDeep { max_depth } => py_dict!(py, max_depth),
+            Self::TupleLengthMismatch {
+                expected_length,
+                input_length,
+            } => py_dict!(py, expected_length, input_length),
+            Self::InvalidJson {
+                error,
+                line,
+                column,
+                position,
+            } => py_dict!(py, error, line, column, position),
+            Self::JsonDuplicateKey {
+                key,
+                line,
+                column,
+                position,
+            } => py_dict!(py, key, line, column, position),
             Self::GetAttributeError { error } => py_dict!(py, error),
             Self::ModelClassType { class_name } => py_dict!(py, class_name),
             Self::GreaterThan { gt } => py_dict!(py, gt),
             Self::GreaterThanEqual { ge } => py_dict!(py, ge),
             Self::LessThan { lt } => py_dict!(py, lt),
             Self::LessThanEqual { le } => py_dict!(py, le),
-            Self::TooShort { min_length } => py_dict!(py, min_length),
-            Self::TooLong { max_length } => py_dict!(py, max_length),
-            Self::StrTooShort { min_length } => py_dict!(py, min_length),
-            Self::StrTooLong { max_length } => py_dict!(py, max_length),
+            Self::TooShort {
+                min_length,
+                input_length,
+            } => py_dict!(py, min_length, input_length),
+            Self::TooLong {
+                max_length,
+                input_length: Some(input_length),
+            } => py_dict!(py, max_length, input_length),
+            Self::TooLong {
+                max_length,
+                input_length: None,
+            } => py_dict!(py, max_length),
+            Self::StrTooShort {
+                min_length,
+                input_length,
+            } => py_dict!(py, min_length, input_length),
+            Self::StrTooLong {
+                max_length,
+                input_length,
+            } => py_dict!(py, max_length, input_length),
             Self::StrPatternMismatch { pattern } => py_dict!(py, pattern),
+            Self::StrNotInAllowed { allowed } => py_dict!(py, allowed),
+            Self::ListUniqueItems { index } => py_dict!(py, index),
             Self::DictFromMapping { error } => py_dict!(py, error),
             Self::IntNan { nan_value } => py_dict!(py, nan_value),
-            Self::IntMultipleOf { multiple_of } => py_dict!(py, multiple_of),
-            Self::IntGreaterThan { gt } => py_dict!(py, gt),
-            Self::IntGreaterThanEqual { ge } => py_dict!(py, ge),
-            Self::IntLessThan { lt } => py_dict!(py, lt),
-            Self::IntLessThanEqual { le } => py_dict!(py, le),
-            Self::FloatMultipleOf { multiple_of } => py_dict!(py, multiple_of),
-            Self::FloatGreaterThan { gt } => py_dict!(py, gt),
-            Self::FloatGreaterThanEqual { ge } => py_dict!(py, ge),
-            Self::FloatLessThan { lt } => py_dict!(py, lt),
-            Self::FloatLessThanEqual { le } => py_dict!(py, le),
-            Self::BytesTooShort { min_length } => py_dict!(py, min_length),
-            Self::BytesTooLong { max_length } => py_dict!(py, max_length),
+            Self::IntMultipleOf { multiple_of, value } => py_dict!(py, multiple_of, value),
+            Self::IntGreaterThan { gt, value } => py_dict!(py, gt, value),
+            Self::IntGreaterThanEqual { ge, value } => py_dict!(py, ge, value),
+            Self::IntLessThan { lt, value } => py_dict!(py, lt, value),
+            Self::IntLessThanEqual { le, value } => py_dict!(py, le, value),
+            Self::FloatFiniteNumber { value } => py_dict!(py, value),
+            Self::StrUnicode { position } => py_dict!(py, position),
+            Self::FloatMultipleOf { multiple_of, value } => py_dict!(py, multiple_of, value),
+            Self::FloatGreaterThan { gt, value } => py_dict!(py, gt, value),
+            Self::FloatGreaterThanEqual { ge, value } => py_dict!(py, ge, value),
+            Self::FloatLessThan { lt, value } => py_dict!(py, lt, value),
+            Self::FloatLessThanEqual { le, value } => py_dict!(py, le, value),
+            Self::DecimalMaxDigits { max_digits, digits } => py_dict!(py, max_digits, digits),
+            Self::DecimalMaxPlaces {
+                decimal_places,
+                decimals,
+            } => py_dict!(py, decimal_places, decimals),
+            Self::BytesTooShort {
+                min_length,
+                input_length,
+            } => py_dict!(py, min_length, input_length),
+            Self::BytesTooLong {
+                max_length,
+                input_length,
+            } => py_dict!(py, max_length, input_length),
             Self::ValueError { error } => py_dict!(py, error),
             Self::AssertionError { error } => py_dict!(py, error),
             Self::CustomError { value_error } => Ok(value_error.context(py)),
             Self::LiteralSingleError { expected } => py_dict!(py, expected),
             Self::LiteralMultipleError { expected } => py_dict!(py, expected),
+            Self::EnumMember { expected } => py_dict!(py, expected),
             Self::DateParsing { error } => py_dict!(py, error),
             Self::DateFromDatetimeParsing { error } => py_dict!(py, error),
+            Self::DateTooEarly { bound, inclusive } => py_dict!(py, bound, inclusive),
+            Self::DateTooLate { bound, inclusive } => py_dict!(py, bound, inclusive),
             Self::TimeParsing { error } => py_dict!(py, error),
             Self::DateTimeParsing { error } => py_dict!(py, error),
             Self::DateTimeObjectInvalid { error } => py_dict!(py, error),
+            Self::DateTimeTooEarly { bound, inclusive } => py_dict!(py, bound, inclusive),
+            Self::DateTimeTooLate { bound, inclusive } => py_dict!(py, bound, inclusive),
             Self::TimeDeltaParsing { error } => py_dict!(py, error),
             Self::IsInstanceOf { class } => py_dict!(py, class),
             Self::UnionTagInvalid {
@@ -439,3 +800,15 @@ impl ErrorKind {
         }
     }
 }
+
+/// line, column and byte offset of a JSON error
+fn json_error_location(error: &serde_json::Error, json_data: &[u8]) -> (usize, usize, usize) {
+    let line = error.line();
+    let column = error.column();
+    let line_start: usize = json_data
+        .split(|b| *b == b'\n')
+        .take(line.saturating_sub(1))
+        .map(|l| l.len() + 1)
+        .sum();
+    (line, column, line_start + column.saturating_sub(1))
+}
@@ -55,6 +55,19 @@ impl<'a> ValError<'a> {
             Self::InternalErr(err) => Self::InternalErr(err),
         }
     }
+
+    /// convert line errors to a new lifetime, needed when the input they refer to doesn't live for `'a`
+    pub fn into_new<'b>(self, py: Python) -> ValError<'b> {
+        match self {
+            Self::LineErrors(line_errors) => ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|line_error| line_error.into_new(py))
+                    .collect(),
+            ),
+            Self::InternalErr(err) => ValError::InternalErr(err),
+        }
+    }
 }
 
 pub fn pretty_line_errors(py: Python, line_errors: Vec<ValLineError>) -> String {
@@ -126,6 +139,23 @@ impl<'a> From<PyObject> for InputValue<'a> {
     }
 }
 
+impl<'a> InputValue<'a> {
+    /// name of the input's type, for JSON input this is the kind of JSON value (e.g. "array") rather than the
+    /// python type it would be converted to
+    pub fn type_name(&self, py: Python) -> String {
+        match self {
+            Self::PyAny(input) => py_type_name(input),
+            Self::JsonInput(input) => input.type_name().to_string(),
+            Self::String(_) => "string".to_string(),
+            Self::PyObject(py_obj) => py_type_name(py_obj.as_ref(py)),
+        }
+    }
+}
+
+fn py_type_name(input: &PyAny) -> String {
+    input.get_type().name().unwrap_or("unknown").to_string()
+}
+
 impl<'a> ToPyObject for InputValue<'a> {
     fn to_object(&self, py: Python) -> PyObject {
         match self {
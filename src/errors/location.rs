@@ -1,14 +1,16 @@
 use std::fmt;
+use std::sync::Arc;
 
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::PyTuple;
 
 /// Used to store individual items of the error location, e.g. a string for key/field names
 /// or a number for array indices.
 #[derive(Debug, Clone)]
 pub enum LocItem {
-    /// string type key, used to identify items from a dict or anything that implements `__getitem__`
-    S(String),
+    /// string type key, used to identify items from a dict or anything that implements `__getitem__`,
+    /// reference counted so one item (e.g. a union choice's name) can be shared by many errors without copying
+    S(Arc<str>),
     /// integer key, used to get items from a list, tuple OR a dict with int keys `Dict[int, ...]` (python only)
     I(usize),
 }
@@ -24,13 +26,13 @@ impl fmt::Display for LocItem {
 
 impl From<String> for LocItem {
     fn from(s: String) -> Self {
-        Self::S(s)
+        Self::S(s.into())
     }
 }
 
 impl From<&str> for LocItem {
     fn from(s: &str) -> Self {
-        Self::S(s.to_string())
+        Self::S(s.into())
     }
 }
 
@@ -43,7 +45,7 @@ impl From<usize> for LocItem {
 impl ToPyObject for LocItem {
     fn to_object(&self, py: Python<'_>) -> PyObject {
         match self {
-            Self::S(val) => val.to_object(py),
+            Self::S(val) => val.as_ref().to_object(py),
             Self::I(val) => val.to_object(py),
         }
     }
@@ -51,7 +53,7 @@ impl ToPyObject for LocItem {
 
 /// Error locations are represented by a vector of `LocItem`s.
 /// e.g. if the error occurred in the third member of a list called `foo`,
-/// the location would be `["foo", 2]`.
+/// the location would be `("foo", 2)`.
 /// Note: location in List is stored in **REVERSE** so adding an "outer" item to location involves
 /// pushing to the vec which is faster than inserting and shifting everything along.
 /// Then when "using" location in `Display` and `ToPyObject` order has to be reversed
@@ -73,8 +75,8 @@ impl Default for Location {
 impl ToPyObject for Location {
     fn to_object(&self, py: Python<'_>) -> PyObject {
         match self {
-            Self::List(loc) => loc.iter().rev().collect::<Vec<_>>().to_object(py),
-            Self::Empty => PyList::empty(py).to_object(py),
+            Self::List(loc) => PyTuple::new(py, loc.iter().rev()).to_object(py),
+            Self::Empty => PyTuple::empty(py).to_object(py),
         }
     }
 }
@@ -107,4 +109,13 @@ impl Location {
             }
         };
     }
+
+    /// the outermost location item, e.g. the field name when validating a typed dict
+    pub fn outer(&self) -> Option<&LocItem> {
+        match self {
+            // location is stored in reverse, so the outermost item is last
+            Self::List(loc) => loc.last(),
+            Self::Empty => None,
+        }
+    }
 }
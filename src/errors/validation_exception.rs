@@ -3,13 +3,15 @@ use std::fmt::Write;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyFrozenSet, PyInt, PyList, PySet, PyString, PyTuple};
+use serde::Serialize;
+use serde_json::{Map, Number, Value};
 
 use crate::input::repr_string;
 
 use super::kinds::ErrorKind;
 use super::line_error::ValLineError;
-use super::location::Location;
+use super::location::{LocItem, Location};
 use super::ValError;
 
 #[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
@@ -67,15 +69,68 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    fn errors(&self, py: Python) -> PyResult<PyObject> {
+    /// input values longer than `max_input_length` (characters for `str` and `bytes`, items for `list` and
+    /// `tuple`) are truncated, `None` includes input values in full
+    #[args(max_input_length = "MAX_INPUT_LENGTH")]
+    fn errors(&self, py: Python, max_input_length: Option<usize>) -> PyResult<PyObject> {
         Ok(self
             .line_errors
             .iter()
-            .map(|e| e.as_dict(py))
+            .map(|e| e.as_dict(py, max_input_length))
             .collect::<PyResult<Vec<PyObject>>>()?
             .into_py(py))
     }
 
+    /// errors grouped by the first item of their location (e.g. the field name), errors without a location are
+    /// grouped under `None`
+    #[args(max_input_length = "MAX_INPUT_LENGTH")]
+    fn grouped_errors(&self, py: Python, max_input_length: Option<usize>) -> PyResult<PyObject> {
+        let groups = PyDict::new(py);
+        for line_error in &self.line_errors {
+            let key = match line_error.location.outer() {
+                Some(loc_item) => loc_item.to_object(py),
+                None => py.None(),
+            };
+            let error = line_error.as_dict(py, max_input_length)?;
+            match groups.get_item(&key) {
+                Some(group) => group.cast_as::<PyList>()?.append(error)?,
+                None => groups.set_item(key, PyList::new(py, [error]))?,
+            }
+        }
+        Ok(groups.into_py(py))
+    }
+
+    /// errors serialized to a JSON string, with the same structure as `errors()`; input values which can't be
+    /// represented in JSON are serialized as their `repr()`
+    #[args(indent = "None", include_input = "true", max_input_length = "MAX_INPUT_LENGTH")]
+    fn json(
+        &self,
+        py: Python,
+        indent: Option<usize>,
+        include_input: bool,
+        max_input_length: Option<usize>,
+    ) -> PyResult<String> {
+        let errors = self
+            .line_errors
+            .iter()
+            .map(|e| e.as_json(py, include_input, max_input_length))
+            .collect::<PyResult<Vec<Value>>>()?;
+        let value = Value::Array(errors);
+        let result = match indent {
+            Some(indent) => {
+                let indent = " ".repeat(indent);
+                let mut bytes: Vec<u8> = Vec::with_capacity(200);
+                let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+                let mut serializer = serde_json::Serializer::with_formatter(&mut bytes, formatter);
+                value
+                    .serialize(&mut serializer)
+                    .map(|_| String::from_utf8_lossy(&bytes).to_string())
+            }
+            None => serde_json::to_string(&value),
+        };
+        result.map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
     fn __repr__(&self, py: Python) -> String {
         self.display(py)
     }
@@ -85,6 +140,48 @@ impl ValidationError {
     }
 }
 
+/// default for `max_input_length` in `errors()`, `grouped_errors()` and `json()`
+const MAX_INPUT_LENGTH: usize = 200;
+
+const TRUNCATED_MARKER: &str = "...";
+
+/// truncate long `str`, `bytes`, `list` and `tuple` input values so errors on huge inputs stay a sensible size,
+/// truncated values end with "..." (or a "..." item for lists and tuples)
+fn truncated_input_value(py: Python, input_value: &PyObject, max_input_length: Option<usize>) -> PyResult<PyObject> {
+    let max_length = match max_input_length {
+        Some(max_length) => max_length,
+        None => return Ok(input_value.clone_ref(py)),
+    };
+    let value = input_value.as_ref(py);
+    if let Ok(py_str) = value.cast_as::<PyString>() {
+        let s = py_str.to_string_lossy();
+        if s.chars().count() > max_length {
+            let mut truncated: String = s.chars().take(max_length).collect();
+            truncated.push_str(TRUNCATED_MARKER);
+            return Ok(truncated.into_py(py));
+        }
+    } else if let Ok(py_bytes) = value.cast_as::<PyBytes>() {
+        let bytes = py_bytes.as_bytes();
+        if bytes.len() > max_length {
+            let truncated = [&bytes[..max_length], TRUNCATED_MARKER.as_bytes()].concat();
+            return Ok(PyBytes::new(py, &truncated).into_py(py));
+        }
+    } else if let Ok(py_list) = value.cast_as::<PyList>() {
+        if py_list.len() > max_length {
+            let truncated = py_list.get_slice(0, max_length);
+            truncated.append(TRUNCATED_MARKER)?;
+            return Ok(truncated.into_py(py));
+        }
+    } else if let Ok(py_tuple) = value.cast_as::<PyTuple>() {
+        if py_tuple.len() > max_length {
+            let mut truncated: Vec<PyObject> = py_tuple.iter().take(max_length).map(|i| i.to_object(py)).collect();
+            truncated.push(TRUNCATED_MARKER.to_object(py));
+            return Ok(PyTuple::new(py, truncated).into_py(py));
+        }
+    }
+    Ok(input_value.clone_ref(py))
+}
+
 macro_rules! truncate_input_value {
     ($out:expr, $value:expr) => {
         if $value.len() > 50 {
@@ -115,14 +212,22 @@ pub struct PyLineError {
     kind: ErrorKind,
     location: Location,
     input_value: PyObject,
+    // only set for type errors, calculated here rather than when the error is created since many errors
+    // (e.g. from union choices) are never shown to the user
+    input_type: Option<String>,
 }
 
 impl<'a> IntoPy<PyLineError> for ValLineError<'a> {
     fn into_py(self, py: Python<'_>) -> PyLineError {
+        let input_type = match self.kind.is_type_error() {
+            true => Some(self.input_value.type_name(py)),
+            false => None,
+        };
         PyLineError {
             kind: self.kind,
             location: self.location,
             input_value: self.input_value.to_object(py),
+            input_type,
         }
     }
 }
@@ -139,22 +244,65 @@ impl<'a> From<PyLineError> for ValLineError<'a> {
 }
 
 impl PyLineError {
-    pub fn as_dict(&self, py: Python) -> PyResult<PyObject> {
+    pub fn as_dict(&self, py: Python, max_input_length: Option<usize>) -> PyResult<PyObject> {
         let dict = PyDict::new(py);
         dict.set_item("kind", self.kind.kind())?;
         dict.set_item("loc", self.location.to_object(py))?;
-        dict.set_item("message", self.kind.render_message(py)?)?;
-        dict.set_item("input_value", &self.input_value)?;
-        if let Some(context) = self.kind.py_dict(py)? {
+        dict.set_item("message", self.message(py)?)?;
+        let input_value = truncated_input_value(py, &self.input_value, max_input_length)?;
+        dict.set_item("input_value", input_value)?;
+        if let Some(context) = self.context(py)? {
             dict.set_item("context", context)?;
         }
         Ok(dict.into_py(py))
     }
 
+    fn as_json(&self, py: Python, include_input: bool, max_input_length: Option<usize>) -> PyResult<Value> {
+        let mut map = Map::new();
+        map.insert("kind".to_string(), Value::String(self.kind.kind()));
+        let loc = match self.location {
+            Location::List(ref loc) => loc.iter().rev().map(loc_item_as_json).collect(),
+            Location::Empty => Vec::new(),
+        };
+        map.insert("loc".to_string(), Value::Array(loc));
+        map.insert("message".to_string(), Value::String(self.message(py)?));
+        if include_input {
+            let input_value = truncated_input_value(py, &self.input_value, max_input_length)?;
+            map.insert("input_value".to_string(), py_as_json(input_value.as_ref(py), 0));
+        }
+        if let Some(context) = self.context(py)? {
+            map.insert("context".to_string(), py_as_json(context.as_ref(py), 0));
+        }
+        Ok(Value::Object(map))
+    }
+
+    fn message(&self, py: Python) -> PyResult<String> {
+        let message = self.kind.render_message(py)?;
+        match self.input_type {
+            Some(ref input_type) => Ok(format!("{}, input is of type `{}`", message, input_type)),
+            None => Ok(message),
+        }
+    }
+
+    fn context(&self, py: Python) -> PyResult<Option<Py<PyDict>>> {
+        match self.input_type {
+            Some(ref input_type) => {
+                let context = match self.kind.py_dict(py)? {
+                    Some(context) => context,
+                    None => PyDict::new(py).into(),
+                };
+                context.as_ref(py).set_item("input_type", input_type)?;
+                Ok(Some(context))
+            }
+            None => self.kind.py_dict(py),
+        }
+    }
+
     fn pretty(&self, py: Python) -> Result<String, fmt::Error> {
         let mut output = String::with_capacity(200);
         write!(output, "{}", self.location)?;
 
+        // the input type is shown at the end of the line so isn't included in the message here
         let message = match self.kind.render_message(py) {
             Ok(message) => message,
             Err(err) => format!("(error rendering message: {})", err),
@@ -162,9 +310,13 @@ impl PyLineError {
         write!(output, "  {} [kind={}", message, self.kind.kind())?;
 
         let input_value = self.input_value.as_ref(py);
+        // repr and str can both fail, e.g. with a `RecursionError` for deeply nested input
         let input_str = match repr_string(input_value) {
             Ok(s) => s,
-            Err(_) => input_value.to_string(),
+            Err(_) => match input_value.str() {
+                Ok(s) => s.to_string_lossy().to_string(),
+                Err(_) => "(unable to render input)".to_string(),
+            },
         };
         truncate_input_value!(output, input_str);
 
@@ -175,3 +327,69 @@ impl PyLineError {
         Ok(output)
     }
 }
+
+fn loc_item_as_json(loc_item: &LocItem) -> Value {
+    match loc_item {
+        LocItem::S(s) => Value::String(s.to_string()),
+        LocItem::I(i) => Value::Number((*i).into()),
+    }
+}
+
+/// nested values deeper than this are serialized as their `repr()`, this also protects against recursive objects
+const MAX_JSON_DEPTH: usize = 100;
+
+/// best-effort conversion of a python object to a JSON value, anything which can't be represented in JSON
+/// (or which would lose information, e.g. `NaN`) is serialized as its `repr()`
+fn py_as_json(value: &PyAny, depth: usize) -> Value {
+    let depth = depth + 1;
+    let as_json = |value: &PyAny| py_as_json(value, depth);
+    if depth > MAX_JSON_DEPTH {
+        // fall through to the `repr()` below
+    } else if value.is_none() {
+        return Value::Null;
+    } else if let Ok(py_bool) = value.cast_as::<PyBool>() {
+        return Value::Bool(py_bool.is_true());
+    } else if let Ok(py_str) = value.cast_as::<PyString>() {
+        return Value::String(py_str.to_string_lossy().to_string());
+    } else if value.cast_as::<PyInt>().is_ok() {
+        if let Ok(int) = value.extract::<i64>() {
+            return Value::Number(int.into());
+        }
+        // big ints, relies on serde_json's "arbitrary_precision" feature
+        if let Some(number) = value.str().ok().and_then(|s| s.to_str().ok()?.parse::<Number>().ok()) {
+            return Value::Number(number);
+        }
+    } else if let Ok(py_float) = value.cast_as::<PyFloat>() {
+        if let Some(number) = Number::from_f64(py_float.value()) {
+            return Value::Number(number);
+        }
+    } else if let Ok(py_bytes) = value.cast_as::<PyBytes>() {
+        if let Ok(s) = std::str::from_utf8(py_bytes.as_bytes()) {
+            return Value::String(s.to_string());
+        }
+    } else if let Ok(py_list) = value.cast_as::<PyList>() {
+        return Value::Array(py_list.iter().map(as_json).collect());
+    } else if let Ok(py_tuple) = value.cast_as::<PyTuple>() {
+        return Value::Array(py_tuple.iter().map(as_json).collect());
+    } else if let Ok(py_set) = value.cast_as::<PySet>() {
+        return Value::Array(py_set.iter().map(as_json).collect());
+    } else if let Ok(py_frozenset) = value.cast_as::<PyFrozenSet>() {
+        return Value::Array(py_frozenset.iter().map(as_json).collect());
+    } else if let Ok(py_dict) = value.cast_as::<PyDict>() {
+        let map: Map<String, Value> = py_dict
+            .iter()
+            .map(|(k, v)| {
+                let key = match k.cast_as::<PyString>() {
+                    Ok(py_str) => py_str.to_string_lossy().to_string(),
+                    Err(_) => repr_string(k).unwrap_or_else(|_| "(unable to render key)".to_string()),
+                };
+                (key, as_json(v))
+            })
+            .collect();
+        return Value::Object(map);
+    }
+    match repr_string(value) {
+        Ok(s) => Value::String(s),
+        Err(_) => Value::String("(unable to render input)".to_string()),
+    }
+}
@@ -18,11 +18,7 @@ pub struct PydanticValueError {
 impl PydanticValueError {
     #[new]
     fn py_new(py: Python, kind: String, message_template: String, context: Option<&PyDict>) -> Self {
-        Self {
-            kind,
-            message_template,
-            context: context.map(|c| c.into_py(py)),
-        }
+        Self::new(kind, message_template, context.map(|c| c.into_py(py)))
     }
 
     #[getter]
@@ -72,6 +68,14 @@ impl PydanticValueError {
 }
 
 impl PydanticValueError {
+    pub fn new(kind: String, message_template: String, context: Option<Py<PyDict>>) -> Self {
+        Self {
+            kind,
+            message_template,
+            context,
+        }
+    }
+
     pub fn into_val_error<'a>(self, input: &'a impl Input<'a>) -> ValError<'a> {
         let kind = ErrorKind::CustomError { value_error: self };
         ValError::new(kind, input)
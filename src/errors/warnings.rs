@@ -0,0 +1,60 @@
+use std::cell::RefCell;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::input::Input;
+
+use super::location::{LocItem, Location};
+
+/// A non-fatal note about how the input was validated, e.g. lax mode coercing the input to another type
+#[derive(Debug)]
+struct ValWarning {
+    message: String,
+    location: Location,
+    input_value: PyObject,
+}
+
+/// Collects warnings during validation, shared via `Extra::warnings`. Like errors, warnings are recorded without
+/// a location and validators of containers add outer locations to warnings recorded while validating each item.
+#[derive(Debug, Default)]
+pub struct ValWarnings(RefCell<Vec<ValWarning>>);
+
+impl ValWarnings {
+    pub fn add<'a>(&self, py: Python, message: String, input: &'a impl Input<'a>) {
+        self.0.borrow_mut().push(ValWarning {
+            message,
+            location: Location::default(),
+            input_value: input.as_error_value().to_object(py),
+        });
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// add an outer location to the warnings recorded since there were `start` warnings
+    pub fn with_outer_location(&self, start: usize, loc_item: LocItem) {
+        for warning in self.0.borrow_mut()[start..].iter_mut() {
+            warning.location.with_outer(loc_item.clone());
+        }
+    }
+
+    /// discard warnings recorded since there were `len` warnings, used when an error is suppressed,
+    /// e.g. by a union trying the next choice, since the warnings relate to a result which isn't used
+    pub fn truncate(&self, len: usize) {
+        self.0.borrow_mut().truncate(len);
+    }
+
+    pub fn into_py_list(self, py: Python) -> PyResult<&PyList> {
+        let list = PyList::empty(py);
+        for warning in self.0.into_inner() {
+            let dict = PyDict::new(py);
+            dict.set_item("loc", warning.location.to_object(py))?;
+            dict.set_item("message", warning.message)?;
+            dict.set_item("input_value", warning.input_value)?;
+            list.append(dict)?;
+        }
+        Ok(list)
+    }
+}
@@ -54,13 +54,14 @@ impl<'a> EitherDate<'a> {
 }
 
 pub enum EitherTime<'a> {
-    Raw(Time),
+    /// the second value is the timezone offset in seconds, if the time string included one
+    Raw(Time, Option<i32>),
     Py(&'a PyTime),
 }
 
 impl<'a> From<Time> for EitherTime<'a> {
     fn from(time: Time) -> Self {
-        Self::Raw(time)
+        Self::Raw(time, None)
     }
 }
 
@@ -154,20 +155,51 @@ pub(crate) use pytime_as_time;
 impl<'a> EitherTime<'a> {
     pub fn as_raw(&self) -> PyResult<Time> {
         match self {
-            Self::Raw(time) => Ok(time.clone()),
+            Self::Raw(time, _) => Ok(time.clone()),
             Self::Py(py_time) => Ok(pytime_as_time!(py_time)),
         }
     }
 
+    /// offset from UTC in seconds, `None` if the time is naive
+    pub fn offset(&self) -> PyResult<Option<i32>> {
+        match self {
+            Self::Raw(_, offset) => Ok(*offset),
+            Self::Py(py_time) => {
+                let py = py_time.py();
+                // as with datetimes, utcoffset() can return None even if tzinfo is set
+                let offset_delta = py_time.getattr(intern!(py, "utcoffset"))?.call0()?;
+                if offset_delta.is_none() {
+                    Ok(None)
+                } else {
+                    let offset_seconds: f64 = offset_delta.getattr(intern!(py, "total_seconds"))?.call0()?.extract()?;
+                    Ok(Some(offset_seconds.round() as i32))
+                }
+            }
+        }
+    }
+
     pub fn try_into_py(self, py: Python<'_>) -> PyResult<PyObject> {
         let time = match self {
             Self::Py(time) => Ok(time),
-            Self::Raw(time) => PyTime::new(py, time.hour, time.minute, time.second, time.microsecond, None),
+            Self::Raw(time, offset) => {
+                let tz = offset_as_tzinfo(py, offset)?;
+                PyTime::new(py, time.hour, time.minute, time.second, time.microsecond, tz.as_ref())
+            }
         }?;
         Ok(time.into_py(py))
     }
 }
 
+fn offset_as_tzinfo(py: Python, offset: Option<i32>) -> PyResult<Option<PyObject>> {
+    match offset {
+        Some(offset) => {
+            let tz_info = TzInfo::new(offset);
+            Ok(Some(Py::new(py, tz_info)?.to_object(py)))
+        }
+        None => Ok(None),
+    }
+}
+
 pub enum EitherDateTime<'a> {
     Raw(DateTime),
     Py(&'a PyDateTime),
@@ -217,13 +249,7 @@ impl<'a> EitherDateTime<'a> {
     pub fn try_into_py(self, py: Python<'a>) -> PyResult<PyObject> {
         let dt = match self {
             Self::Raw(datetime) => {
-                let tz: Option<PyObject> = match datetime.offset {
-                    Some(offset) => {
-                        let tz_info = TzInfo::new(offset);
-                        Some(Py::new(py, tz_info)?.to_object(py))
-                    }
-                    None => None,
-                };
+                let tz = offset_as_tzinfo(py, datetime.offset)?;
                 PyDateTime::new(
                     py,
                     datetime.date.year as i32,
@@ -255,14 +281,41 @@ pub fn bytes_as_date<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<'
 }
 
 pub fn bytes_as_time<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<'a, EitherTime<'a>> {
-    match Time::parse_bytes(bytes) {
-        Ok(date) => Ok(date.into()),
-        Err(err) => Err(ValError::new(
-            ErrorKind::TimeParsing {
-                error: err.get_documentation().unwrap_or_default(),
-            },
-            input,
-        )),
+    let time_parsing_err = |error| ValError::new(ErrorKind::TimeParsing { error }, input);
+    // speedate doesn't support timezone offsets on times, so we split off and parse the offset here
+    let (time_bytes, offset) = match bytes.iter().position(|b| matches!(b, b'Z' | b'z' | b'+' | b'-')) {
+        Some(index) => match parse_offset(&bytes[index..]) {
+            Some(offset) => (&bytes[..index], Some(offset)),
+            None => return Err(time_parsing_err("invalid timezone offset")),
+        },
+        None => (bytes, None),
+    };
+    match Time::parse_bytes(time_bytes) {
+        Ok(time) => Ok(EitherTime::Raw(time, offset)),
+        Err(err) => Err(time_parsing_err(err.get_documentation().unwrap_or_default())),
+    }
+}
+
+/// parse a timezone offset in the form `Z`, `±HH`, `±HHMM` or `±HH:MM` into seconds
+fn parse_offset(bytes: &[u8]) -> Option<i32> {
+    let (sign, rest) = match bytes {
+        [b'Z' | b'z'] => return Some(0),
+        [b'+', rest @ ..] => (1, rest),
+        [b'-', rest @ ..] => (-1, rest),
+        _ => return None,
+    };
+    let (hour, minute) = match rest {
+        [h1, h2] => ([*h1, *h2], [b'0', b'0']),
+        [h1, h2, b':', m1, m2] | [h1, h2, m1, m2] => ([*h1, *h2], [*m1, *m2]),
+        _ => return None,
+    };
+    let two_digits = |digits: [u8; 2]| match digits {
+        [d1 @ b'0'..=b'9', d2 @ b'0'..=b'9'] => Some(((d1 - b'0') * 10 + (d2 - b'0')) as i32),
+        _ => None,
+    };
+    match (two_digits(hour)?, two_digits(minute)?) {
+        (hour, minute) if hour < 24 && minute < 60 => Some(sign * (hour * 3600 + minute * 60)),
+        _ => None,
     }
 }
 
@@ -356,15 +409,36 @@ pub fn float_as_time<'a>(input: &'a impl Input<'a>, timestamp: f64) -> ValResult
 }
 
 pub fn bytes_as_timedelta<'a, 'b>(input: &'a impl Input<'a>, bytes: &'b [u8]) -> ValResult<'a, EitherTimedelta<'a>> {
+    let time_delta_parsing_err = |error| ValError::new(ErrorKind::TimeDeltaParsing { error }, input);
+    if has_years_or_months(bytes) {
+        return Err(time_delta_parsing_err(
+            "years and months are not supported since their length varies",
+        ));
+    }
     match Duration::parse_bytes(bytes) {
         Ok(dt) => Ok(dt.into()),
-        Err(err) => Err(ValError::new(
-            ErrorKind::TimeDeltaParsing {
-                error: err.get_documentation().unwrap_or_default(),
-            },
-            input,
-        )),
+        Err(err) => Err(time_delta_parsing_err(err.get_documentation().unwrap_or_default())),
+    }
+}
+
+/// whether an ISO 8601 duration has non-zero years or months, speedate would treat them as 365 and 30 days
+/// which silently gives the wrong duration, zero values (e.g. `P0Y0M3D`) are allowed
+fn has_years_or_months(bytes: &[u8]) -> bool {
+    let date_part = match bytes.iter().position(|b| b.eq_ignore_ascii_case(&b'P')) {
+        Some(index) => &bytes[index + 1..],
+        None => return false,
+    };
+    let mut non_zero = false;
+    for b in date_part.iter().map(u8::to_ascii_uppercase) {
+        match b {
+            b'T' => break,
+            b'1'..=b'9' => non_zero = true,
+            b'0' | b'.' | b',' => (),
+            b'Y' | b'M' if non_zero => return true,
+            _ => non_zero = false,
+        }
     }
+    false
 }
 
 pub fn int_as_duration(total_seconds: i64) -> Duration {
@@ -398,15 +472,16 @@ impl TzInfo {
         Self { seconds }
     }
 
-    fn utcoffset<'p>(&self, py: Python<'p>, _dt: &PyDateTime) -> PyResult<&'p PyDelta> {
+    // `_dt` is the datetime, or `None` when called for a time
+    fn utcoffset<'p>(&self, py: Python<'p>, _dt: &PyAny) -> PyResult<&'p PyDelta> {
         PyDelta::new(py, 0, self.seconds, 0, true)
     }
 
-    fn tzname(&self, _dt: &PyDateTime) -> String {
+    fn tzname(&self, _dt: &PyAny) -> String {
         self.__str__()
     }
 
-    fn dst(&self, _dt: &PyDateTime) -> Option<&PyDelta> {
+    fn dst(&self, _dt: &PyAny) -> Option<&PyDelta> {
         None
     }
 
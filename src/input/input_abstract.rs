@@ -8,7 +8,7 @@ use crate::input::datetime::EitherTime;
 
 use super::datetime::{EitherDate, EitherDateTime, EitherTimedelta};
 use super::return_enums::{EitherBytes, EitherString};
-use super::{GenericListLike, GenericMapping};
+use super::{GenericListLike, GenericMapping, JsonInput};
 
 /// all types have three methods: `validate_*`, `strict_*`, `lax_*`
 /// the convention is to either implement:
@@ -38,10 +38,37 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         Ok(false)
     }
 
+    /// whether the input is a python object of exactly this type (not a subclass), immutable input like this
+    /// can be returned unchanged rather than creating a new, equal object
+    fn is_exact_instance(&self, _class: &PyType) -> bool {
+        false
+    }
+
+    /// whether the input is already an int (but not a bool), unlike `strict_int().is_ok()` no error is created
+    /// so this is cheap enough to use as a type check on the hot path
+    fn is_exact_int(&self) -> bool {
+        false
+    }
+
+    /// whether the input is already a float, see `is_exact_int`
+    fn is_exact_float(&self) -> bool {
+        false
+    }
+
     fn callable(&self) -> bool {
         false
     }
 
+    /// whether the input is a python object rather than parsed JSON, some python types (e.g. enum members)
+    /// have no JSON equivalent so strict validation of JSON input has to be more lenient
+    fn is_python(&self) -> bool {
+        false
+    }
+
+    /// parse the input as a JSON document, used by the json validator, only strings (and in python bytes)
+    /// can be parsed, `None` is returned for other types
+    fn parse_json(&'a self, reject_duplicate_keys: bool) -> ValResult<'a, Option<JsonInput>>;
+
     fn validate_str(&'a self, strict: bool) -> ValResult<EitherString<'a>> {
         if strict {
             self.strict_str()
@@ -50,6 +77,14 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         }
     }
     fn strict_str(&'a self) -> ValResult<EitherString<'a>>;
+
+    /// the input if it's already a string, used as a fast path before checking other types in lax mode
+    fn exact_str(&'a self) -> Option<EitherString<'a>>;
+
+    /// decode bytes to a string with a python codec, `None` if the input isn't bytes (e.g. all JSON input)
+    fn decode_str(&'a self, _encoding: &str) -> Option<ValResult<EitherString<'a>>> {
+        None
+    }
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn lax_str(&'a self) -> ValResult<EitherString<'a>> {
         self.strict_str()
@@ -67,6 +102,11 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
     fn lax_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
         self.strict_bytes()
     }
+    /// used in strict mode when `strict_bytes_type` is set, only `bytes` itself is accepted, e.g. not `bytearray`
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn exact_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+        self.strict_bytes()
+    }
 
     fn validate_bool(&self, strict: bool) -> ValResult<bool> {
         if strict {
@@ -81,6 +121,12 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.strict_bool()
     }
 
+    /// used by the int validator when `validate_int` fails, integers which are too large for an `i64` are
+    /// returned as python ints, in lax mode this includes strings of such integers
+    fn as_big_int(&'a self, _py: Python, _strict: bool) -> ValResult<'a, Option<PyObject>> {
+        Ok(None)
+    }
+
     fn validate_int(&self, strict: bool) -> ValResult<i64> {
         if strict {
             self.strict_int()
@@ -102,11 +148,29 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         }
     }
     fn strict_float(&self) -> ValResult<f64>;
+    /// used in strict mode when `strict_float_reject_int` is set, only floats are accepted, e.g. not ints
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn exact_float(&self) -> ValResult<f64> {
+        self.strict_float()
+    }
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn lax_float(&self) -> ValResult<f64> {
         self.strict_float()
     }
 
+    fn validate_decimal(&'a self, py: Python<'a>, strict: bool) -> ValResult<&'a PyAny> {
+        if strict {
+            self.strict_decimal(py)
+        } else {
+            self.lax_decimal(py)
+        }
+    }
+    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny>;
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn lax_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+        self.strict_decimal(py)
+    }
+
     fn validate_dict(&'a self, strict: bool) -> ValResult<GenericMapping<'a>> {
         if strict {
             self.strict_dict()
@@ -124,16 +188,17 @@ pub trait Input<'a>: fmt::Debug + ToPyObject {
         self.validate_dict(strict)
     }
 
-    fn validate_list(&'a self, strict: bool) -> ValResult<GenericListLike<'a>> {
+    /// `max_length` lets inputs without a length (e.g. iterators) stop reading once they're known to be too long
+    fn validate_list(&'a self, strict: bool, max_length: Option<usize>) -> ValResult<GenericListLike<'a>> {
         if strict {
             self.strict_list()
         } else {
-            self.lax_list()
+            self.lax_list(max_length)
         }
     }
     fn strict_list(&'a self) -> ValResult<GenericListLike<'a>>;
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn lax_list(&'a self) -> ValResult<GenericListLike<'a>> {
+    fn lax_list(&'a self, _max_length: Option<usize>) -> ValResult<GenericListLike<'a>> {
         self.strict_list()
     }
 
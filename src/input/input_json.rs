@@ -1,10 +1,16 @@
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+
 use crate::errors::{ErrorKind, InputValue, LocItem, ValError, ValResult};
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
     float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
 };
-use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_int};
+use super::parse_json::{big_int_to_py, parse_json_bytes};
+use super::shared::{
+    create_decimal, float_as_bool, float_as_int, int_as_bool, str_as_big_int, str_as_bool, str_as_float, str_as_int,
+};
 use super::{EitherBytes, EitherString, EitherTimedelta, GenericListLike, GenericMapping, Input, JsonInput};
 
 impl<'a> Input<'a> for JsonInput {
@@ -26,17 +32,52 @@ impl<'a> Input<'a> for JsonInput {
         matches!(self, JsonInput::Null)
     }
 
+    fn is_exact_int(&self) -> bool {
+        matches!(self, JsonInput::Int(_) | JsonInput::BigInt(_))
+    }
+
+    fn is_exact_float(&self) -> bool {
+        matches!(self, JsonInput::Float(..))
+    }
+
+    fn as_big_int(&'a self, py: Python, strict: bool) -> ValResult<'a, Option<PyObject>> {
+        match self {
+            JsonInput::BigInt(int) => match big_int_to_py(py, int) {
+                Some(int) => Ok(Some(int)),
+                None => Err(ValError::new(ErrorKind::IntParsing, self)),
+            },
+            JsonInput::String(str) if !strict => Ok(str_as_big_int(py, str)),
+            _ => Ok(None),
+        }
+    }
+
+    fn parse_json(&'a self, reject_duplicate_keys: bool) -> ValResult<'a, Option<JsonInput>> {
+        match self {
+            JsonInput::String(s) => parse_json_bytes(s.as_bytes(), reject_duplicate_keys)
+                .map(Some)
+                .map_err(|kind| ValError::new(kind, self)),
+            _ => Ok(None),
+        }
+    }
+
     fn strict_str(&'a self) -> ValResult<EitherString<'a>> {
         match self {
             JsonInput::String(s) => Ok(s.as_str().into()),
             _ => Err(ValError::new(ErrorKind::StrType, self)),
         }
     }
+    fn exact_str(&'a self) -> Option<EitherString<'a>> {
+        match self {
+            JsonInput::String(s) => Some(s.as_str().into()),
+            _ => None,
+        }
+    }
     fn lax_str(&'a self) -> ValResult<EitherString<'a>> {
         match self {
             JsonInput::String(s) => Ok(s.as_str().into()),
             JsonInput::Int(int) => Ok(int.to_string().into()),
-            JsonInput::Float(float) => Ok(float.to_string().into()),
+            JsonInput::BigInt(int) => Ok(int.as_str().into()),
+            JsonInput::Float(float, _) => Ok(float.to_string().into()),
             _ => Err(ValError::new(ErrorKind::StrType, self)),
         }
     }
@@ -63,10 +104,8 @@ impl<'a> Input<'a> for JsonInput {
             JsonInput::Bool(b) => Ok(*b),
             JsonInput::String(s) => str_as_bool(self, s),
             JsonInput::Int(int) => int_as_bool(self, *int),
-            JsonInput::Float(float) => match float_as_int(self, *float) {
-                Ok(int) => int_as_bool(self, int),
-                _ => Err(ValError::new(ErrorKind::BoolType, self)),
-            },
+            JsonInput::BigInt(_) => Err(ValError::new(ErrorKind::BoolParsing, self)),
+            JsonInput::Float(float, _) => float_as_bool(self, *float),
             _ => Err(ValError::new(ErrorKind::BoolType, self)),
         }
     }
@@ -74,6 +113,7 @@ impl<'a> Input<'a> for JsonInput {
     fn strict_int(&self) -> ValResult<i64> {
         match self {
             JsonInput::Int(i) => Ok(*i),
+            JsonInput::BigInt(_) => Err(ValError::new(ErrorKind::IntOverflow, self)),
             _ => Err(ValError::new(ErrorKind::IntType, self)),
         }
     }
@@ -84,7 +124,8 @@ impl<'a> Input<'a> for JsonInput {
                 false => Ok(0),
             },
             JsonInput::Int(i) => Ok(*i),
-            JsonInput::Float(f) => float_as_int(self, *f),
+            JsonInput::BigInt(_) => Err(ValError::new(ErrorKind::IntOverflow, self)),
+            JsonInput::Float(f, _) => float_as_int(self, *f),
             JsonInput::String(str) => str_as_int(self, str),
             _ => Err(ValError::new(ErrorKind::IntType, self)),
         }
@@ -92,8 +133,15 @@ impl<'a> Input<'a> for JsonInput {
 
     fn strict_float(&self) -> ValResult<f64> {
         match self {
-            JsonInput::Float(f) => Ok(*f),
+            JsonInput::Float(f, _) => Ok(*f),
             JsonInput::Int(i) => Ok(*i as f64),
+            JsonInput::BigInt(i) => str_as_float(self, i),
+            _ => Err(ValError::new(ErrorKind::FloatType, self)),
+        }
+    }
+    fn exact_float(&self) -> ValResult<f64> {
+        match self {
+            JsonInput::Float(f, _) => Ok(*f),
             _ => Err(ValError::new(ErrorKind::FloatType, self)),
         }
     }
@@ -103,16 +151,35 @@ impl<'a> Input<'a> for JsonInput {
                 true => Ok(1.0),
                 false => Ok(0.0),
             },
-            JsonInput::Float(f) => Ok(*f),
+            JsonInput::Float(f, _) => Ok(*f),
             JsonInput::Int(i) => Ok(*i as f64),
-            JsonInput::String(str) => match str.parse() {
-                Ok(i) => Ok(i),
-                Err(_) => Err(ValError::new(ErrorKind::FloatParsing, self)),
-            },
+            JsonInput::BigInt(i) => str_as_float(self, i),
+            JsonInput::String(str) => str_as_float(self, str),
             _ => Err(ValError::new(ErrorKind::FloatType, self)),
         }
     }
 
+    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+        match self {
+            JsonInput::Int(i) => create_decimal(py, i.to_object(py).into_ref(py), self),
+            JsonInput::BigInt(i) => create_decimal(py, PyString::new(py, i), self),
+            JsonInput::String(s) => create_decimal(py, PyString::new(py, s), self),
+            _ => Err(ValError::new(ErrorKind::DecimalType, self)),
+        }
+    }
+    fn lax_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+        match self {
+            JsonInput::Int(i) => create_decimal(py, i.to_object(py).into_ref(py), self),
+            JsonInput::BigInt(i) => create_decimal(py, PyString::new(py, i), self),
+            JsonInput::Float(float, text) => match text {
+                Some(text) => create_decimal(py, PyString::new(py, text), self),
+                None => create_decimal(py, PyString::new(py, &float.to_string()), self),
+            },
+            JsonInput::String(s) => create_decimal(py, PyString::new(py, s), self),
+            _ => Err(ValError::new(ErrorKind::DecimalType, self)),
+        }
+    }
+
     fn validate_dict(&'a self, _strict: bool) -> ValResult<GenericMapping<'a>> {
         match self {
             JsonInput::Object(dict) => Ok(dict.into()),
@@ -124,7 +191,7 @@ impl<'a> Input<'a> for JsonInput {
         self.validate_dict(false)
     }
 
-    fn validate_list(&'a self, _strict: bool) -> ValResult<GenericListLike<'a>> {
+    fn validate_list(&'a self, _strict: bool, _max_length: Option<usize>) -> ValResult<GenericListLike<'a>> {
         match self {
             JsonInput::Array(a) => Ok(a.into()),
             _ => Err(ValError::new(ErrorKind::ListType, self)),
@@ -132,7 +199,7 @@ impl<'a> Input<'a> for JsonInput {
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn strict_list(&'a self) -> ValResult<GenericListLike<'a>> {
-        self.validate_list(false)
+        self.validate_list(false, None)
     }
 
     fn validate_tuple(&'a self, _strict: bool) -> ValResult<GenericListLike<'a>> {
@@ -194,7 +261,8 @@ impl<'a> Input<'a> for JsonInput {
         match self {
             JsonInput::String(v) => bytes_as_time(self, v.as_bytes()),
             JsonInput::Int(v) => int_as_time(self, *v, 0),
-            JsonInput::Float(v) => float_as_time(self, *v),
+            JsonInput::Float(v, _) => float_as_time(self, *v),
+            JsonInput::BigInt(v) => float_as_time(self, str_as_float(self, v)?),
             _ => Err(ValError::new(ErrorKind::TimeType, self)),
         }
     }
@@ -209,7 +277,8 @@ impl<'a> Input<'a> for JsonInput {
         match self {
             JsonInput::String(v) => bytes_as_datetime(self, v.as_bytes()),
             JsonInput::Int(v) => int_as_datetime(self, *v, 0),
-            JsonInput::Float(v) => float_as_datetime(self, *v),
+            JsonInput::Float(v, _) => float_as_datetime(self, *v),
+            JsonInput::BigInt(v) => float_as_datetime(self, str_as_float(self, v)?),
             _ => Err(ValError::new(ErrorKind::DateTimeType, self)),
         }
     }
@@ -224,7 +293,8 @@ impl<'a> Input<'a> for JsonInput {
         match self {
             JsonInput::String(v) => bytes_as_timedelta(self, v.as_bytes()),
             JsonInput::Int(v) => Ok(int_as_duration(*v).into()),
-            JsonInput::Float(v) => Ok(float_as_duration(*v).into()),
+            JsonInput::Float(v, _) => Ok(float_as_duration(*v).into()),
+            JsonInput::BigInt(v) => Ok(float_as_duration(str_as_float(self, v)?).into()),
             _ => Err(ValError::new(ErrorKind::TimeDeltaType, self)),
         }
     }
@@ -245,12 +315,21 @@ impl<'a> Input<'a> for String {
         false
     }
 
+    fn parse_json(&'a self, reject_duplicate_keys: bool) -> ValResult<'a, Option<JsonInput>> {
+        parse_json_bytes(self.as_bytes(), reject_duplicate_keys)
+            .map(Some)
+            .map_err(|kind| ValError::new(kind, self))
+    }
+
     fn validate_str(&'a self, _strict: bool) -> ValResult<EitherString<'a>> {
         Ok(self.as_str().into())
     }
     fn strict_str(&'a self) -> ValResult<EitherString<'a>> {
         self.validate_str(false)
     }
+    fn exact_str(&'a self) -> Option<EitherString<'a>> {
+        Some(self.as_str().into())
+    }
 
     fn validate_bytes(&'a self, _strict: bool) -> ValResult<EitherBytes<'a>> {
         Ok(self.as_bytes().into())
@@ -267,14 +346,22 @@ impl<'a> Input<'a> for String {
         str_as_bool(self, self)
     }
 
+    /// JSON object keys are always strings, so the int validator in strict mode allows keys which are exactly
+    /// an integer, e.g. for `Dict[int, str]`, but not surrounding whitespace, underscores, a leading `+` or floats
+    /// as in lax mode
+    fn validate_int(&self, strict: bool) -> ValResult<i64> {
+        match strict {
+            // `str::parse` accepts a leading `+`, JSON numbers don't
+            true if self.starts_with('+') => Err(ValError::new(ErrorKind::IntParsing, self)),
+            true => self.parse().map_err(|_| ValError::new(ErrorKind::IntParsing, self)),
+            false => self.lax_int(),
+        }
+    }
     fn strict_int(&self) -> ValResult<i64> {
         Err(ValError::new(ErrorKind::IntType, self))
     }
     fn lax_int(&self) -> ValResult<i64> {
-        match self.parse() {
-            Ok(i) => Ok(i),
-            Err(_) => Err(ValError::new(ErrorKind::IntParsing, self)),
-        }
+        str_as_int(self, self)
     }
 
     #[cfg_attr(has_no_coverage, no_coverage)]
@@ -282,10 +369,15 @@ impl<'a> Input<'a> for String {
         Err(ValError::new(ErrorKind::FloatType, self))
     }
     fn lax_float(&self) -> ValResult<f64> {
-        match self.parse() {
-            Ok(i) => Ok(i),
-            Err(_) => Err(ValError::new(ErrorKind::FloatParsing, self)),
-        }
+        str_as_float(self, self)
+    }
+
+    fn validate_decimal(&'a self, py: Python<'a>, _strict: bool) -> ValResult<&'a PyAny> {
+        create_decimal(py, PyString::new(py, self), self)
+    }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+        self.validate_decimal(py, false)
     }
 
     #[cfg_attr(has_no_coverage, no_coverage)]
@@ -298,12 +390,12 @@ impl<'a> Input<'a> for String {
     }
 
     #[cfg_attr(has_no_coverage, no_coverage)]
-    fn validate_list(&'a self, _strict: bool) -> ValResult<GenericListLike<'a>> {
+    fn validate_list(&'a self, _strict: bool, _max_length: Option<usize>) -> ValResult<GenericListLike<'a>> {
         Err(ValError::new(ErrorKind::ListType, self))
     }
     #[cfg_attr(has_no_coverage, no_coverage)]
     fn strict_list(&'a self) -> ValResult<GenericListLike<'a>> {
-        self.validate_list(false)
+        self.validate_list(false, None)
     }
 
     #[cfg_attr(has_no_coverage, no_coverage)]
@@ -1,10 +1,10 @@
 use std::str::from_utf8;
 
-use pyo3::exceptions::PyAttributeError;
+use pyo3::exceptions::{PyAttributeError, PyUnicodeDecodeError};
 use pyo3::prelude::*;
 use pyo3::types::{
-    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFrozenSet, PyInt, PyList, PyMapping,
-    PySequence, PySet, PyString, PyTime, PyTuple, PyType,
+    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyInt, PyIterator, PyList,
+    PyMapping, PySequence, PySet, PyString, PyTime, PyTuple, PyType,
 };
 use pyo3::{intern, AsPyPointer};
 
@@ -15,8 +15,14 @@ use super::datetime::{
     float_as_duration, float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime,
     EitherTime,
 };
-use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_int};
-use super::{repr_string, EitherBytes, EitherString, EitherTimedelta, GenericListLike, GenericMapping, Input};
+use super::parse_json::{parse_json_allow_threads, py_json_data};
+use super::shared::{
+    create_decimal, float_as_bool, float_as_int, get_decimal_type, get_enum_type, int_as_bool, str_as_big_int,
+    str_as_bool, str_as_float, str_as_int,
+};
+use super::{
+    repr_string, EitherBytes, EitherString, EitherTimedelta, GenericListLike, GenericMapping, Input, JsonInput,
+};
 
 impl<'a> Input<'a> for PyAny {
     fn as_loc_item(&self) -> LocItem {
@@ -56,10 +62,52 @@ impl<'a> Input<'a> for PyAny {
         self.is_instance(class)
     }
 
+    fn is_exact_instance(&self, class: &PyType) -> bool {
+        self.get_type().is(class)
+    }
+
+    fn is_exact_int(&self) -> bool {
+        self.cast_as::<PyInt>().is_ok() && self.cast_as::<PyBool>().is_err()
+    }
+
+    fn is_exact_float(&self) -> bool {
+        self.cast_as::<PyFloat>().is_ok()
+    }
+
     fn callable(&self) -> bool {
         self.is_callable()
     }
 
+    fn as_big_int(&'a self, py: Python, strict: bool) -> ValResult<'a, Option<PyObject>> {
+        if let Ok(int) = self.cast_as::<PyInt>() {
+            match self.cast_as::<PyBool>() {
+                Ok(_) => Ok(None),
+                Err(_) => Ok(Some(int.into_py(py))),
+            }
+        } else if strict {
+            Ok(None)
+        } else {
+            match maybe_as_string(self, ErrorKind::IntParsing)? {
+                Some(either_str) => Ok(str_as_big_int(py, &either_str.as_cow())),
+                None => Ok(None),
+            }
+        }
+    }
+
+    fn is_python(&self) -> bool {
+        true
+    }
+
+    fn parse_json(&'a self, reject_duplicate_keys: bool) -> ValResult<'a, Option<JsonInput>> {
+        let json_data = match py_json_data(self)? {
+            Some(json_data) => json_data,
+            None => return Ok(None),
+        };
+        parse_json_allow_threads(self.py(), &json_data, reject_duplicate_keys)
+            .map(Some)
+            .map_err(|kind| ValError::new(kind, self))
+    }
+
     fn strict_str(&'a self) -> ValResult<EitherString<'a>> {
         if let Ok(py_str) = self.cast_as::<PyString>() {
             Ok(py_str.into())
@@ -68,28 +116,70 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
+    fn exact_str(&'a self) -> Option<EitherString<'a>> {
+        self.cast_as::<PyString>().ok().map(Into::into)
+    }
+
+    fn decode_str(&'a self, encoding: &str) -> Option<ValResult<EitherString<'a>>> {
+        if self.cast_as::<PyBytes>().is_err() && self.cast_as::<PyByteArray>().is_err() {
+            return None;
+        }
+        let py = self.py();
+        let result = match self.call_method1("decode", (encoding,)) {
+            Ok(py_str) => py_str
+                .cast_as::<PyString>()
+                .map(|py_str| py_str.into())
+                .map_err(Into::into),
+            Err(err) if err.is_instance_of::<PyUnicodeDecodeError>(py) => {
+                match err
+                    .value(py)
+                    .getattr(intern!(py, "start"))
+                    .and_then(|start| start.extract())
+                {
+                    Ok(position) => Err(ValError::new(ErrorKind::StrUnicode { position }, self)),
+                    Err(err) => Err(err.into()),
+                }
+            }
+            Err(err) => Err(err.into()),
+        };
+        Some(result)
+    }
+
     fn lax_str(&'a self) -> ValResult<EitherString<'a>> {
         if let Ok(py_str) = self.cast_as::<PyString>() {
             Ok(py_str.into())
         } else if let Ok(bytes) = self.cast_as::<PyBytes>() {
             let str = match from_utf8(bytes.as_bytes()) {
                 Ok(s) => s,
-                Err(_) => return Err(ValError::new(ErrorKind::StrUnicode, self)),
+                Err(err) => {
+                    let position = err.valid_up_to();
+                    return Err(ValError::new(ErrorKind::StrUnicode { position }, self));
+                }
             };
             Ok(str.into())
         } else if let Ok(py_byte_array) = self.cast_as::<PyByteArray>() {
             let str = match from_utf8(unsafe { py_byte_array.as_bytes() }) {
                 Ok(s) => s,
-                Err(_) => return Err(ValError::new(ErrorKind::StrUnicode, self)),
+                Err(err) => {
+                    let position = err.valid_up_to();
+                    return Err(ValError::new(ErrorKind::StrUnicode { position }, self));
+                }
             };
             Ok(str.into())
+        } else if self.is_instance(get_enum_type(self.py()))? {
+            // members of enums which don't subclass `str` are accepted if their value is a string, this is checked
+            // before ints and floats so e.g. `IntEnum` members aren't stringified
+            match self.getattr(intern!(self.py(), "value"))?.cast_as::<PyString>() {
+                Ok(py_str) => Ok(py_str.into()),
+                Err(_) => Err(ValError::new(ErrorKind::StrType, self)),
+            }
         } else if self.cast_as::<PyBool>().is_ok() {
             // do this before int and float parsing as `False` is cast to `0` and we don't want False to
             // be returned as a string
             Err(ValError::new(ErrorKind::StrType, self))
         } else if let Ok(int) = self.cast_as::<PyInt>() {
-            let int = i64::extract(int)?;
-            Ok(int.to_string().into())
+            // `str()` rather than extracting an `i64` so ints of any size are accepted, as with JSON
+            Ok(int.str()?.into())
         } else if let Ok(float) = f64::extract(self) {
             // don't cast_as here so Decimals are covered - internally f64:extract uses PyFloat_AsDouble
             Ok(float.to_string().into())
@@ -99,6 +189,17 @@ impl<'a> Input<'a> for PyAny {
     }
 
     fn strict_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
+        if let Ok(py_bytes) = self.cast_as::<PyBytes>() {
+            Ok(py_bytes.into())
+        } else if let Ok(py_byte_array) = self.cast_as::<PyByteArray>() {
+            // copied so later mutation of the bytearray can't change the validated value
+            Ok(py_byte_array.to_vec().into())
+        } else {
+            Err(ValError::new(ErrorKind::BytesType, self))
+        }
+    }
+
+    fn exact_bytes(&'a self) -> ValResult<EitherBytes<'a>> {
         if let Ok(py_bytes) = self.cast_as::<PyBytes>() {
             Ok(py_bytes.into())
         } else {
@@ -135,10 +236,7 @@ impl<'a> Input<'a> for PyAny {
         } else if let Ok(int) = self.extract::<i64>() {
             int_as_bool(self, int)
         } else if let Ok(float) = self.extract::<f64>() {
-            match float_as_int(self, float) {
-                Ok(int) => int_as_bool(self, int),
-                _ => Err(ValError::new(ErrorKind::BoolType, self)),
-            }
+            float_as_bool(self, float)
         } else {
             Err(ValError::new(ErrorKind::BoolType, self))
         }
@@ -150,6 +248,8 @@ impl<'a> Input<'a> for PyAny {
             Err(ValError::new(ErrorKind::IntType, self))
         } else if let Ok(int) = self.extract::<i64>() {
             Ok(int)
+        } else if self.cast_as::<PyInt>().is_ok() {
+            Err(ValError::new(ErrorKind::IntOverflow, self))
         } else {
             Err(ValError::new(ErrorKind::IntType, self))
         }
@@ -158,6 +258,8 @@ impl<'a> Input<'a> for PyAny {
     fn lax_int(&self) -> ValResult<i64> {
         if let Ok(int) = self.extract::<i64>() {
             Ok(int)
+        } else if self.cast_as::<PyInt>().is_ok() {
+            Err(ValError::new(ErrorKind::IntOverflow, self))
         } else if let Some(either_str) = maybe_as_string(self, ErrorKind::IntParsing)? {
             str_as_int(self, &either_str.as_cow())
         } else if let Ok(float) = self.lax_float() {
@@ -177,19 +279,56 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
+    fn exact_float(&self) -> ValResult<f64> {
+        if let Ok(py_float) = self.cast_as::<PyFloat>() {
+            Ok(py_float.value())
+        } else {
+            Err(ValError::new(ErrorKind::FloatType, self))
+        }
+    }
+
     fn lax_float(&self) -> ValResult<f64> {
         if let Ok(float) = self.extract::<f64>() {
             Ok(float)
         } else if let Some(either_str) = maybe_as_string(self, ErrorKind::FloatParsing)? {
-            match either_str.as_cow().as_ref().parse() {
-                Ok(i) => Ok(i),
-                Err(_) => Err(ValError::new(ErrorKind::FloatParsing, self)),
-            }
+            str_as_float(self, &either_str.as_cow())
         } else {
             Err(ValError::new(ErrorKind::FloatType, self))
         }
     }
 
+    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+        if self.is_instance(get_decimal_type(py))? {
+            Ok(self)
+        } else if let Ok(py_str) = self.cast_as::<PyString>() {
+            create_decimal(py, py_str, self)
+        } else if self.cast_as::<PyBool>().is_ok() {
+            Err(ValError::new(ErrorKind::DecimalType, self))
+        } else if self.cast_as::<PyInt>().is_ok() {
+            create_decimal(py, self, self)
+        } else {
+            // floats are deliberately excluded here since they can't represent most decimals exactly
+            Err(ValError::new(ErrorKind::DecimalType, self))
+        }
+    }
+
+    fn lax_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+        if self.is_instance(get_decimal_type(py))? {
+            Ok(self)
+        } else if let Some(either_str) = maybe_as_string(self, ErrorKind::DecimalParsing)? {
+            create_decimal(py, PyString::new(py, &either_str.as_cow()), self)
+        } else if self.cast_as::<PyBool>().is_ok() {
+            Err(ValError::new(ErrorKind::DecimalType, self))
+        } else if self.cast_as::<PyInt>().is_ok() {
+            create_decimal(py, self, self)
+        } else if let Ok(float) = self.cast_as::<PyFloat>() {
+            // go via `str(float)` so the decimal matches the float's shortest repr, e.g. 0.1 -> Decimal('0.1')
+            create_decimal(py, float.str()?, self)
+        } else {
+            Err(ValError::new(ErrorKind::DecimalType, self))
+        }
+    }
+
     fn strict_dict(&'a self) -> ValResult<GenericMapping<'a>> {
         if let Ok(dict) = self.cast_as::<PyDict>() {
             Ok(dict.into())
@@ -241,7 +380,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn lax_list(&'a self) -> ValResult<GenericListLike<'a>> {
+    fn lax_list(&'a self, max_length: Option<usize>) -> ValResult<GenericListLike<'a>> {
         if let Ok(list) = self.cast_as::<PyList>() {
             Ok(list.into())
         } else if let Ok(tuple) = self.cast_as::<PyTuple>() {
@@ -250,6 +389,26 @@ impl<'a> Input<'a> for PyAny {
             Ok(set.into())
         } else if let Ok(frozen_set) = self.cast_as::<PyFrozenSet>() {
             Ok(frozen_set.into())
+        } else if let Ok(iterator) = self.cast_as::<PyIterator>() {
+            // generators and other iterators have no length, so they're consumed into a new list upfront,
+            // if there's a maximum length we stop one item after it, the input's real length isn't known
+            let items = match max_length {
+                Some(max_length) => {
+                    let items = iterator.take(max_length + 1).collect::<PyResult<Vec<_>>>()?;
+                    if items.len() > max_length {
+                        return Err(ValError::new(
+                            ErrorKind::TooLong {
+                                max_length,
+                                input_length: None,
+                            },
+                            self,
+                        ));
+                    }
+                    items
+                }
+                None => iterator.collect::<PyResult<Vec<_>>>()?,
+            };
+            Ok(PyList::new(self.py(), items).into())
         } else {
             Err(ValError::new(ErrorKind::ListType, self))
         }
@@ -427,7 +586,7 @@ impl<'a> Input<'a> for PyAny {
     }
 }
 
-/// return None if obj is not a mapping (cast_as::<PyMapping> fails or mapping.items returns an AttributeError)
+/// return None if obj is not a mapping (cast_as::<PyMapping> fails or neither mapping.items nor mapping.keys exist)
 /// otherwise try to covert the mapping to a dict and return an Some(error) if it fails
 fn mapping_as_dict(obj: &PyAny) -> Option<ValResult<GenericMapping>> {
     let mapping: &PyMapping = match obj.cast_as() {
@@ -439,13 +598,15 @@ fn mapping_as_dict(obj: &PyAny) -> Option<ValResult<GenericMapping>> {
     // hence we also require that the object as `items` to consider it a mapping
     let result_dict = match mapping.items() {
         Ok(seq) => mapping_seq_as_dict(seq),
-        Err(err) => {
-            if matches!(err.get_type(obj.py()).is_subclass_of::<PyAttributeError>(), Ok(true)) {
-                return None;
-            } else {
-                Err(err)
+        Err(err) if is_attribute_error(obj.py(), &err) => {
+            // no `items()`, fall back to `keys()` and `__getitem__` which is all `dict(obj)` requires
+            match mapping.keys() {
+                Ok(keys) => mapping_keys_as_dict(mapping, keys),
+                Err(err) if is_attribute_error(obj.py(), &err) => return None,
+                Err(err) => Err(err),
             }
         }
+        Err(err) => Err(err),
     };
     match result_dict {
         Ok(dict) => Some(Ok(dict.into())),
@@ -468,6 +629,19 @@ fn mapping_seq_as_dict(seq: &PySequence) -> PyResult<&PyDict> {
     Ok(dict)
 }
 
+fn mapping_keys_as_dict<'py>(mapping: &'py PyMapping, keys: &'py PySequence) -> PyResult<&'py PyDict> {
+    let dict = PyDict::new(mapping.py());
+    for key in keys.iter()? {
+        let key = key?;
+        dict.set_item(key, mapping.get_item(key)?)?;
+    }
+    Ok(dict)
+}
+
+fn is_attribute_error(py: Python, err: &PyErr) -> bool {
+    matches!(err.get_type(py).is_subclass_of::<PyAttributeError>(), Ok(true))
+}
+
 /// Best effort check of whether it's likely to make sense to inspect obj for attributes and iterate over it
 /// with `obj.dir()`
 fn from_attributes_applicable(obj: &PyAny) -> bool {
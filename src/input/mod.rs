@@ -10,7 +10,7 @@ mod shared;
 
 pub use datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
 pub use input_abstract::Input;
-pub use parse_json::{JsonInput, JsonObject};
+pub use parse_json::{parse_json_allow_threads, py_json_data, JsonInput, JsonObject};
 pub use return_enums::{EitherBytes, EitherString, GenericListLike, GenericMapping};
 
 pub fn repr_string(v: &PyAny) -> PyResult<String> {
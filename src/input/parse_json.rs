@@ -1,9 +1,15 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
 use std::fmt;
+use std::fmt::Write;
 
 use indexmap::IndexMap;
+use num_bigint::BigInt;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
-use serde::de::{Deserialize, DeserializeSeed, Error as SerdeError, MapAccess, SeqAccess, Visitor};
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyString};
+use serde::de::{DeserializeSeed, Error as SerdeError, MapAccess, SeqAccess, Visitor};
+
+use crate::errors::ErrorKind;
 
 // taken from `serde_json`
 // We only use our own error type; no need for From conversions provided by the
@@ -17,13 +23,21 @@ macro_rules! tri {
     };
 }
 
-/// similar to serde `Value` but with int and float split
+// `serde_json::number::TOKEN` isn't public
+const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+/// similar to serde `Value` but with int and float split,
+/// holds no python objects (and must stay `Send`) so it can be built while the GIL is released
 #[derive(Clone, Debug)]
 pub enum JsonInput {
     Null,
     Bool(bool),
     Int(i64),
-    Float(f64),
+    /// integers too large for an `i64`, stored as their text and converted to a python int as required
+    BigInt(String),
+    /// the original text of the number is kept if it differs from the float's own representation,
+    /// so decimals can be created without losing precision
+    Float(f64, Option<String>),
     String(String),
     Array(JsonArray),
     Object(JsonObject),
@@ -31,13 +45,30 @@ pub enum JsonInput {
 pub type JsonArray = Vec<JsonInput>;
 pub type JsonObject = IndexMap<String, JsonInput>;
 
+impl JsonInput {
+    /// name of the JSON value kind, used in error messages
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Null => "null",
+            Self::Bool(_) => "bool",
+            Self::Int(_) | Self::BigInt(_) => "int",
+            Self::Float(..) => "float",
+            Self::String(_) => "string",
+            Self::Array(_) => "array",
+            Self::Object(_) => "object",
+        }
+    }
+}
+
 impl ToPyObject for JsonInput {
     fn to_object(&self, py: Python<'_>) -> PyObject {
         match self {
             Self::Null => py.None(),
             Self::Bool(b) => b.into_py(py),
             Self::Int(i) => i.into_py(py),
-            Self::Float(f) => f.into_py(py),
+            // the parser only creates `BigInt` from valid integer text, falling back to a string just in case
+            Self::BigInt(b) => big_int_to_py(py, b).unwrap_or_else(|| b.into_py(py)),
+            Self::Float(f, _) => f.into_py(py),
             Self::String(s) => s.into_py(py),
             Self::Array(v) => v.iter().map(|v| v.to_object(py)).collect::<Vec<_>>().into_py(py),
             Self::Object(o) => {
@@ -51,107 +82,325 @@ impl ToPyObject for JsonInput {
     }
 }
 
-impl<'de> Deserialize<'de> for JsonInput {
+/// Convert the text of an integer to a python int, `None` if the text isn't a valid integer.
+/// `BigInt` is used rather than calling `int()` since python limits the number of digits `int()` will parse
+pub fn big_int_to_py(py: Python, text: &str) -> Option<PyObject> {
+    match is_int_text(text) {
+        true => text.parse::<BigInt>().ok().map(|big_int| big_int.to_object(py)),
+        false => None,
+    }
+}
+
+/// whether the text is an optional minus sign followed by one or more ascii digits
+fn is_int_text(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Build a `JsonInput` from the text of a number which isn't a valid `i64` or `u64`
+fn number_from_text<E: SerdeError>(number: String) -> Result<JsonInput, E> {
+    if is_int_text(&number) {
+        return Ok(JsonInput::BigInt(number));
+    }
+    match number.parse::<f64>() {
+        Ok(float) if float.is_finite() => match float_matches_text(float, &number) {
+            true => Ok(JsonInput::Float(float, None)),
+            false => Ok(JsonInput::Float(float, Some(number))),
+        },
+        _ => Err(SerdeError::custom("number out of range")),
+    }
+}
+
+/// whether formatting the float gives exactly the text, checked without allocating
+fn float_matches_text(float: f64, text: &str) -> bool {
+    let mut remaining = MatchText(text);
+    write!(remaining, "{}", float).is_ok() && remaining.0.is_empty()
+}
+
+/// `fmt::Write` which fails as soon as the output differs from the text
+struct MatchText<'t>(&'t str);
+
+impl<'t> Write for MatchText<'t> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        match self.0.strip_prefix(s) {
+            Some(rest) => {
+                self.0 = rest;
+                Ok(())
+            }
+            None => Err(fmt::Error),
+        }
+    }
+}
+
+/// The data to parse as JSON from a `str`, `bytes` or `bytearray`, `None` for any other type
+pub fn py_json_data(input: &PyAny) -> PyResult<Option<Cow<[u8]>>> {
+    if let Ok(py_bytes) = input.cast_as::<PyBytes>() {
+        Ok(Some(Cow::Borrowed(py_bytes.as_bytes())))
+    } else if let Ok(py_str) = input.cast_as::<PyString>() {
+        Ok(Some(Cow::Borrowed(py_str.to_str()?.as_bytes())))
+    } else if let Ok(py_byte_array) = input.cast_as::<PyByteArray>() {
+        // a bytearray could be mutated by another thread while the GIL is released for parsing, so we copy it
+        Ok(Some(Cow::Owned(py_byte_array.to_vec())))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Releasing the GIL lets another thread take it, so we may have to wait to get it back, that isn't worth it for
+/// small documents which parse in a few microseconds
+const ALLOW_THREADS_MIN_LENGTH: usize = 16 * 1024;
+
+/// Parse JSON without holding the GIL so other threads can run python code (or validate) while we parse,
+/// documents shorter than `ALLOW_THREADS_MIN_LENGTH` bytes are parsed while holding the GIL
+pub fn parse_json_allow_threads(
+    py: Python,
+    json_data: &[u8],
+    reject_duplicate_keys: bool,
+) -> Result<JsonInput, ErrorKind> {
+    if json_data.len() < ALLOW_THREADS_MIN_LENGTH {
+        parse_json_bytes(json_data, reject_duplicate_keys)
+    } else {
+        py.allow_threads(|| parse_json_bytes(json_data, reject_duplicate_keys))
+    }
+}
+
+/// Parse JSON, by default if a key is repeated in an object the last value wins (as with `json.loads`),
+/// if `reject_duplicate_keys` is set a repeated key is an error instead
+pub fn parse_json_bytes(json_data: &[u8], reject_duplicate_keys: bool) -> Result<JsonInput, ErrorKind> {
+    let duplicate_key = RefCell::new(None);
+    let seed = JsonSeed {
+        duplicate_key: match reject_duplicate_keys {
+            true => Some(&duplicate_key),
+            false => None,
+        },
+    };
+    let mut deserializer = serde_json::Deserializer::from_slice(json_data);
+    seed.deserialize(&mut deserializer)
+        .and_then(|json_input| deserializer.end().map(|_| json_input))
+        .map_err(|error| match duplicate_key.into_inner() {
+            Some(key) => ErrorKind::json_duplicate_key(key, error, json_data),
+            None => ErrorKind::invalid_json(error, json_data),
+        })
+}
+
+/// Deserializes a `JsonInput`, if `duplicate_key` is set, a repeated key in an object is an error and the key is
+/// recorded so the error can be reported as `ErrorKind::JsonDuplicateKey`
+#[derive(Clone, Copy)]
+struct JsonSeed<'k> {
+    duplicate_key: Option<&'k RefCell<Option<String>>>,
+}
+
+impl<'de, 'k> DeserializeSeed<'de> for JsonSeed<'k> {
+    type Value = JsonInput;
+
     #[inline]
-    fn deserialize<D>(deserializer: D) -> Result<JsonInput, D::Error>
+    fn deserialize<D>(self, deserializer: D) -> Result<JsonInput, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        struct JsonVisitor;
+        deserializer.deserialize_any(self)
+    }
+}
 
-        impl<'de> Visitor<'de> for JsonVisitor {
-            type Value = JsonInput;
+impl<'de, 'k> Visitor<'de> for JsonSeed<'k> {
+    type Value = JsonInput;
 
-            #[cfg_attr(has_no_coverage, no_coverage)]
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                formatter.write_str("any valid JSON value")
-            }
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any valid JSON value")
+    }
 
-            #[inline]
-            fn visit_bool<E>(self, value: bool) -> Result<JsonInput, E> {
-                Ok(JsonInput::Bool(value))
-            }
+    #[inline]
+    fn visit_bool<E>(self, value: bool) -> Result<JsonInput, E> {
+        Ok(JsonInput::Bool(value))
+    }
 
-            #[inline]
-            fn visit_i64<E>(self, value: i64) -> Result<JsonInput, E> {
-                Ok(JsonInput::Int(value))
-            }
+    #[inline]
+    fn visit_i64<E>(self, value: i64) -> Result<JsonInput, E> {
+        Ok(JsonInput::Int(value))
+    }
 
-            #[inline]
-            fn visit_u64<E>(self, value: u64) -> Result<JsonInput, E> {
-                Ok(JsonInput::Int(value as i64))
-            }
+    #[inline]
+    fn visit_u64<E>(self, value: u64) -> Result<JsonInput, E> {
+        match i64::try_from(value) {
+            Ok(value) => Ok(JsonInput::Int(value)),
+            Err(_) => Ok(JsonInput::BigInt(value.to_string())),
+        }
+    }
 
-            #[inline]
-            fn visit_f64<E>(self, value: f64) -> Result<JsonInput, E> {
-                Ok(JsonInput::Float(value))
-            }
+    #[inline]
+    fn visit_f64<E>(self, value: f64) -> Result<JsonInput, E> {
+        Ok(JsonInput::Float(value, None))
+    }
 
-            #[inline]
-            fn visit_str<E>(self, value: &str) -> Result<JsonInput, E>
-            where
-                E: SerdeError,
-            {
-                Ok(JsonInput::String(value.to_string()))
-            }
+    #[inline]
+    fn visit_str<E>(self, value: &str) -> Result<JsonInput, E>
+    where
+        E: SerdeError,
+    {
+        Ok(JsonInput::String(value.to_string()))
+    }
 
-            #[inline]
-            fn visit_string<E>(self, value: String) -> Result<JsonInput, E> {
-                Ok(JsonInput::String(value))
-            }
+    #[inline]
+    fn visit_string<E>(self, value: String) -> Result<JsonInput, E> {
+        Ok(JsonInput::String(value))
+    }
 
-            #[inline]
-            fn visit_none<E>(self) -> Result<JsonInput, E> {
-                Ok(JsonInput::Null)
-            }
+    #[inline]
+    fn visit_none<E>(self) -> Result<JsonInput, E> {
+        Ok(JsonInput::Null)
+    }
 
-            #[inline]
-            fn visit_some<D>(self, deserializer: D) -> Result<JsonInput, D::Error>
-            where
-                D: serde::Deserializer<'de>,
-            {
-                Deserialize::deserialize(deserializer)
-            }
+    #[inline]
+    fn visit_some<D>(self, deserializer: D) -> Result<JsonInput, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        self.deserialize(deserializer)
+    }
 
-            #[inline]
-            fn visit_unit<E>(self) -> Result<JsonInput, E> {
-                Ok(JsonInput::Null)
-            }
+    #[inline]
+    fn visit_unit<E>(self) -> Result<JsonInput, E> {
+        Ok(JsonInput::Null)
+    }
 
-            #[inline]
-            fn visit_seq<V>(self, mut visitor: V) -> Result<JsonInput, V::Error>
-            where
-                V: SeqAccess<'de>,
-            {
-                let mut vec = Vec::new();
+    #[inline]
+    fn visit_seq<V>(self, mut visitor: V) -> Result<JsonInput, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        let mut vec = Vec::new();
 
-                while let Some(elem) = tri!(visitor.next_element()) {
-                    vec.push(elem);
-                }
+        while let Some(elem) = tri!(visitor.next_element_seed(self)) {
+            vec.push(elem);
+        }
 
-                Ok(JsonInput::Array(vec))
-            }
+        Ok(JsonInput::Array(vec))
+    }
+
+    fn visit_map<V>(self, mut visitor: V) -> Result<JsonInput, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        match visitor.next_key_seed(KeyDeserializer)? {
+            Some(first_key) => {
+                let first_value = match first_key == NUMBER_TOKEN {
+                    // with `arbitrary_precision`, numbers which aren't valid `i64` or `u64` are passed as a map
+                    // with a single magic key and the number's text as the value, but the same key could
+                    // also come from an object in the JSON
+                    true => match tri!(visitor.next_value_seed(NumberTokenSeed(self))) {
+                        NumberTokenValue::Number(number) => return number_from_text(number),
+                        NumberTokenValue::Value(value) => value,
+                    },
+                    false => tri!(visitor.next_value_seed(self)),
+                };
+                let mut values = IndexMap::new();
 
-            fn visit_map<V>(self, mut visitor: V) -> Result<JsonInput, V::Error>
-            where
-                V: MapAccess<'de>,
-            {
-                match visitor.next_key_seed(KeyDeserializer)? {
-                    Some(first_key) => {
-                        let mut values = IndexMap::new();
-
-                        values.insert(first_key, tri!(visitor.next_value()));
-                        while let Some((key, value)) = tri!(visitor.next_entry()) {
-                            values.insert(key, value);
+                values.insert(first_key, first_value);
+                while let Some(key) = tri!(visitor.next_key_seed(KeyDeserializer)) {
+                    if let Some(duplicate_key) = self.duplicate_key {
+                        if values.contains_key(&key) {
+                            let error = SerdeError::custom(format!("duplicate key `{}`", key));
+                            *duplicate_key.borrow_mut() = Some(key);
+                            return Err(error);
                         }
-                        Ok(JsonInput::Object(values))
                     }
-                    None => Ok(JsonInput::Object(IndexMap::new())),
+                    values.insert(key, tri!(visitor.next_value_seed(self)));
                 }
+                Ok(JsonInput::Object(values))
             }
+            None => Ok(JsonInput::Object(IndexMap::new())),
         }
+    }
+}
+
+enum NumberTokenValue {
+    Number(String),
+    Value(JsonInput),
+}
+
+/// Deserializes the value of an object's `NUMBER_TOKEN` key, numbers from `serde_json` are passed to `visit_string`
+/// while strings in the JSON are only ever passed to `visit_str` or `visit_borrowed_str`,
+/// anything else is deserialized as a normal value
+struct NumberTokenSeed<'k>(JsonSeed<'k>);
+
+impl<'de, 'k> DeserializeSeed<'de> for NumberTokenSeed<'k> {
+    type Value = NumberTokenValue;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de, 'k> Visitor<'de> for NumberTokenSeed<'k> {
+    type Value = NumberTokenValue;
+
+    #[cfg_attr(has_no_coverage, no_coverage)]
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.0.expecting(formatter)
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+        Ok(NumberTokenValue::Number(value))
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        self.0.visit_bool(value).map(NumberTokenValue::Value)
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        self.0.visit_i64(value).map(NumberTokenValue::Value)
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        self.0.visit_u64(value).map(NumberTokenValue::Value)
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        self.0.visit_f64(value).map(NumberTokenValue::Value)
+    }
 
-        deserializer.deserialize_any(JsonVisitor)
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        self.0.visit_str(value).map(NumberTokenValue::Value)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E>
+    where
+        E: SerdeError,
+    {
+        self.0.visit_unit().map(NumberTokenValue::Value)
+    }
+
+    fn visit_seq<V>(self, visitor: V) -> Result<Self::Value, V::Error>
+    where
+        V: SeqAccess<'de>,
+    {
+        self.0.visit_seq(visitor).map(NumberTokenValue::Value)
+    }
+
+    fn visit_map<V>(self, visitor: V) -> Result<Self::Value, V::Error>
+    where
+        V: MapAccess<'de>,
+    {
+        self.0.visit_map(visitor).map(NumberTokenValue::Value)
     }
 }
 
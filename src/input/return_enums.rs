@@ -37,21 +37,35 @@ derive_from!(GenericListLike, Set, PySet);
 derive_from!(GenericListLike, FrozenSet, PyFrozenSet);
 derive_from!(GenericListLike, JsonArray, JsonArray);
 
+/// `None` (or JSON `null`) is always valid and returned unchanged by these validators, so `None` items in
+/// large sequences can skip dispatching to the validator
+fn none_always_valid(validator: &CombinedValidator) -> bool {
+    matches!(validator, CombinedValidator::Nullable(_) | CombinedValidator::None(_))
+}
+
 macro_rules! build_validate_to_vec {
     ($name:ident, $list_like_type:ty) => {
         fn $name<'a, 's>(
             py: Python<'a>,
             list_like: &'a $list_like_type,
-            length: usize,
             validator: &'s CombinedValidator,
+            fail_fast: bool,
             extra: &Extra,
             slots: &'a [CombinedValidator],
             recursion_guard: &'s mut RecursionGuard,
         ) -> ValResult<'a, Vec<PyObject>> {
-            let mut output: Vec<PyObject> = Vec::with_capacity(length);
+            let mut output: Vec<PyObject> = Vec::with_capacity(list_like.len());
             let mut errors: Vec<ValLineError> = Vec::new();
+            let skip_none = none_always_valid(validator);
             for (index, item) in list_like.iter().enumerate() {
-                match validator.validate(py, item, extra, slots, recursion_guard) {
+                if skip_none && item.is_none() {
+                    output.push(py.None());
+                    continue;
+                }
+                let warnings_start = extra.warnings_count();
+                let result = validator.validate(py, item, extra, slots, recursion_guard);
+                extra.warnings_outer_location(warnings_start, index);
+                match result {
                     Ok(item) => output.push(item),
                     Err(ValError::LineErrors(line_errors)) => {
                         errors.extend(
@@ -59,6 +73,9 @@ macro_rules! build_validate_to_vec {
                                 .into_iter()
                                 .map(|err| err.with_outer_location(index.into())),
                         );
+                        if fail_fast {
+                            break;
+                        }
                     }
                     Err(err) => return Err(err),
                 }
@@ -89,54 +106,67 @@ impl<'a> GenericListLike<'a> {
         }
     }
 
+    /// all list-like inputs have a known length, so this is called before validating items - oversized inputs
+    /// are rejected without validating any of their items
     pub fn check_len<'s, 'data>(
         &'s self,
         size_range: Option<(Option<usize>, Option<usize>)>,
         input: &'data impl Input<'data>,
-    ) -> ValResult<'data, Option<usize>> {
-        let mut length: Option<usize> = None;
+    ) -> ValResult<'data, ()> {
         if let Some((min_items, max_items)) = size_range {
             let len = self.generic_len();
             if let Some(min_length) = min_items {
                 if len < min_length {
-                    return Err(ValError::new(ErrorKind::TooShort { min_length }, input));
+                    return Err(ValError::new(
+                        ErrorKind::TooShort {
+                            min_length,
+                            input_length: len,
+                        },
+                        input,
+                    ));
                 }
             }
             if let Some(max_length) = max_items {
                 if len > max_length {
-                    return Err(ValError::new(ErrorKind::TooLong { max_length }, input));
+                    return Err(ValError::new(
+                        ErrorKind::TooLong {
+                            max_length,
+                            input_length: Some(len),
+                        },
+                        input,
+                    ));
                 }
             }
-            length = Some(len);
         }
-        Ok(length)
+        Ok(())
     }
 
+    /// errors from all items are collected unless `fail_fast` is set, in which case validation stops at
+    /// the first invalid item
     pub fn validate_to_vec<'s>(
         &self,
         py: Python<'a>,
-        length: Option<usize>,
         validator: &'s CombinedValidator,
+        fail_fast: bool,
         extra: &Extra,
         slots: &'a [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'a, Vec<PyObject>> {
-        let length = length.unwrap_or_else(|| self.generic_len());
         match self {
             Self::List(list_like) => {
-                validate_to_vec_list(py, list_like, length, validator, extra, slots, recursion_guard)
+                validate_to_vec_list(py, list_like, validator, fail_fast, extra, slots, recursion_guard)
             }
             Self::Tuple(list_like) => {
-                validate_to_vec_tuple(py, list_like, length, validator, extra, slots, recursion_guard)
+                validate_to_vec_tuple(py, list_like, validator, fail_fast, extra, slots, recursion_guard)
             }
             Self::Set(list_like) => {
-                validate_to_vec_set(py, list_like, length, validator, extra, slots, recursion_guard)
+                validate_to_vec_set(py, list_like, validator, fail_fast, extra, slots, recursion_guard)
             }
             Self::FrozenSet(list_like) => {
-                validate_to_vec_frozenset(py, list_like, length, validator, extra, slots, recursion_guard)
+                validate_to_vec_frozenset(py, list_like, validator, fail_fast, extra, slots, recursion_guard)
             }
             Self::JsonArray(list_like) => {
-                validate_to_vec_jsonarray(py, list_like, length, validator, extra, slots, recursion_guard)
+                validate_to_vec_jsonarray(py, list_like, validator, fail_fast, extra, slots, recursion_guard)
             }
         }
     }
@@ -170,9 +200,11 @@ pub enum EitherString<'a> {
 }
 
 impl<'a> EitherString<'a> {
+    /// borrows the string without copying, a python string is only copied if it isn't valid UTF-8
+    /// (e.g. it contains lone surrogates) and has to be decoded lossily
     pub fn as_cow(&self) -> Cow<str> {
         match self {
-            Self::Cow(data) => data.clone(),
+            Self::Cow(data) => Cow::Borrowed(data.as_ref()),
             Self::Py(py_str) => py_str.to_string_lossy(),
         }
     }
@@ -240,6 +272,13 @@ impl<'a> EitherBytes<'a> {
             EitherBytes::Py(py_bytes) => py_bytes.len(),
         }
     }
+
+    pub fn as_slice(&'a self) -> &'a [u8] {
+        match self {
+            EitherBytes::Cow(bytes) => bytes,
+            EitherBytes::Py(py_bytes) => py_bytes.as_bytes(),
+        }
+    }
 }
 
 impl<'a> IntoPy<PyObject> for EitherBytes<'a> {
@@ -1,5 +1,12 @@
+use std::borrow::Cow;
+
+use pyo3::once_cell::GILOnceCell;
+use pyo3::prelude::*;
+use pyo3::types::PyType;
+
 use crate::errors::{ErrorKind, ValError, ValResult};
 
+use super::parse_json::big_int_to_py;
 use super::Input;
 
 #[inline]
@@ -36,8 +43,26 @@ pub fn int_as_bool<'a>(input: &'a impl Input<'a>, int: i64) -> ValResult<'a, boo
     }
 }
 
+#[inline]
+pub fn float_as_bool<'a>(input: &'a impl Input<'a>, float: f64) -> ValResult<'a, bool> {
+    if float == 0.0 {
+        Ok(false)
+    } else if float == 1.0 {
+        Ok(true)
+    } else {
+        Err(ValError::new(ErrorKind::BoolParsing, input))
+    }
+}
+
+/// Matches python's `int(str)`, including signs, leading zeros and underscores, except that:
+/// * strings of whole numbers in float notation are accepted, e.g. "1.0" and "1e3", consistent with lax float inputs
+/// * only ASCII digits are accepted, python also accepts other unicode decimal digits
 #[inline]
 pub fn str_as_int<'s, 'l>(input: &'s impl Input<'s>, str: &'l str) -> ValResult<'s, i64> {
+    let str = match clean_number_str(str) {
+        Some(str) => str,
+        None => return Err(ValError::new(ErrorKind::IntParsing, input)),
+    };
     if let Ok(i) = str.parse::<i64>() {
         Ok(i)
     } else if let Ok(f) = str.parse::<f64>() {
@@ -47,6 +72,47 @@ pub fn str_as_int<'s, 'l>(input: &'s impl Input<'s>, str: &'l str) -> ValResult<
     }
 }
 
+/// Used by `as_big_int` in lax mode: a python int from a string of an integer too large for an `i64`,
+/// cleaned like `str_as_int` but without float notation since floats that large aren't exact
+pub fn str_as_big_int(py: Python, str: &str) -> Option<PyObject> {
+    let str = clean_number_str(str)?;
+    match str.strip_prefix('+') {
+        Some(unsigned) if unsigned.starts_with('-') => None,
+        Some(unsigned) => big_int_to_py(py, unsigned),
+        None => big_int_to_py(py, &str),
+    }
+}
+
+pub fn str_as_float<'s, 'l>(input: &'s impl Input<'s>, str: &'l str) -> ValResult<'s, f64> {
+    match clean_number_str(str).map(|str| str.parse()) {
+        Some(Ok(float)) => Ok(float),
+        _ => Err(ValError::new(ErrorKind::FloatParsing, input)),
+    }
+}
+
+/// like python's `int()` and `float()`, surrounding whitespace is ignored and underscores are allowed
+/// as separators between digits (e.g. "1_000"), `None` is returned if underscores are anywhere else
+fn clean_number_str(str: &str) -> Option<Cow<str>> {
+    let str = str.trim_matches(char::is_whitespace);
+    if !str.contains('_') {
+        return Some(Cow::Borrowed(str));
+    }
+    let bytes = str.as_bytes();
+    let mut cleaned = String::with_capacity(str.len());
+    for (index, c) in str.char_indices() {
+        if c == '_' {
+            let digit_before = index > 0 && bytes[index - 1].is_ascii_digit();
+            let digit_after = matches!(bytes.get(index + 1), Some(b) if b.is_ascii_digit());
+            if !(digit_before && digit_after) {
+                return None;
+            }
+        } else {
+            cleaned.push(c);
+        }
+    }
+    Some(Cow::Owned(cleaned))
+}
+
 pub fn float_as_int<'a>(input: &'a impl Input<'a>, float: f64) -> ValResult<'a, i64> {
     if float == f64::INFINITY {
         Err(ValError::new(ErrorKind::IntNan { nan_value: "infinity" }, input))
@@ -61,7 +127,46 @@ pub fn float_as_int<'a>(input: &'a impl Input<'a>, float: f64) -> ValResult<'a,
         Err(ValError::new(ErrorKind::IntNan { nan_value: "NaN" }, input))
     } else if float % 1.0 != 0.0 {
         Err(ValError::new(ErrorKind::IntFromFloat, input))
+    } else if float < i64::MIN as f64 || float >= i64::MAX as f64 {
+        // `i64::MAX as f64` rounds up to 2^63 so `>=` is correct, without this check `as` would saturate
+        Err(ValError::new(ErrorKind::IntOverflow, input))
     } else {
         Ok(float as i64)
     }
 }
+
+static DECIMAL_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+pub fn get_decimal_type(py: Python) -> &PyType {
+    DECIMAL_TYPE
+        .get_or_init(py, || {
+            py.import("decimal")
+                .and_then(|decimal_module| decimal_module.getattr("Decimal"))
+                .and_then(|decimal_type| decimal_type.extract::<&PyType>())
+                .map(|decimal_type| decimal_type.into())
+                .expect("unable to import decimal.Decimal")
+        })
+        .as_ref(py)
+}
+
+static ENUM_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+pub fn get_enum_type(py: Python) -> &PyType {
+    ENUM_TYPE
+        .get_or_init(py, || {
+            py.import("enum")
+                .and_then(|enum_module| enum_module.getattr("Enum"))
+                .and_then(|enum_type| enum_type.extract::<&PyType>())
+                .map(|enum_type| enum_type.into())
+                .expect("unable to import enum.Enum")
+        })
+        .as_ref(py)
+}
+
+/// Create a `decimal.Decimal` from `arg`, the `Decimal` constructor only raises for invalid input
+/// so any exception is treated as a parsing error
+pub fn create_decimal<'a>(py: Python<'a>, arg: &'a PyAny, input: &'a impl Input<'a>) -> ValResult<'a, &'a PyAny> {
+    get_decimal_type(py)
+        .call1((arg,))
+        .map_err(|_| ValError::new(ErrorKind::DecimalParsing, input))
+}
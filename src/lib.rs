@@ -17,7 +17,7 @@ mod validators;
 // required for benchmarks
 pub use build_tools::SchemaError;
 pub use errors::{PydanticValueError, ValidationError};
-pub use validators::SchemaValidator;
+pub use validators::{py_parse_json, JsonArrayIterator, ParsedJson, SchemaValidator};
 
 pub fn get_version() -> String {
     let version = env!("CARGO_PKG_VERSION").to_string();
@@ -33,6 +33,10 @@ pub fn get_version() -> String {
 fn _pydantic_core(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add("__version__", get_version())?;
     m.add_class::<SchemaValidator>()?;
+    m.add_class::<JsonArrayIterator>()?;
+    m.add_class::<ParsedJson>()?;
+    m.add_function(wrap_pyfunction!(py_parse_json, m)?)?;
+    m.add_function(wrap_pyfunction!(errors::list_error_codes, m)?)?;
     m.add_class::<ValidationError>()?;
     m.add_class::<SchemaError>()?;
     m.add_class::<PydanticValueError>()?;
@@ -1,22 +1,33 @@
-use std::collections::HashSet;
-use std::hash::BuildHasherDefault;
-
-use nohash_hasher::NoHashHasher;
-
-type BuildNoHashHasher = BuildHasherDefault<NoHashHasher<usize>>;
+use ahash::AHashSet;
 
 /// This is used to avoid cyclic references in input data causing recursive validation and a nasty segmentation fault.
 /// It's used in `validators/recursive.rs` to detect when a reference is reused within itself.
+/// It also limits how deeply recursive validators can be nested, so deeply nested (but not cyclic) input
+/// results in a validation error rather than a stack overflow.
 #[derive(Debug, Clone, Default)]
 pub struct RecursionGuard {
-    ids: Option<HashSet<usize, BuildNoHashHasher>>,
-    // see validators/recursive.rs::BACKUP_GUARD_LIMIT for details
+    // ids are object addresses so their low bits are always zero, they need a real (but fast) hasher for
+    // `HashSet` to spread them across buckets
+    ids: Option<AHashSet<usize>>,
     // depth could be a hashmap {validator_id => depth} but for simplicity and performance it's easier to just
     // use one number for all validators
     depth: u16,
+    // from the `max_recursion_depth` config setting, if `None` validators/recursive.rs::DEFAULT_MAX_DEPTH is used
+    max_depth: Option<u16>,
 }
 
 impl RecursionGuard {
+    pub fn new(max_depth: Option<u16>) -> Self {
+        Self {
+            max_depth,
+            ..Default::default()
+        }
+    }
+
+    pub fn max_depth(&self) -> Option<u16> {
+        self.max_depth
+    }
+
     // insert a new id into the set, return whether the set already had the id in it
     pub fn contains_or_insert(&mut self, id: usize) -> bool {
         match self.ids {
@@ -24,8 +35,7 @@ impl RecursionGuard {
             // "If the set did not have this value present, `true` is returned."
             Some(ref mut set) => !set.insert(id),
             None => {
-                let mut set: HashSet<usize, BuildNoHashHasher> =
-                    HashSet::with_capacity_and_hasher(10, BuildHasherDefault::default());
+                let mut set: AHashSet<usize> = AHashSet::with_capacity(10);
                 set.insert(id);
                 self.ids = Some(set);
                 false
@@ -33,7 +43,8 @@ impl RecursionGuard {
         }
     }
 
-    // see #143 this used as a backup in case the identity check recursion guard fails
+    // see #143 this is used for inputs without an identity (e.g. JSON) and as a backup in case the identity
+    // check recursion guard fails
     pub fn incr_depth(&mut self) -> u16 {
         self.depth += 1;
         self.depth
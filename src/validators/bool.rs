@@ -1,8 +1,9 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::is_strict;
-use crate::errors::ValResult;
+use crate::build_tools::{is_strict, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
@@ -11,6 +12,9 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct BoolValidator {
     strict: bool,
+    // in lax mode, whether numbers and strings may be coerced to bools, both are ignored in strict mode
+    allow_int: bool,
+    allow_str: bool,
 }
 
 impl BuildValidator for BoolValidator {
@@ -21,8 +25,11 @@ impl BuildValidator for BoolValidator {
         config: Option<&PyDict>,
         _build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            allow_int: schema.get_as(intern!(py, "allow_int"))?.unwrap_or(true),
+            allow_str: schema.get_as(intern!(py, "allow_str"))?.unwrap_or(true),
         }
         .into())
     }
@@ -39,7 +46,24 @@ impl Validator for BoolValidator {
     ) -> ValResult<'data, PyObject> {
         // TODO in theory this could be quicker if we used PyBool rather than going to a bool
         // and back again, might be worth profiling?
-        Ok(input.validate_bool(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        if strict || (self.allow_int && self.allow_str) {
+            let bool = input.validate_bool(strict)?;
+            extra.warn_if_coerced(py, input, strict, "bool", || input.strict_bool().is_ok());
+            return Ok(bool.into_py(py));
+        }
+        if let Ok(bool) = input.strict_bool() {
+            return Ok(bool.into_py(py));
+        }
+        if !self.allow_int && (input.is_exact_int() || input.is_exact_float()) {
+            return Err(ValError::new(ErrorKind::BoolType, input));
+        }
+        if !self.allow_str && (input.strict_str().is_ok() || input.strict_bytes().is_ok()) {
+            return Err(ValError::new(ErrorKind::BoolType, input));
+        }
+        let bool = input.lax_bool()?;
+        extra.warn_if_coerced(py, input, strict, "bool", || false);
+        Ok(bool.into_py(py))
     }
 
     fn get_name(&self) -> &str {
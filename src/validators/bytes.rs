@@ -2,16 +2,61 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, py_error, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherBytes, Input};
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
+/// optionally convert validated bytes to a string on output
+#[derive(Debug, Clone, Copy)]
+enum OutputEncoding {
+    Hex,
+    Base64,
+}
+
+impl OutputEncoding {
+    fn from_schema(schema: &PyDict) -> PyResult<Option<Self>> {
+        match schema.get_as::<&str>(intern!(schema.py(), "output_encoding"))? {
+            Some("hex") => Ok(Some(Self::Hex)),
+            Some("base64") => Ok(Some(Self::Base64)),
+            Some(s) => py_error!("Invalid output_encoding: {}", s),
+            None => Ok(None),
+        }
+    }
+
+    fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            Self::Hex => bytes.iter().map(|b| format!("{:02x}", b)).collect(),
+            Self::Base64 => base64::encode(bytes),
+        }
+    }
+}
+
+fn bytes_output(py: Python, either_bytes: EitherBytes, output_encoding: Option<OutputEncoding>) -> PyObject {
+    match output_encoding {
+        Some(encoding) => encoding.encode(either_bytes.as_slice()).into_py(py),
+        None => either_bytes.into_py(py),
+    }
+}
+
+fn validate_bytes<'data>(
+    input: &'data impl Input<'data>,
+    strict: bool,
+    strict_bytes_type: bool,
+) -> ValResult<'data, EitherBytes<'data>> {
+    match strict && strict_bytes_type {
+        true => input.exact_bytes(),
+        false => input.validate_bytes(strict),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct BytesValidator {
     strict: bool,
+    strict_bytes_type: bool,
+    output_encoding: Option<OutputEncoding>,
 }
 
 impl BuildValidator for BytesValidator {
@@ -30,6 +75,8 @@ impl BuildValidator for BytesValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                strict_bytes_type: schema.get_as(intern!(py, "strict_bytes_type"))?.unwrap_or(false),
+                output_encoding: OutputEncoding::from_schema(schema)?,
             }
             .into())
         }
@@ -45,8 +92,9 @@ impl Validator for BytesValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_bytes = input.validate_bytes(extra.strict.unwrap_or(self.strict))?;
-        Ok(either_bytes.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        let either_bytes = validate_bytes(input, strict, self.strict_bytes_type)?;
+        Ok(bytes_output(py, either_bytes, self.output_encoding))
     }
 
     fn get_name(&self) -> &str {
@@ -57,8 +105,10 @@ impl Validator for BytesValidator {
 #[derive(Debug, Clone)]
 pub struct BytesConstrainedValidator {
     strict: bool,
+    strict_bytes_type: bool,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    output_encoding: Option<OutputEncoding>,
 }
 
 impl Validator for BytesConstrainedValidator {
@@ -70,21 +120,34 @@ impl Validator for BytesConstrainedValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_bytes = input.validate_bytes(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let either_bytes = validate_bytes(input, strict, self.strict_bytes_type)?;
         let len = either_bytes.len()?;
 
         if let Some(min_length) = self.min_length {
             if len < min_length {
-                return Err(ValError::new(ErrorKind::BytesTooShort { min_length }, input));
+                return Err(ValError::new(
+                    ErrorKind::BytesTooShort {
+                        min_length,
+                        input_length: len,
+                    },
+                    input,
+                ));
             }
         }
         if let Some(max_length) = self.max_length {
             if len > max_length {
-                return Err(ValError::new(ErrorKind::BytesTooLong { max_length }, input));
+                return Err(ValError::new(
+                    ErrorKind::BytesTooLong {
+                        max_length,
+                        input_length: len,
+                    },
+                    input,
+                ));
             }
         }
 
-        Ok(either_bytes.into_py(py))
+        Ok(bytes_output(py, either_bytes, self.output_encoding))
     }
 
     fn get_name(&self) -> &str {
@@ -97,8 +160,10 @@ impl BytesConstrainedValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            strict_bytes_type: schema.get_as(intern!(py, "strict_bytes_type"))?.unwrap_or(false),
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            output_encoding: OutputEncoding::from_schema(schema)?,
         }
         .into())
     }
@@ -0,0 +1,77 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct ChainValidator {
+    steps: Vec<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for ChainValidator {
+    const EXPECTED_TYPE: &'static str = "chain";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let steps: Vec<CombinedValidator> = schema
+            .get_as_req::<&PyList>(intern!(schema.py(), "steps"))?
+            .iter()
+            .map(|step| build_validator(step, config, build_context).map(|result| result.0))
+            .collect::<PyResult<Vec<CombinedValidator>>>()?;
+
+        if steps.is_empty() {
+            return py_error!("One or more steps are required for a chain validator");
+        }
+
+        let descr = steps.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
+
+        Ok(Self {
+            steps,
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, descr),
+        }
+        .into())
+    }
+}
+
+impl Validator for ChainValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let mut steps_iter = self.steps.iter().enumerate();
+        // the first step receives the original input, there's always at least one step
+        let (_, first_step) = steps_iter.next().unwrap();
+        let value = first_step
+            .validate(py, input, extra, slots, recursion_guard)
+            .map_err(|err| err.with_outer_location(0.into()))?;
+
+        // later steps receive the output of the previous step as python input
+        steps_iter.try_fold(value, |value, (index, step)| {
+            let new_input: &PyAny = value.as_ref(py);
+            step.validate(py, new_input, extra, slots, recursion_guard)
+                .map_err(|err| err.with_outer_location(index.into()).into_new(py))
+        })
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        self.steps.iter_mut().try_for_each(|v| v.complete(build_context))
+    }
+}
@@ -0,0 +1,70 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{is_strict, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::{repr_string, Input};
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// Ignores the input and always returns `value`, unlike `default` which is only used when the input is missing.
+/// In strict mode the input must have the same type as `value` and equal it, so e.g. `True` doesn't match `1`.
+#[derive(Debug, Clone)]
+pub struct ConstantValidator {
+    value: PyObject,
+    repr: String,
+    strict: bool,
+}
+
+impl BuildValidator for ConstantValidator {
+    const EXPECTED_TYPE: &'static str = "constant";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let value: &PyAny = schema.get_as_req(intern!(schema.py(), "value"))?;
+        Ok(Self {
+            value: value.into(),
+            repr: repr_string(value)?,
+            strict: is_strict(schema, config)?,
+        }
+        .into())
+    }
+}
+
+impl Validator for ConstantValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if extra.strict.unwrap_or(self.strict) && !self.strict_match(py, input)? {
+            return Err(ValError::new(
+                ErrorKind::LiteralSingleError {
+                    expected: self.repr.clone(),
+                },
+                input,
+            ));
+        }
+        Ok(self.value.clone_ref(py))
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl ConstantValidator {
+    fn strict_match<'data>(&self, py: Python<'data>, input: &'data impl Input<'data>) -> PyResult<bool> {
+        let input_value = input.to_object(py).into_ref(py);
+        let value = self.value.as_ref(py);
+        Ok(input_value.get_type().is(value.get_type()) && input_value.eq(value)?)
+    }
+}
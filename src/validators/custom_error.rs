@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::{ErrorKind, PydanticValueError, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, CombinedValidator, Extra, Validator};
+
+/// Wraps any validator whose schema sets `custom_error_kind` and `custom_error_message`, errors raised by
+/// the validator itself are replaced with the custom error, errors from nested validators (which have a location)
+/// are left untouched
+#[derive(Debug, Clone)]
+pub struct CustomErrorValidator {
+    validator: Arc<CombinedValidator>,
+    custom_error: PydanticValueError,
+}
+
+impl CustomErrorValidator {
+    /// returns `validator` unchanged unless the schema defines a custom error
+    pub fn wrap(validator: CombinedValidator, schema: &PyDict) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let kind: Option<String> = schema.get_as(intern!(py, "custom_error_kind"))?;
+        let message: Option<String> = schema.get_as(intern!(py, "custom_error_message"))?;
+        let context: Option<&PyDict> = schema.get_as(intern!(py, "custom_error_context"))?;
+
+        let custom_error = match (kind, message) {
+            (Some(kind), Some(message)) => PydanticValueError::new(kind, message, context.map(|c| c.into_py(py))),
+            (None, None) if context.is_none() => return Ok(validator),
+            _ => return py_error!("'custom_error_kind' and 'custom_error_message' must be set together"),
+        };
+        Ok(Self {
+            validator: Arc::new(validator),
+            custom_error,
+        }
+        .into())
+    }
+
+    pub fn validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
+    /// replace errors raised by the wrapped validator itself with the custom error
+    pub fn convert_error<'a>(&self, error: ValError<'a>) -> ValError<'a> {
+        match error {
+            ValError::LineErrors(line_errors) => ValError::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|line_error| match line_error.location.outer() {
+                        Some(_) => line_error,
+                        None => line_error.with_kind(ErrorKind::CustomError {
+                            value_error: self.custom_error.clone(),
+                        }),
+                    })
+                    .collect(),
+            ),
+            otherwise => otherwise,
+        }
+    }
+}
+
+impl Validator for CustomErrorValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        self.validator
+            .validate(py, input, extra, slots, recursion_guard)
+            .map_err(|error| self.convert_error(error))
+    }
+
+    /// the wrapper is transparent, so it keeps the name of the validator it wraps
+    fn get_name(&self) -> &str {
+        self.validator.get_name()
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        Arc::make_mut(&mut self.validator).complete(build_context)
+    }
+}
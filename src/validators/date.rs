@@ -3,17 +3,32 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDate, PyDict, PyString};
 use speedate::{Date, Time};
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, py_error, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::{EitherDate, Input};
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::datetime::{NowConstraint, NowOp};
+use super::{current_time, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+/// how datetimes (and strings representing datetimes) are converted to dates in lax mode,
+/// in strict mode datetimes are always rejected
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FromDatetime {
+    /// the datetime must have a zero time component and no timezone
+    Exact,
+    /// the time component is dropped, datetimes with a timezone are rejected
+    Truncate,
+    /// the time component and timezone are dropped
+    TruncateIgnoreTz,
+}
 
 #[derive(Debug, Clone)]
 pub struct DateValidator {
     strict: bool,
+    from_datetime: FromDatetime,
     constraints: Option<DateConstraints>,
+    now_constraint: Option<NowConstraint>,
 }
 
 #[derive(Debug, Clone)]
@@ -38,8 +53,16 @@ impl BuildValidator for DateValidator {
             || schema.get_item(intern!(py, "ge")).is_some()
             || schema.get_item(intern!(py, "gt")).is_some();
 
+        let from_datetime = match schema.get_as::<&str>(intern!(py, "from_datetime"))? {
+            Some("exact") | None => FromDatetime::Exact,
+            Some("truncate") => FromDatetime::Truncate,
+            Some("truncate_ignore_tz") => FromDatetime::TruncateIgnoreTz,
+            Some(s) => return py_error!(r#"Invalid from_datetime mode: "{}""#, s),
+        };
+
         Ok(Self {
             strict: is_strict(schema, config)?,
+            from_datetime,
             constraints: match has_constraints {
                 true => Some(DateConstraints {
                     le: convert_pydate(schema, intern!(py, "le"))?,
@@ -49,6 +72,7 @@ impl BuildValidator for DateValidator {
                 }),
                 false => None,
             },
+            now_constraint: NowConstraint::from_schema(schema)?,
         }
         .into())
     }
@@ -63,27 +87,29 @@ impl Validator for DateValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let date = match input.validate_date(extra.strict.unwrap_or(self.strict)) {
+        let strict = extra.strict.unwrap_or(self.strict);
+        let date = match input.validate_date(strict) {
             Ok(date) => date,
             // if the date error was an internal error, return that immediately
             Err(ValError::InternalErr(internal_err)) => return Err(ValError::InternalErr(internal_err)),
-            Err(date_err) => match self.strict {
+            Err(date_err) => match strict {
                 // if we're in strict mode, we doing try coercing from a date
                 true => return Err(date_err),
                 // otherwise, try creating a date from a datetime input
-                false => date_from_datetime(input, date_err),
+                false => date_from_datetime(input, date_err, self.from_datetime),
             }?,
         };
         if let Some(constraints) = &self.constraints {
             let raw_date = date.as_raw()?;
 
             macro_rules! check_constraint {
-                ($constraint:ident, $error:ident) => {
+                ($constraint:ident, $error:ident, $inclusive:literal) => {
                     if let Some(constraint) = &constraints.$constraint {
                         if !raw_date.$constraint(constraint) {
                             return Err(ValError::new(
                                 ErrorKind::$error {
-                                    $constraint: constraint.to_string(),
+                                    bound: constraint.to_string(),
+                                    inclusive: $inclusive,
                                 },
                                 input,
                             ));
@@ -92,10 +118,21 @@ impl Validator for DateValidator {
                 };
             }
 
-            check_constraint!(le, LessThanEqual);
-            check_constraint!(lt, LessThan);
-            check_constraint!(ge, GreaterThanEqual);
-            check_constraint!(gt, GreaterThan);
+            check_constraint!(le, DateTooLate, true);
+            check_constraint!(lt, DateTooLate, false);
+            check_constraint!(ge, DateTooEarly, true);
+            check_constraint!(gt, DateTooEarly, false);
+        }
+        if let Some(ref now_constraint) = self.now_constraint {
+            let now = extra.now.unwrap_or_else(current_time);
+            // compare days since the epoch, "today" is calculated in the timezone given by `utc_offset`
+            let today = (now.as_secs() as i64 + now_constraint.utc_offset(py, now)? as i64).div_euclid(86400);
+            let day = date.as_raw()?.timestamp().div_euclid(86400);
+            match now_constraint.op {
+                NowOp::Past if day >= today => return Err(ValError::new(ErrorKind::DateInFuture, input)),
+                NowOp::Future if day <= today => return Err(ValError::new(ErrorKind::DateInPast, input)),
+                _ => (),
+            }
         }
         Ok(date.try_into_py(py)?)
     }
@@ -105,11 +142,12 @@ impl Validator for DateValidator {
     }
 }
 
-/// In lax mode, if the input is not a date, we try parsing the input as a datetime, then check it is an
-/// "exact date", e.g. has a zero time component.
+/// In lax mode, if the input is not a date, we try parsing the input as a datetime, then depending on
+/// `from_datetime` either check it is an "exact date", e.g. has a zero time component, or truncate it.
 fn date_from_datetime<'data>(
     input: &'data impl Input<'data>,
     date_err: ValError<'data>,
+    from_datetime: FromDatetime,
 ) -> ValResult<'data, EitherDate<'data>> {
     let either_dt = match input.validate_datetime(false) {
         Ok(dt) => dt,
@@ -137,16 +175,22 @@ fn date_from_datetime<'data>(
         }
     };
     let dt = either_dt.as_raw()?;
-    let zero_time = Time {
-        hour: 0,
-        minute: 0,
-        second: 0,
-        microsecond: 0,
-    };
-    if dt.time == zero_time && dt.offset.is_none() {
-        Ok(EitherDate::Raw(dt.date))
-    } else {
-        Err(ValError::new(ErrorKind::DateFromDatetimeInexact, input))
+    match from_datetime {
+        FromDatetime::Exact => {
+            let zero_time = Time {
+                hour: 0,
+                minute: 0,
+                second: 0,
+                microsecond: 0,
+            };
+            if dt.time == zero_time && dt.offset.is_none() {
+                Ok(EitherDate::Raw(dt.date))
+            } else {
+                Err(ValError::new(ErrorKind::DateFromDatetimeInexact, input))
+            }
+        }
+        FromDatetime::Truncate if dt.offset.is_some() => Err(ValError::new(ErrorKind::DateFromDatetimeTzAware, input)),
+        FromDatetime::Truncate | FromDatetime::TruncateIgnoreTz => Ok(EitherDate::Raw(dt.date)),
     }
 }
 
@@ -1,21 +1,27 @@
+use std::time::Duration;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDateTime, PyDict, PyString};
 use speedate::DateTime;
+use strum::EnumMessage;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, py_error, SchemaDict};
 use crate::errors::{py_err_string, ErrorKind, ValError, ValResult};
 use crate::input::{EitherDateTime, Input};
 use crate::recursion_guard::RecursionGuard;
 
-use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+use super::{current_time, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct DateTimeValidator {
     strict: bool,
     constraints: Option<DateTimeConstraints>,
+    now_constraint: Option<NowConstraint>,
 }
 
+/// Bounds are parsed once when the validator is built. Datetimes are compared by their UTC timestamp so the
+/// offset is always respected, naive datetimes (including bounds) are treated as UTC
 #[derive(Debug, Clone)]
 struct DateTimeConstraints {
     le: Option<DateTime>,
@@ -42,13 +48,14 @@ impl BuildValidator for DateTimeValidator {
             strict: is_strict(schema, config)?,
             constraints: match has_constraints {
                 true => Some(DateTimeConstraints {
-                    le: py_datetime_as_datetime(schema, intern!(py, "le"))?,
-                    lt: py_datetime_as_datetime(schema, intern!(py, "lt"))?,
-                    ge: py_datetime_as_datetime(schema, intern!(py, "ge"))?,
-                    gt: py_datetime_as_datetime(schema, intern!(py, "gt"))?,
+                    le: datetime_bound(schema, intern!(py, "le"))?,
+                    lt: datetime_bound(schema, intern!(py, "lt"))?,
+                    ge: datetime_bound(schema, intern!(py, "ge"))?,
+                    gt: datetime_bound(schema, intern!(py, "gt"))?,
                 }),
                 false => None,
             },
+            now_constraint: NowConstraint::from_schema(schema)?,
         }
         .into())
     }
@@ -64,23 +71,28 @@ impl Validator for DateTimeValidator {
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let datetime = input.validate_datetime(extra.strict.unwrap_or(self.strict))?;
+        if self.constraints.is_none() && self.now_constraint.is_none() {
+            return Ok(datetime.try_into_py(py)?);
+        }
+        // if we get an error from as_speedate, it's probably because the input datetime was invalid
+        // specifically had an invalid tzinfo, hence here we return a validation error
+        let speedate_dt = match datetime.as_raw() {
+            Ok(dt) => dt,
+            Err(err) => {
+                let error = py_err_string(py, err);
+                return Err(ValError::new(ErrorKind::DateTimeObjectInvalid { error }, input));
+            }
+        };
         if let Some(constraints) = &self.constraints {
-            // if we get an error from as_speedate, it's probably because the input datetime was invalid
-            // specifically had an invalid tzinfo, hence here we return a validation error
-            let speedate_dt = match datetime.as_raw() {
-                Ok(dt) => dt,
-                Err(err) => {
-                    let error = py_err_string(py, err);
-                    return Err(ValError::new(ErrorKind::DateTimeObjectInvalid { error }, input));
-                }
-            };
+            let dt_micros = utc_micros(&speedate_dt);
             macro_rules! check_constraint {
-                ($constraint:ident, $error:ident) => {
+                ($constraint:ident, $error:ident, $inclusive:literal) => {
                     if let Some(constraint) = &constraints.$constraint {
-                        if !speedate_dt.$constraint(constraint) {
+                        if !dt_micros.$constraint(&utc_micros(constraint)) {
                             return Err(ValError::new(
                                 ErrorKind::$error {
-                                    $constraint: constraint.to_string(),
+                                    bound: constraint.to_string(),
+                                    inclusive: $inclusive,
                                 },
                                 input,
                             ));
@@ -89,10 +101,29 @@ impl Validator for DateTimeValidator {
                 };
             }
 
-            check_constraint!(le, LessThanEqual);
-            check_constraint!(lt, LessThan);
-            check_constraint!(ge, GreaterThanEqual);
-            check_constraint!(gt, GreaterThan);
+            check_constraint!(le, DateTimeTooLate, true);
+            check_constraint!(lt, DateTimeTooLate, false);
+            check_constraint!(ge, DateTimeTooEarly, true);
+            check_constraint!(gt, DateTimeTooEarly, false);
+        }
+        if let Some(ref now_constraint) = self.now_constraint {
+            let now = extra.now.unwrap_or_else(current_time);
+            // microseconds since the epoch, naive datetimes are interpreted using `utc_offset`
+            let timestamp = match speedate_dt.offset {
+                Some(_) => speedate_dt.timestamp_tz(),
+                None => speedate_dt.timestamp() - now_constraint.utc_offset(py, now)? as i64,
+            };
+            let dt_micros = timestamp as i128 * 1_000_000 + speedate_dt.time.microsecond as i128;
+            let now_micros = now.as_micros() as i128;
+            match now_constraint.op {
+                NowOp::Past if dt_micros >= now_micros => {
+                    return Err(ValError::new(ErrorKind::DateTimeInFuture, input))
+                }
+                NowOp::Future if dt_micros <= now_micros => {
+                    return Err(ValError::new(ErrorKind::DateTimeInPast, input))
+                }
+                _ => (),
+            }
         }
         Ok(datetime.try_into_py(py)?)
     }
@@ -102,9 +133,69 @@ impl Validator for DateTimeValidator {
     }
 }
 
-fn py_datetime_as_datetime(schema: &PyDict, field: &PyString) -> PyResult<Option<DateTime>> {
-    match schema.get_as::<&PyDateTime>(field)? {
-        Some(dt) => Ok(Some(EitherDateTime::Py(dt).as_raw()?)),
-        None => Ok(None),
+/// microseconds since the UTC epoch, naive datetimes are treated as UTC
+fn utc_micros(dt: &DateTime) -> i128 {
+    dt.timestamp_tz() as i128 * 1_000_000 + dt.time.microsecond as i128
+}
+
+/// bounds may be either datetime objects or ISO 8601 strings
+fn datetime_bound(schema: &PyDict, field: &PyString) -> PyResult<Option<DateTime>> {
+    let value = match schema.get_item(field) {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    if let Ok(py_str) = value.cast_as::<PyString>() {
+        match DateTime::parse_str(py_str.to_str()?) {
+            Ok(dt) => Ok(Some(dt)),
+            Err(err) => py_error!(
+                "Invalid value for \"{}\": {}",
+                field,
+                err.get_documentation().unwrap_or_default()
+            ),
+        }
+    } else {
+        let dt: &PyDateTime = schema.get_as_req(field)?;
+        Ok(Some(EitherDateTime::Py(dt).as_raw()?))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) enum NowOp {
+    Past,
+    Future,
+}
+
+/// Constraint relative to the current time (`Extra::now`), used by the date and datetime validators
+#[derive(Debug, Clone)]
+pub(super) struct NowConstraint {
+    pub op: NowOp,
+    // offset from UTC in seconds of the timezone used for naive datetimes and for "today" when validating dates,
+    // if not set, the local timezone is used
+    utc_offset: Option<i32>,
+}
+
+impl NowConstraint {
+    pub fn from_schema(schema: &PyDict) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let op = match schema.get_as::<&str>(intern!(py, "now_op"))? {
+            Some("past") => NowOp::Past,
+            Some("future") => NowOp::Future,
+            Some(s) => return py_error!(r#"Invalid now_op: "{}""#, s),
+            None => return Ok(None),
+        };
+        Ok(Some(Self {
+            op,
+            utc_offset: schema.get_as(intern!(py, "now_utc_offset"))?,
+        }))
+    }
+
+    pub fn utc_offset(&self, py: Python, now: Duration) -> PyResult<i32> {
+        match self.utc_offset {
+            Some(utc_offset) => Ok(utc_offset),
+            None => {
+                let local_time = py.import("time")?.call_method1("localtime", (now.as_secs(),))?;
+                local_time.getattr(intern!(py, "tm_gmtoff"))?.extract()
+            }
+        }
     }
 }
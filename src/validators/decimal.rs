@@ -0,0 +1,145 @@
+use std::cmp::max;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+
+use crate::build_tools::{is_strict, schema_or_config, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct DecimalValidator {
+    strict: bool,
+    allow_inf_nan: bool,
+}
+
+impl BuildValidator for DecimalValidator {
+    const EXPECTED_TYPE: &'static str = "decimal";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let use_constrained = schema.get_item(intern!(py, "max_digits")).is_some()
+            || schema.get_item(intern!(py, "decimal_places")).is_some();
+        if use_constrained {
+            ConstrainedDecimalValidator::build(schema, config)
+        } else {
+            Ok(Self {
+                strict: is_strict(schema, config)?,
+                allow_inf_nan: allow_inf_nan(schema, config)?,
+            }
+            .into())
+        }
+    }
+}
+
+impl Validator for DecimalValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let decimal = input.validate_decimal(py, extra.strict.unwrap_or(self.strict))?;
+        if !self.allow_inf_nan && !decimal.call_method0("is_finite")?.is_true()? {
+            return Err(ValError::new(ErrorKind::DecimalFinite, input));
+        }
+        Ok(decimal.into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConstrainedDecimalValidator {
+    strict: bool,
+    allow_inf_nan: bool,
+    max_digits: Option<usize>,
+    decimal_places: Option<usize>,
+}
+
+impl Validator for ConstrainedDecimalValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let decimal = input.validate_decimal(py, extra.strict.unwrap_or(self.strict))?;
+
+        let (_, digit_tuple, exponent): (&PyAny, &PyTuple, &PyAny) = decimal.call_method0("as_tuple")?.extract()?;
+        // the exponent is a string ('n', 'N' or 'F') for NaN and infinity, digits can't be counted
+        let exponent: i64 = match exponent.extract() {
+            Ok(exponent) => exponent,
+            Err(_) if self.allow_inf_nan => return Ok(decimal.into_py(py)),
+            Err(_) => return Err(ValError::new(ErrorKind::DecimalFinite, input)),
+        };
+        // same logic as pydantic v1's `ConstrainedDecimal`, e.g. "1.23" has 3 digits and 2 decimal places,
+        // "0.001" has 3 digits and 3 decimal places and "100" has 3 digits and no decimal places
+        let (digits, decimals) = if exponent >= 0 {
+            (digit_tuple.len() + exponent as usize, 0)
+        } else {
+            let decimals = exponent.unsigned_abs() as usize;
+            (max(digit_tuple.len(), decimals), decimals)
+        };
+
+        if let Some(max_digits) = self.max_digits {
+            if digits > max_digits {
+                return Err(ValError::new(ErrorKind::DecimalMaxDigits { max_digits, digits }, input));
+            }
+        }
+        if let Some(decimal_places) = self.decimal_places {
+            if decimals > decimal_places {
+                return Err(ValError::new(
+                    ErrorKind::DecimalMaxPlaces {
+                        decimal_places,
+                        decimals,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(decimal.into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        "constrained-decimal"
+    }
+}
+
+impl ConstrainedDecimalValidator {
+    fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            allow_inf_nan: allow_inf_nan(schema, config)?,
+            max_digits: schema.get_as(intern!(py, "max_digits"))?,
+            decimal_places: schema.get_as(intern!(py, "decimal_places"))?,
+        }
+        .into())
+    }
+}
+
+fn allow_inf_nan(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
+    let py = schema.py();
+    let allow = schema_or_config(
+        schema,
+        config,
+        intern!(py, "allow_inf_nan"),
+        intern!(py, "decimal_allow_inf_nan"),
+    )?;
+    Ok(allow.unwrap_or(false))
+}
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -13,8 +15,8 @@ use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Ex
 #[derive(Debug, Clone)]
 pub struct DictValidator {
     strict: bool,
-    key_validator: Box<CombinedValidator>,
-    value_validator: Box<CombinedValidator>,
+    key_validator: Arc<CombinedValidator>,
+    value_validator: Arc<CombinedValidator>,
     min_items: Option<usize>,
     max_items: Option<usize>,
     name: String,
@@ -30,12 +32,12 @@ impl BuildValidator for DictValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let key_validator = match schema.get_item(intern!(py, "keys_schema")) {
-            Some(schema) => Box::new(build_validator(schema, config, build_context)?.0),
-            None => Box::new(AnyValidator::build(schema, config, build_context)?),
+            Some(schema) => Arc::new(build_validator(schema, config, build_context)?.0),
+            None => Arc::new(AnyValidator::build(schema, config, build_context)?),
         };
         let value_validator = match schema.get_item(intern!(py, "values_schema")) {
-            Some(d) => Box::new(build_validator(d, config, build_context)?.0),
-            None => Box::new(AnyValidator::build(schema, config, build_context)?),
+            Some(d) => Arc::new(build_validator(d, config, build_context)?.0),
+            None => Arc::new(AnyValidator::build(schema, config, build_context)?),
         };
         let name = format!(
             "{}[{},{}]",
@@ -79,8 +81,8 @@ impl Validator for DictValidator {
     }
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
-        self.key_validator.complete(build_context)?;
-        self.value_validator.complete(build_context)
+        Arc::make_mut(&mut self.key_validator).complete(build_context)?;
+        Arc::make_mut(&mut self.value_validator).complete(build_context)
     }
 }
 
@@ -97,12 +99,24 @@ macro_rules! build_validate {
         ) -> ValResult<'data, PyObject> {
             if let Some(min_length) = self.min_items {
                 if dict.len() < min_length {
-                    return Err(ValError::new(ErrorKind::TooShort { min_length }, input));
+                    return Err(ValError::new(
+                        ErrorKind::TooShort {
+                            min_length,
+                            input_length: dict.len(),
+                        },
+                        input,
+                    ));
                 }
             }
             if let Some(max_length) = self.max_items {
                 if dict.len() > max_length {
-                    return Err(ValError::new(ErrorKind::TooLong { max_length }, input));
+                    return Err(ValError::new(
+                        ErrorKind::TooLong {
+                            max_length,
+                            input_length: Some(dict.len()),
+                        },
+                        input,
+                    ));
                 }
             }
             let output = PyDict::new(py);
@@ -112,7 +126,10 @@ macro_rules! build_validate {
             let value_validator = self.value_validator.as_ref();
 
             for (key, value) in dict.iter() {
-                let output_key = match key_validator.validate(py, key, extra, slots, recursion_guard) {
+                let warnings_start = extra.warnings_count();
+                let key_result = key_validator.validate(py, key, extra, slots, recursion_guard);
+                extra.warnings_outer_location(warnings_start, "[key]");
+                let output_key = match key_result {
                     Ok(value) => Some(value),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
@@ -126,7 +143,9 @@ macro_rules! build_validate {
                     }
                     Err(err) => return Err(err),
                 };
-                let output_value = match value_validator.validate(py, value, extra, slots, recursion_guard) {
+                let value_result = value_validator.validate(py, value, extra, slots, recursion_guard);
+                extra.warnings_outer_location(warnings_start, key.as_loc_item());
+                let output_value = match value_result {
                     Ok(value) => Some(value),
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
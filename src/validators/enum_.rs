@@ -0,0 +1,90 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyType};
+
+use crate::build_tools::{is_strict, py_error, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct EnumValidator {
+    class: Py<PyType>,
+    // map of member values to members
+    members: Py<PyDict>,
+    strict: bool,
+    expected_repr: String,
+    name: String,
+}
+
+impl BuildValidator for EnumValidator {
+    const EXPECTED_TYPE: &'static str = "enum";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
+        let member_list = PyList::new(py, class.iter()?.collect::<PyResult<Vec<_>>>()?);
+        if member_list.is_empty() {
+            return py_error!("enum class must have at least one member");
+        }
+
+        let members = PyDict::new(py);
+        let mut repr_args: Vec<String> = Vec::with_capacity(member_list.len());
+        for member in member_list.iter() {
+            let value = member.getattr(intern!(py, "value"))?;
+            repr_args.push(value.repr()?.extract()?);
+            members.set_item(value, member)?;
+        }
+        let class_repr = class.name()?.to_string();
+        Ok(Self {
+            class: class.into(),
+            members: members.into(),
+            strict: is_strict(schema, config)?,
+            expected_repr: repr_args.join(", "),
+            name: format!("{}[{}]", Self::EXPECTED_TYPE, class_repr),
+        }
+        .into())
+    }
+}
+
+impl Validator for EnumValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if input.is_instance(self.class.as_ref(py))? {
+            return Ok(input.to_object(py));
+        }
+        // JSON can't contain enum members, so JSON input is always looked up by value
+        if !extra.strict.unwrap_or(self.strict) || !input.is_python() {
+            let key = input.to_object(py);
+            let key = key.as_ref(py);
+            if let Some(member) = self.members.as_ref(py).get_item(key) {
+                // the lookup uses hash equality, so e.g. `True` and `1.0` would otherwise match a member with value `1`
+                if key.get_type().is(member.getattr(intern!(py, "value"))?.get_type()) {
+                    return Ok(member.into_py(py));
+                }
+            }
+        }
+        Err(ValError::new(
+            ErrorKind::EnumMember {
+                expected: self.expected_repr.clone(),
+            },
+            input,
+        ))
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
@@ -1,17 +1,25 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyFloat};
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, schema_or_config, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
+fn validate_float<'data>(input: &'data impl Input<'data>, strict: bool, reject_int: bool) -> ValResult<'data, f64> {
+    match strict && reject_int {
+        true => input.exact_float(),
+        false => input.validate_float(strict),
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
     strict: bool,
+    strict_float_reject_int: bool,
 }
 
 impl BuildValidator for FloatValidator {
@@ -27,12 +35,14 @@ impl BuildValidator for FloatValidator {
             || schema.get_item(intern!(py, "le")).is_some()
             || schema.get_item(intern!(py, "lt")).is_some()
             || schema.get_item(intern!(py, "ge")).is_some()
-            || schema.get_item(intern!(py, "gt")).is_some();
+            || schema.get_item(intern!(py, "gt")).is_some()
+            || !allow_inf_nan(schema, config)?;
         if use_constrained {
             ConstrainedFloatValidator::build(schema, config)
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                strict_float_reject_int: schema.get_as(intern!(py, "strict_float_reject_int"))?.unwrap_or(false),
             }
             .into())
         }
@@ -48,7 +58,10 @@ impl Validator for FloatValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_float(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        let float = validate_float(input, strict, self.strict_float_reject_int)?;
+        extra.warn_if_coerced(py, input, strict, "float", || input.strict_float().is_ok());
+        Ok(float_output(py, input, float))
     }
 
     fn get_name(&self) -> &str {
@@ -59,6 +72,8 @@ impl Validator for FloatValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedFloatValidator {
     strict: bool,
+    strict_float_reject_int: bool,
+    allow_inf_nan: bool,
     multiple_of: Option<f64>,
     le: Option<f64>,
     lt: Option<f64>,
@@ -75,33 +90,52 @@ impl Validator for ConstrainedFloatValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let float = input.validate_float(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let float = validate_float(input, strict, self.strict_float_reject_int)?;
+        extra.warn_if_coerced(py, input, strict, "float", || input.strict_float().is_ok());
+        if !self.allow_inf_nan && !float.is_finite() {
+            let value = match float {
+                f if f.is_nan() => "nan",
+                f if f > 0.0 => "inf",
+                _ => "-inf",
+            };
+            return Err(ValError::new(ErrorKind::FloatFiniteNumber { value }, input));
+        }
         if let Some(multiple_of) = self.multiple_of {
             if float % multiple_of != 0.0 {
-                return Err(ValError::new(ErrorKind::FloatMultipleOf { multiple_of }, input));
+                return Err(ValError::new(
+                    ErrorKind::FloatMultipleOf {
+                        multiple_of,
+                        value: float,
+                    },
+                    input,
+                ));
             }
         }
         if let Some(le) = self.le {
             if float > le {
-                return Err(ValError::new(ErrorKind::FloatLessThanEqual { le }, input));
+                return Err(ValError::new(ErrorKind::FloatLessThanEqual { le, value: float }, input));
             }
         }
         if let Some(lt) = self.lt {
             if float >= lt {
-                return Err(ValError::new(ErrorKind::FloatLessThan { lt }, input));
+                return Err(ValError::new(ErrorKind::FloatLessThan { lt, value: float }, input));
             }
         }
         if let Some(ge) = self.ge {
             if float < ge {
-                return Err(ValError::new(ErrorKind::FloatGreaterThanEqual { ge }, input));
+                return Err(ValError::new(
+                    ErrorKind::FloatGreaterThanEqual { ge, value: float },
+                    input,
+                ));
             }
         }
         if let Some(gt) = self.gt {
             if float <= gt {
-                return Err(ValError::new(ErrorKind::FloatGreaterThan { gt }, input));
+                return Err(ValError::new(ErrorKind::FloatGreaterThan { gt, value: float }, input));
             }
         }
-        Ok(float.into_py(py))
+        Ok(float_output(py, input, float))
     }
     fn get_name(&self) -> &str {
         "constrained-float"
@@ -113,6 +147,8 @@ impl ConstrainedFloatValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            strict_float_reject_int: schema.get_as(intern!(py, "strict_float_reject_int"))?.unwrap_or(false),
+            allow_inf_nan: allow_inf_nan(schema, config)?,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
@@ -122,3 +158,23 @@ impl ConstrainedFloatValidator {
         .into())
     }
 }
+
+fn allow_inf_nan(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
+    let py = schema.py();
+    let allow = schema_or_config(
+        schema,
+        config,
+        intern!(py, "allow_inf_nan"),
+        intern!(py, "float_allow_inf_nan"),
+    )?;
+    Ok(allow.unwrap_or(true))
+}
+
+/// floats are immutable so an exact `float` input is returned as is rather than creating a new `float`
+fn float_output<'data>(py: Python<'data>, input: &'data impl Input<'data>, float: f64) -> PyObject {
+    if input.is_exact_instance(py.get_type::<PyFloat>()) {
+        input.to_object(py)
+    } else {
+        float.into_py(py)
+    }
+}
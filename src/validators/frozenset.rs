@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFrozenSet};
 
@@ -7,19 +9,21 @@ use crate::input::{GenericListLike, Input};
 use crate::recursion_guard::RecursionGuard;
 
 use super::list::generic_list_like_build;
+use super::set::unhashable_items_error;
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
 pub struct FrozenSetValidator {
     strict: bool,
-    item_validator: Option<Box<CombinedValidator>>,
+    item_validator: Option<Arc<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
+    fail_fast: bool,
     name: String,
 }
 
 impl BuildValidator for FrozenSetValidator {
     const EXPECTED_TYPE: &'static str = "frozenset";
-    generic_list_like_build!();
+    generic_list_like_build!("{}[{}]", Self::EXPECTED_TYPE, fail_fast);
 }
 
 impl Validator for FrozenSetValidator {
@@ -33,16 +37,19 @@ impl Validator for FrozenSetValidator {
     ) -> ValResult<'data, PyObject> {
         let seq = input.validate_frozenset(extra.strict.unwrap_or(self.strict))?;
 
-        let length = seq.check_len(self.size_range, input)?;
+        seq.check_len(self.size_range, input)?;
 
         let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, length, v, extra, slots, recursion_guard)?,
+            Some(ref v) => seq.validate_to_vec(py, v, self.fail_fast, extra, slots, recursion_guard)?,
             None => match seq {
                 GenericListLike::FrozenSet(f_set) => return Ok(f_set.into_py(py)),
                 _ => seq.to_vec(py),
             },
         };
-        Ok(PyFrozenSet::new(py, &output)?.into_py(py))
+        match PyFrozenSet::new(py, &output) {
+            Ok(f_set) => Ok(f_set.into_py(py)),
+            Err(err) => Err(unhashable_items_error(py, err, output)),
+        }
     }
 
     fn get_name(&self) -> &str {
@@ -51,7 +58,7 @@ impl Validator for FrozenSetValidator {
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         match self.item_validator {
-            Some(ref mut v) => v.complete(build_context),
+            Some(ref mut v) => Arc::make_mut(v).complete(build_context),
             None => Ok(()),
         }
     }
@@ -1,3 +1,6 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use pyo3::exceptions::{PyAssertionError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
@@ -50,7 +53,7 @@ macro_rules! impl_build {
                 let validator = build_validator(schema.get_as_req(intern!(py, "schema"))?, config, build_context)?.0;
                 let name = format!("{}[{}]", $name, validator.get_name());
                 Ok(Self {
-                    validator: Box::new(validator),
+                    validator: Arc::new(validator),
                     func: schema.get_as_req::<&PyAny>(intern!(py, "function"))?.into_py(py),
                     config: match config {
                         Some(c) => c.into(),
@@ -66,7 +69,7 @@ macro_rules! impl_build {
 
 #[derive(Debug, Clone)]
 pub struct FunctionBeforeValidator {
-    validator: Box<CombinedValidator>,
+    validator: Arc<CombinedValidator>,
     func: PyObject,
     config: PyObject,
     name: String,
@@ -90,20 +93,11 @@ impl Validator for FunctionBeforeValidator {
             .map_err(|e| convert_err(py, e, input))?;
         // maybe there's some way to get the PyAny here and explicitly tell rust it should have lifespan 'a?
         let new_input: &PyAny = value.as_ref(py);
-        match self.validator.validate(py, new_input, extra, slots, recursion_guard) {
-            Ok(v) => Ok(v),
-            Err(ValError::InternalErr(err)) => Err(ValError::InternalErr(err)),
-            Err(ValError::LineErrors(line_errors)) => {
-                // we have to be explicit about clone line errors to a new lifetime since new_input doesn't have
-                // the 'data lifetime
-                Err(ValError::LineErrors(
-                    line_errors
-                        .into_iter()
-                        .map(|line_error| line_error.into_new(py))
-                        .collect(),
-                ))
-            }
-        }
+        // we have to be explicit about clone line errors to a new lifetime since new_input doesn't have
+        // the 'data lifetime
+        self.validator
+            .validate(py, new_input, extra, slots, recursion_guard)
+            .map_err(|err| err.into_new(py))
     }
 
     fn get_name(&self) -> &str {
@@ -111,13 +105,13 @@ impl Validator for FunctionBeforeValidator {
     }
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
-        self.validator.complete(build_context)
+        Arc::make_mut(&mut self.validator).complete(build_context)
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct FunctionAfterValidator {
-    validator: Box<CombinedValidator>,
+    validator: Arc<CombinedValidator>,
     func: PyObject,
     config: PyObject,
     name: String,
@@ -144,7 +138,7 @@ impl Validator for FunctionAfterValidator {
     }
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
-        self.validator.complete(build_context)
+        Arc::make_mut(&mut self.validator).complete(build_context)
     }
 }
 
@@ -194,7 +188,7 @@ impl Validator for FunctionPlainValidator {
 
 #[derive(Debug, Clone)]
 pub struct FunctionWrapValidator {
-    validator: Box<CombinedValidator>,
+    validator: Arc<CombinedValidator>,
     func: PyObject,
     config: PyObject,
     name: String,
@@ -213,11 +207,16 @@ impl Validator for FunctionWrapValidator {
     ) -> ValResult<'data, PyObject> {
         let validator_kwarg = ValidatorCallable {
             validator: self.validator.clone(),
-            slots: slots.to_vec(),
+            // `extra.slots` is only missing when validating the self schema, which has no wrap validators
+            slots: match extra.slots {
+                Some(slots) => slots.clone(),
+                None => slots.into(),
+            },
             data: extra.data.map(|d| d.into_py(py)),
             field: extra.field.map(|f| f.to_string()),
             strict: extra.strict,
             context: extra.context.map(|d| d.into_py(py)),
+            now: extra.now,
             recursion_guard: recursion_guard.clone(),
         };
         let kwargs = kwargs!(
@@ -237,19 +236,20 @@ impl Validator for FunctionWrapValidator {
     }
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
-        self.validator.complete(build_context)
+        Arc::make_mut(&mut self.validator).complete(build_context)
     }
 }
 
 #[pyclass]
 #[derive(Debug, Clone)]
 struct ValidatorCallable {
-    validator: Box<CombinedValidator>,
-    slots: Vec<CombinedValidator>,
+    validator: Arc<CombinedValidator>,
+    slots: Arc<[CombinedValidator]>,
     data: Option<Py<PyDict>>,
     field: Option<String>,
     strict: Option<bool>,
     context: Option<PyObject>,
+    now: Option<Duration>,
     recursion_guard: RecursionGuard,
 }
 
@@ -261,6 +261,10 @@ impl ValidatorCallable {
             field: self.field.as_deref(),
             strict: self.strict,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
+            now: self.now,
+            // the callable may outlive the validation call, so warnings can't be collected through it
+            warnings: None,
+            slots: Some(&self.slots),
         };
         self.validator
             .validate(py, arg, &extra, &self.slots, &mut self.recursion_guard)
@@ -294,7 +298,7 @@ macro_rules! py_err_string {
     };
 }
 
-fn convert_err<'a>(py: Python<'a>, err: PyErr, input: &'a impl Input<'a>) -> ValError<'a> {
+pub fn convert_err<'a>(py: Python<'a>, err: PyErr, input: &'a impl Input<'a>) -> ValError<'a> {
     // Only ValueError and AssertionError are considered as validation errors,
     // TypeError is now considered as a runtime error to catch errors in function signatures
     if err.is_instance_of::<PyValueError>(py) {
@@ -1,8 +1,9 @@
+use num_bigint::{BigInt, Sign};
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyInt};
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, py_error, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
@@ -29,7 +30,15 @@ impl BuildValidator for IntValidator {
             || schema.get_item(intern!(py, "ge")).is_some()
             || schema.get_item(intern!(py, "gt")).is_some();
         if use_constrained {
-            ConstrainedIntValidator::build(schema, config)
+            // constraints which don't fit in an i64 have to be compared as big ints
+            let use_big_int = ["multiple_of", "le", "lt", "ge", "gt"]
+                .iter()
+                .any(|key| matches!(schema.get_item(key), Some(value) if value.extract::<i64>().is_err()));
+            if use_big_int {
+                ConstrainedBigIntValidator::build(schema, config)
+            } else {
+                ConstrainedIntValidator::build(schema, config)
+            }
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
@@ -48,7 +57,15 @@ impl Validator for IntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_int(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        match input.validate_int(strict) {
+            Ok(int) => {
+                extra.warn_if_coerced(py, input, strict, "int", || input.validate_int(true).is_ok());
+                Ok(int_output(py, input, int))
+            }
+            // ints too big for an i64 are returned as-is, see `ConstrainedBigIntValidator` for constraints
+            Err(err) => input.as_big_int(py, strict)?.ok_or(err),
+        }
     }
 
     fn get_name(&self) -> &str {
@@ -75,33 +92,74 @@ impl Validator for ConstrainedIntValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let int = input.validate_int(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let int = match input.validate_int(strict) {
+            Ok(int) => int,
+            // ints too big for an i64 are checked against the constraints as big ints
+            Err(err) => {
+                return match input.as_big_int(py, strict)? {
+                    Some(big_int) => ConstrainedBigIntValidator::from(self).check(py, input, big_int.extract(py)?),
+                    None => Err(err),
+                }
+            }
+        };
+        extra.warn_if_coerced(py, input, strict, "int", || input.validate_int(true).is_ok());
         if let Some(multiple_of) = self.multiple_of {
             if int % multiple_of != 0 {
-                return Err(ValError::new(ErrorKind::IntMultipleOf { multiple_of }, input));
+                return Err(ValError::new(
+                    ErrorKind::IntMultipleOf {
+                        multiple_of: multiple_of.into(),
+                        value: int.into(),
+                    },
+                    input,
+                ));
             }
         }
         if let Some(le) = self.le {
             if int > le {
-                return Err(ValError::new(ErrorKind::IntLessThanEqual { le }, input));
+                return Err(ValError::new(
+                    ErrorKind::IntLessThanEqual {
+                        le: le.into(),
+                        value: int.into(),
+                    },
+                    input,
+                ));
             }
         }
         if let Some(lt) = self.lt {
             if int >= lt {
-                return Err(ValError::new(ErrorKind::IntLessThan { lt }, input));
+                return Err(ValError::new(
+                    ErrorKind::IntLessThan {
+                        lt: lt.into(),
+                        value: int.into(),
+                    },
+                    input,
+                ));
             }
         }
         if let Some(ge) = self.ge {
             if int < ge {
-                return Err(ValError::new(ErrorKind::IntGreaterThanEqual { ge }, input));
+                return Err(ValError::new(
+                    ErrorKind::IntGreaterThanEqual {
+                        ge: ge.into(),
+                        value: int.into(),
+                    },
+                    input,
+                ));
             }
         }
         if let Some(gt) = self.gt {
             if int <= gt {
-                return Err(ValError::new(ErrorKind::IntGreaterThan { gt }, input));
+                return Err(ValError::new(
+                    ErrorKind::IntGreaterThan {
+                        gt: gt.into(),
+                        value: int.into(),
+                    },
+                    input,
+                ));
             }
         }
-        Ok(int.into_py(py))
+        Ok(int_output(py, input, int))
     }
 
     fn get_name(&self) -> &str {
@@ -112,9 +170,13 @@ impl Validator for ConstrainedIntValidator {
 impl ConstrainedIntValidator {
     fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
         let py = schema.py();
+        let multiple_of: Option<i64> = schema.get_as(intern!(py, "multiple_of"))?;
+        if matches!(multiple_of, Some(multiple_of) if multiple_of <= 0) {
+            return py_error!("'multiple_of' must be greater than 0");
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
-            multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
+            multiple_of,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
             ge: schema.get_as(intern!(py, "ge"))?,
@@ -123,3 +185,149 @@ impl ConstrainedIntValidator {
         .into())
     }
 }
+
+/// Used when any constraint is too large to fit in an i64, `ConstrainedIntValidator` also uses it to check
+/// big int inputs against its constraints
+#[derive(Debug, Clone)]
+pub struct ConstrainedBigIntValidator {
+    strict: bool,
+    multiple_of: Option<BigInt>,
+    le: Option<BigInt>,
+    lt: Option<BigInt>,
+    ge: Option<BigInt>,
+    gt: Option<BigInt>,
+}
+
+impl Validator for ConstrainedBigIntValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        _slots: &'data [CombinedValidator],
+        _recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let strict = extra.strict.unwrap_or(self.strict);
+        let int: BigInt = match input.validate_int(strict) {
+            Ok(int) => {
+                extra.warn_if_coerced(py, input, strict, "int", || input.validate_int(true).is_ok());
+                int.into()
+            }
+            Err(err) => match input.as_big_int(py, strict)? {
+                Some(big_int) => big_int.extract(py)?,
+                None => return Err(err),
+            },
+        };
+        self.check(py, input, int)
+    }
+
+    fn get_name(&self) -> &str {
+        "constrained-int"
+    }
+}
+
+impl ConstrainedBigIntValidator {
+    fn build(schema: &PyDict, config: Option<&PyDict>) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let multiple_of: Option<BigInt> = schema.get_as(intern!(py, "multiple_of"))?;
+        if matches!(multiple_of, Some(ref multiple_of) if multiple_of.sign() != Sign::Plus) {
+            return py_error!("'multiple_of' must be greater than 0");
+        }
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            multiple_of,
+            le: schema.get_as(intern!(py, "le"))?,
+            lt: schema.get_as(intern!(py, "lt"))?,
+            ge: schema.get_as(intern!(py, "ge"))?,
+            gt: schema.get_as(intern!(py, "gt"))?,
+        }
+        .into())
+    }
+
+    fn check<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        int: BigInt,
+    ) -> ValResult<'data, PyObject> {
+        if let Some(ref multiple_of) = self.multiple_of {
+            if (&int % multiple_of).sign() != Sign::NoSign {
+                return Err(ValError::new(
+                    ErrorKind::IntMultipleOf {
+                        multiple_of: multiple_of.clone(),
+                        value: int,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ref le) = self.le {
+            if &int > le {
+                return Err(ValError::new(
+                    ErrorKind::IntLessThanEqual {
+                        le: le.clone(),
+                        value: int,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ref lt) = self.lt {
+            if &int >= lt {
+                return Err(ValError::new(
+                    ErrorKind::IntLessThan {
+                        lt: lt.clone(),
+                        value: int,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ref ge) = self.ge {
+            if &int < ge {
+                return Err(ValError::new(
+                    ErrorKind::IntGreaterThanEqual {
+                        ge: ge.clone(),
+                        value: int,
+                    },
+                    input,
+                ));
+            }
+        }
+        if let Some(ref gt) = self.gt {
+            if &int <= gt {
+                return Err(ValError::new(
+                    ErrorKind::IntGreaterThan {
+                        gt: gt.clone(),
+                        value: int,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(int_output(py, input, int))
+    }
+}
+
+impl From<&ConstrainedIntValidator> for ConstrainedBigIntValidator {
+    fn from(validator: &ConstrainedIntValidator) -> Self {
+        Self {
+            strict: validator.strict,
+            multiple_of: validator.multiple_of.map(BigInt::from),
+            le: validator.le.map(BigInt::from),
+            lt: validator.lt.map(BigInt::from),
+            ge: validator.ge.map(BigInt::from),
+            gt: validator.gt.map(BigInt::from),
+        }
+    }
+}
+
+/// ints are immutable so an exact `int` input is returned as is, anything else (e.g. a string in lax mode or a
+/// subclass like an `IntEnum` member) is converted to a new `int`
+fn int_output<'data>(py: Python<'data>, input: &'data impl Input<'data>, int: impl IntoPy<PyObject>) -> PyObject {
+    if input.is_exact_instance(py.get_type::<PyInt>()) {
+        input.to_object(py)
+    } else {
+        int.into_py(py)
+    }
+}
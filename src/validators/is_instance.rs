@@ -24,7 +24,18 @@ impl BuildValidator for IsInstanceValidator {
         _config: Option<&PyDict>,
         _build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
-        let class: &PyType = schema.get_as_req(intern!(schema.py(), "class_"))?;
+        let py = schema.py();
+        let cls_key = intern!(py, "cls");
+        let deprecated_key = intern!(py, "class_");
+        // `class_` was the name of this key before `cls`, it's still accepted for now
+        let class: &PyType = match (schema.contains(cls_key)?, schema.contains(deprecated_key)?) {
+            (false, true) => {
+                let category = py.import("builtins")?.getattr(intern!(py, "DeprecationWarning"))?;
+                PyErr::warn(py, category, "'class_' is deprecated, use 'cls' instead", 1)?;
+                schema.get_as_req(deprecated_key)?
+            }
+            _ => schema.get_as_req(cls_key)?,
+        };
         let class_repr = class.name()?.to_string();
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, class_repr);
         Ok(Self {
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{is_strict, SchemaDict};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+pub struct JsonValidator {
+    validator: Option<Arc<CombinedValidator>>,
+    strict: bool,
+    reject_duplicate_keys: bool,
+    name: String,
+}
+
+impl BuildValidator for JsonValidator {
+    const EXPECTED_TYPE: &'static str = "json";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let validator = match schema.get_item(intern!(schema.py(), "schema")) {
+            Some(sub_schema) => Some(Arc::new(build_validator(sub_schema, config, build_context)?.0)),
+            None => None,
+        };
+        let inner_name = validator.as_ref().map(|v| v.get_name()).unwrap_or("any");
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, inner_name);
+        Ok(Self {
+            validator,
+            strict: is_strict(schema, config)?,
+            reject_duplicate_keys: config
+                .get_as(intern!(schema.py(), "json_reject_duplicate_keys"))?
+                .unwrap_or(false),
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for JsonValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        match input.parse_json(self.reject_duplicate_keys)? {
+            Some(json_input) => match self.validator {
+                // the parsed input doesn't have the 'data lifetime, so errors need to be converted
+                Some(ref validator) => validator
+                    .validate(py, &json_input, extra, slots, recursion_guard)
+                    .map_err(|err| err.into_new(py)),
+                None => Ok(json_input.to_object(py)),
+            },
+            None if extra.strict.unwrap_or(self.strict) => Err(ValError::new(ErrorKind::JsonType, input)),
+            // in lax mode, input which isn't a string is assumed to have already been parsed
+            None => match self.validator {
+                Some(ref validator) => validator.validate(py, input, extra, slots, recursion_guard),
+                None => Ok(input.to_object(py)),
+            },
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        match self.validator {
+            Some(ref mut v) => Arc::make_mut(v).complete(build_context),
+            None => Ok(()),
+        }
+    }
+}
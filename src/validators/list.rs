@@ -1,9 +1,12 @@
+use std::sync::Arc;
+
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PySet};
 
 use crate::build_tools::SchemaDict;
-use crate::errors::ValResult;
-use crate::input::{GenericListLike, Input};
+use crate::errors::{ErrorKind, ValError, ValResult};
+use crate::input::{GenericListLike, Input, JsonInput};
 use crate::recursion_guard::RecursionGuard;
 
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -11,24 +14,32 @@ use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Ex
 #[derive(Debug, Clone)]
 pub struct ListValidator {
     strict: bool,
-    item_validator: Option<Box<CombinedValidator>>,
+    item_validator: Option<Arc<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
+    fail_fast: bool,
+    unique_items: bool,
+    coerce_single_to_list: bool,
     name: String,
 }
 
+/// builds validators for list-like types, extra idents are boolean flags read from the schema, default false
 macro_rules! generic_list_like_build {
     () => {
         super::list::generic_list_like_build!("{}[{}]", Self::EXPECTED_TYPE);
     };
-    ($name_template:literal, $name:expr) => {
+    ($name_template:literal, $name:expr $(, $flag:ident)*) => {
         fn build(
             schema: &PyDict,
             config: Option<&PyDict>,
             build_context: &mut BuildContext,
         ) -> PyResult<CombinedValidator> {
             let py = schema.py();
+            // an `any` items schema is the same as no items schema, items are then copied without calling a validator
             let item_validator = match schema.get_item(pyo3::intern!(py, "items_schema")) {
-                Some(d) => Some(Box::new(build_validator(d, config, build_context)?.0)),
+                Some(d) => match build_validator(d, config, build_context)?.0 {
+                    CombinedValidator::Any(_) => None,
+                    validator => Some(Arc::new(validator)),
+                },
                 None => None,
             };
             let inner_name = item_validator.as_ref().map(|v| v.get_name()).unwrap_or("any");
@@ -42,6 +53,7 @@ macro_rules! generic_list_like_build {
                     true => Some((min_items, max_items)),
                     false => None,
                 },
+                $($flag: schema.get_as(pyo3::intern!(py, stringify!($flag)))?.unwrap_or(false),)*
                 name,
             }
             .into())
@@ -52,7 +64,13 @@ pub(crate) use generic_list_like_build;
 
 impl BuildValidator for ListValidator {
     const EXPECTED_TYPE: &'static str = "list";
-    generic_list_like_build!();
+    generic_list_like_build!(
+        "{}[{}]",
+        Self::EXPECTED_TYPE,
+        unique_items,
+        coerce_single_to_list,
+        fail_fast
+    );
 }
 
 impl Validator for ListValidator {
@@ -64,17 +82,43 @@ impl Validator for ListValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let seq = input.validate_list(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let seq = match input.validate_list(strict, self.max_items()) {
+            Ok(seq) => seq,
+            // only input which isn't list-like is coerced, not e.g. an iterator which is too long
+            Err(ValError::LineErrors(ref line_errors))
+                if self.coerce_single_to_list
+                    && !strict
+                    && line_errors
+                        .iter()
+                        .all(|line_error| matches!(line_error.kind, ErrorKind::ListType)) =>
+            {
+                return self.validate_single(py, input, extra, slots, recursion_guard);
+            }
+            Err(err) => return Err(err),
+        };
 
-        let length = seq.check_len(self.size_range, input)?;
+        seq.check_len(self.size_range, input)?;
 
         let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, length, v, extra, slots, recursion_guard)?,
+            Some(ref v) => seq.validate_to_vec(py, v, self.fail_fast, extra, slots, recursion_guard)?,
             None => match seq {
-                GenericListLike::List(list) => return Ok(list.into_py(py)),
+                GenericListLike::List(list) => {
+                    if self.unique_items {
+                        check_unique(py, &list.iter().collect::<Vec<_>>(), input)?;
+                    }
+                    return Ok(list.into_py(py));
+                }
                 _ => seq.to_vec(py),
             },
         };
+        if self.unique_items {
+            check_unique(
+                py,
+                &output.iter().map(|item| item.as_ref(py)).collect::<Vec<_>>(),
+                input,
+            )?;
+        }
         Ok(output.into_py(py))
     }
 
@@ -84,8 +128,115 @@ impl Validator for ListValidator {
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         match self.item_validator {
-            Some(ref mut v) => v.complete(build_context),
+            Some(ref mut v) => Arc::make_mut(v).complete(build_context),
             None => Ok(()),
         }
     }
 }
+
+impl ListValidator {
+    /// Used in lax mode when `coerce_single_to_list` is set and the input isn't list-like, the input is validated
+    /// as the only item of a list
+    fn validate_single<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if let Some((min_items, max_items)) = self.size_range {
+            if let Some(min_length) = min_items.filter(|min_length| *min_length > 1) {
+                return Err(ValError::new(
+                    ErrorKind::TooShort {
+                        min_length,
+                        input_length: 1,
+                    },
+                    input,
+                ));
+            }
+            if let Some(max_length) = max_items.filter(|max_length| *max_length == 0) {
+                return Err(ValError::new(
+                    ErrorKind::TooLong {
+                        max_length,
+                        input_length: Some(1),
+                    },
+                    input,
+                ));
+            }
+        }
+        let item = match self.item_validator {
+            Some(ref v) => v.validate(py, input, extra, slots, recursion_guard)?,
+            None => input.to_object(py),
+        };
+        Ok(PyList::new(py, [item]).into_py(py))
+    }
+
+    /// Check a JSON array is valid before its items are validated one at a time with `validate_item`,
+    /// see `SchemaValidator.validate_json_iter`
+    pub fn validate_array<'data>(&self, input: &'data JsonInput, extra: &Extra) -> ValResult<'data, ()> {
+        let seq = input.validate_list(extra.strict.unwrap_or(self.strict), self.max_items())?;
+        seq.check_len(self.size_range, input)?;
+        Ok(())
+    }
+
+    pub fn validate_item<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        index: usize,
+        item: &'data JsonInput,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        match self.item_validator {
+            Some(ref v) => v
+                .validate(py, item, extra, slots, recursion_guard)
+                .map_err(|err| err.with_outer_location(index.into())),
+            None => Ok(item.to_object(py)),
+        }
+    }
+
+    fn max_items(&self) -> Option<usize> {
+        self.size_range.and_then(|(_, max_items)| max_items)
+    }
+
+    /// uniqueness can't be checked without holding all items, so isn't supported when validating item by item
+    pub fn unique_items(&self) -> bool {
+        self.unique_items
+    }
+}
+
+fn check_unique<'data>(py: Python, items: &[&PyAny], input: &'data impl Input<'data>) -> ValResult<'data, ()> {
+    match first_duplicate(py, items)? {
+        Some(index) => Err(ValError::new(ErrorKind::ListUniqueItems { index }, input)),
+        None => Ok(()),
+    }
+}
+
+/// Find the index of the first item which equals an earlier item. Items are hashed where possible (this
+/// includes all JSON values except arrays and objects), if any item is unhashable we fall back to comparing
+/// every pair of items which is O(n²), so `unique_items` should be used with care on long lists of e.g. dicts.
+fn first_duplicate(py: Python, items: &[&PyAny]) -> PyResult<Option<usize>> {
+    let seen = PySet::empty(py)?;
+    for (index, item) in items.iter().enumerate() {
+        match seen.contains(item) {
+            Ok(true) => return Ok(Some(index)),
+            Ok(false) => seen.add(item)?,
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => return first_duplicate_unhashable(items),
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(None)
+}
+
+fn first_duplicate_unhashable(items: &[&PyAny]) -> PyResult<Option<usize>> {
+    for (index, item) in items.iter().enumerate() {
+        for earlier in &items[..index] {
+            if item.eq(earlier)? {
+                return Ok(Some(index));
+            }
+        }
+    }
+    Ok(None)
+}
@@ -1,30 +1,38 @@
 use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use enum_dispatch::enum_dispatch;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyByteArray, PyBytes, PyDict, PyString};
+use pyo3::types::{PyAny, PyDateTime, PyDict};
 
 use crate::build_tools::{py_error, SchemaDict, SchemaError};
-use crate::errors::{ErrorKind, ValError, ValLineError, ValResult, ValidationError};
-use crate::input::{Input, JsonInput};
+use crate::errors::{ErrorKind, LocItem, ValError, ValLineError, ValResult, ValWarnings, ValidationError};
+use crate::input::{parse_json_allow_threads, py_json_data, Input, JsonInput};
 use crate::recursion_guard::RecursionGuard;
 
 mod any;
 mod bool;
 mod bytes;
 mod callable;
+mod chain;
+mod constant;
+mod custom_error;
 mod date;
 mod datetime;
+mod decimal;
 mod dict;
+mod enum_;
 mod float;
 mod frozenset;
 mod function;
 mod int;
 mod is_instance;
+mod json;
 mod list;
 mod literal;
 mod model_class;
@@ -38,14 +46,18 @@ mod timedelta;
 mod tuple;
 mod typed_dict;
 mod union;
+mod with_default;
 
 #[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
 pub struct SchemaValidator {
-    validator: CombinedValidator,
-    slots: Vec<CombinedValidator>,
+    // both are shared so cloning a `SchemaValidator` doesn't clone the validator tree
+    validator: Arc<CombinedValidator>,
+    slots: Arc<[CombinedValidator]>,
     schema: PyObject,
     title: PyObject,
+    max_recursion_depth: Option<u16>,
+    json_reject_duplicate_keys: bool,
 }
 
 #[pymethods]
@@ -70,12 +82,31 @@ impl SchemaValidator {
         let (mut validator, _) = build_validator(schema, config, &mut build_context)?;
         validator.complete(&build_context)?;
         let slots = build_context.into_slots()?;
-        let title = validator.get_name().into_py(py);
+        // the `title` config setting is used in error messages, defaulting to the name of the validator
+        let title = match config.get_as::<String>(intern!(py, "title"))? {
+            Some(title) => title.into_py(py),
+            None => validator.get_name().into_py(py),
+        };
+        let max_recursion_depth: Option<u16> = config.get_as(intern!(py, "max_recursion_depth"))?;
+        if let Some(max_depth) = max_recursion_depth {
+            if max_depth > recursive::MAX_MAX_DEPTH {
+                return py_error!(
+                    "max_recursion_depth must be no greater than {}, got {}",
+                    recursive::MAX_MAX_DEPTH,
+                    max_depth
+                );
+            }
+        }
+        let json_reject_duplicate_keys = config
+            .get_as(intern!(py, "json_reject_duplicate_keys"))?
+            .unwrap_or(false);
         Ok(Self {
-            validator,
-            slots,
+            validator: Arc::new(validator),
+            slots: slots.into(),
             schema: schema.into_py(py),
             title,
+            max_recursion_depth,
+            json_reject_duplicate_keys,
         })
     }
 
@@ -91,13 +122,14 @@ impl SchemaValidator {
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        now: Option<&PyAny>,
     ) -> PyResult<PyObject> {
         let r = self.validator.validate(
             py,
             input,
-            &Extra::new(strict, context),
+            &Extra::new(strict, context, now_from_py(now)?, &self.slots),
             &self.slots,
-            &mut RecursionGuard::default(),
+            &mut RecursionGuard::new(self.max_recursion_depth),
         );
         r.map_err(|e| self.prepare_validation_err(py, e))
     }
@@ -108,13 +140,14 @@ impl SchemaValidator {
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        now: Option<&PyAny>,
     ) -> PyResult<bool> {
         match self.validator.validate(
             py,
             input,
-            &Extra::new(strict, context),
+            &Extra::new(strict, context, now_from_py(now)?, &self.slots),
             &self.slots,
-            &mut RecursionGuard::default(),
+            &mut RecursionGuard::new(self.max_recursion_depth),
         ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
@@ -128,41 +161,78 @@ impl SchemaValidator {
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        now: Option<&PyAny>,
     ) -> PyResult<PyObject> {
-        match parse_json(input)? {
+        match parse_json(input, self.json_reject_duplicate_keys)? {
             Ok(input) => {
                 let r = self.validator.validate(
                     py,
                     &input,
-                    &Extra::new(strict, context),
+                    &Extra::new(strict, context, now_from_py(now)?, &self.slots),
                     &self.slots,
-                    &mut RecursionGuard::default(),
+                    &mut RecursionGuard::new(self.max_recursion_depth),
                 );
                 r.map_err(|e| self.prepare_validation_err(py, e))
             }
-            Err(e) => {
-                let line_err = ValLineError::new(ErrorKind::InvalidJson { error: e.to_string() }, input);
+            Err(kind) => {
+                let line_err = ValLineError::new(kind, input);
                 let err = ValError::LineErrors(vec![line_err]);
                 Err(self.prepare_validation_err(py, err))
             }
         }
     }
 
+    /// Like `validate_json` but also returns a list of warnings about how the input was validated, e.g. where
+    /// lax mode coerced the input to another type, validation errors are raised as usual
+    pub fn validate_json_with_warnings(
+        &self,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        now: Option<&PyAny>,
+    ) -> PyResult<(PyObject, PyObject)> {
+        let json_input = match parse_json(input, self.json_reject_duplicate_keys)? {
+            Ok(json_input) => json_input,
+            Err(kind) => {
+                let err = ValError::LineErrors(vec![ValLineError::new(kind, input)]);
+                return Err(self.prepare_validation_err(py, err));
+            }
+        };
+        let warnings = ValWarnings::default();
+        let extra = Extra {
+            warnings: Some(&warnings),
+            ..Extra::new(strict, context, now_from_py(now)?, &self.slots)
+        };
+        let output = self
+            .validator
+            .validate(
+                py,
+                &json_input,
+                &extra,
+                &self.slots,
+                &mut RecursionGuard::new(self.max_recursion_depth),
+            )
+            .map_err(|e| self.prepare_validation_err(py, e))?;
+        Ok((output, warnings.into_py_list(py)?.into_py(py)))
+    }
+
     pub fn isinstance_json(
         &self,
         py: Python,
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        now: Option<&PyAny>,
     ) -> PyResult<bool> {
-        match parse_json(input)? {
+        match parse_json(input, self.json_reject_duplicate_keys)? {
             Ok(input) => {
                 match self.validator.validate(
                     py,
                     &input,
-                    &Extra::new(strict, context),
+                    &Extra::new(strict, context, now_from_py(now)?, &self.slots),
                     &self.slots,
-                    &mut RecursionGuard::default(),
+                    &mut RecursionGuard::new(self.max_recursion_depth),
                 ) {
                     Ok(_) => Ok(true),
                     Err(ValError::InternalErr(err)) => Err(err),
@@ -173,16 +243,89 @@ impl SchemaValidator {
         }
     }
 
+    /// Validate a JSON array item by item, the returned iterator validates each item as it's requested so the
+    /// full validated output is never held in memory, the schema must be a list schema
+    pub fn validate_json_iter(
+        slf: PyRef<Self>,
+        py: Python,
+        input: &PyAny,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        now: Option<&PyAny>,
+    ) -> PyResult<JsonArrayIterator> {
+        let (list_validator, custom_error) = match json_iter_validator(&slf.validator, &slf.slots, None) {
+            Some(found) => found,
+            None => return py_error!(PyTypeError; "validate_json_iter can only be used with a list schema"),
+        };
+        if list_validator.unique_items() {
+            return py_error!(PyTypeError; "validate_json_iter doesn't support 'unique_items'");
+        }
+        let now = now_from_py(now)?;
+        let json_input = match parse_json(input, slf.json_reject_duplicate_keys)? {
+            Ok(json_input) => json_input,
+            Err(kind) => {
+                let err = ValError::LineErrors(vec![ValLineError::new(kind, input)]);
+                return Err(slf.prepare_validation_err(py, err));
+            }
+        };
+        let convert_err = |err: ValError| match custom_error {
+            Some(custom_error) => slf.prepare_validation_err(py, custom_error.convert_error(err)),
+            None => slf.prepare_validation_err(py, err),
+        };
+        if let Err(err) = list_validator.validate_array(&json_input, &Extra::new(strict, context, now, &slf.slots)) {
+            return Err(convert_err(err));
+        }
+        let items = match json_input {
+            JsonInput::Array(items) => items,
+            other => return Err(convert_err(ValError::new(ErrorKind::ListType, &other))),
+        };
+        Ok(JsonArrayIterator {
+            schema_validator: slf.into(),
+            items: items.into_iter(),
+            index: 0,
+            strict,
+            context: context.map(|context| context.into_py(py)),
+            now,
+        })
+    }
+
+    /// Validate JSON already parsed with `parse_json`, the same parsed JSON can be validated any number of times
+    /// (e.g. against different schemas) without being parsed again
+    pub fn validate_parsed_json(
+        &self,
+        py: Python,
+        input: PyRef<ParsedJson>,
+        strict: Option<bool>,
+        context: Option<&PyAny>,
+        now: Option<&PyAny>,
+    ) -> PyResult<PyObject> {
+        let r = self.validator.validate(
+            py,
+            &input.json_input,
+            &Extra::new(strict, context, now_from_py(now)?, &self.slots),
+            &self.slots,
+            &mut RecursionGuard::new(self.max_recursion_depth),
+        );
+        r.map_err(|e| self.prepare_validation_err(py, e))
+    }
+
     pub fn validate_assignment(&self, py: Python, field: String, input: &PyAny, data: &PyDict) -> PyResult<PyObject> {
         let extra = Extra {
             data: Some(data),
             field: Some(field.as_str()),
             strict: None,
             context: None,
+            now: None,
+            warnings: None,
+            slots: Some(&self.slots),
         };
-        let r = self
-            .validator
-            .validate(py, input, &extra, &self.slots, &mut RecursionGuard::default());
+        let r = self.validator.validate(
+            py,
+            input,
+            &extra,
+            &self.slots,
+            &mut RecursionGuard::new(self.max_recursion_depth),
+        );
         r.map_err(|e| self.prepare_validation_err(py, e))
     }
 
@@ -214,10 +357,12 @@ impl SchemaValidator {
             Err(err) => return Err(SchemaError::new_err(format!("Error building self-schema:\n  {}", err))),
         };
         Ok(Self {
-            validator,
-            slots: build_context.into_slots()?,
+            validator: Arc::new(validator),
+            slots: build_context.into_slots()?.into(),
             schema: py.None(),
             title: "Self Schema".into_py(py),
+            max_recursion_depth: None,
+            json_reject_duplicate_keys: false,
         })
     }
 
@@ -226,20 +371,119 @@ impl SchemaValidator {
     }
 }
 
-fn parse_json(input: &PyAny) -> PyResult<serde_json::Result<JsonInput>> {
-    if let Ok(py_bytes) = input.cast_as::<PyBytes>() {
-        Ok(serde_json::from_slice(py_bytes.as_bytes()))
-    } else if let Ok(py_str) = input.cast_as::<PyString>() {
-        let str = py_str.to_str()?;
-        Ok(serde_json::from_str(str))
-    } else if let Ok(py_byte_array) = input.cast_as::<PyByteArray>() {
-        Ok(serde_json::from_slice(unsafe { py_byte_array.as_bytes() }))
-    } else {
-        let input_type = input.get_type().name().unwrap_or("unknown");
-        py_error!(PyTypeError; "JSON input must be str, bytes or bytearray, not {}", input_type)
+/// Find the list validator used by `validate_json_iter`, looking through recursive references and custom errors,
+/// the outermost custom error is returned too as it replaces errors raised by the list validator itself
+fn json_iter_validator<'v>(
+    validator: &'v CombinedValidator,
+    slots: &'v [CombinedValidator],
+    custom_error: Option<&'v custom_error::CustomErrorValidator>,
+) -> Option<(&'v list::ListValidator, Option<&'v custom_error::CustomErrorValidator>)> {
+    match validator {
+        CombinedValidator::List(list_validator) => Some((list_validator, custom_error)),
+        CombinedValidator::CustomError(custom_error_validator) => json_iter_validator(
+            custom_error_validator.validator(),
+            slots,
+            custom_error.or(Some(custom_error_validator)),
+        ),
+        CombinedValidator::Recursive(container) => {
+            json_iter_validator(slots.get(container.validator_id())?, slots, custom_error)
+        }
+        CombinedValidator::RecursiveRef(recursive_ref) => {
+            json_iter_validator(slots.get(recursive_ref.validator_id())?, slots, custom_error)
+        }
+        _ => None,
     }
 }
 
+/// Returned by `SchemaValidator.validate_json_iter`, items are validated as the iterator is advanced, errors are
+/// raised for each invalid item and located by the item's index, iteration can continue after an error
+#[pyclass(module = "pydantic_core._pydantic_core")]
+pub struct JsonArrayIterator {
+    schema_validator: Py<SchemaValidator>,
+    items: std::vec::IntoIter<JsonInput>,
+    index: usize,
+    strict: Option<bool>,
+    context: Option<PyObject>,
+    now: Option<Duration>,
+}
+
+#[pymethods]
+impl JsonArrayIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Option<PyObject>> {
+        let item = match self.items.next() {
+            Some(item) => item,
+            None => return Ok(None),
+        };
+        let index = self.index;
+        self.index += 1;
+
+        let schema_validator = self.schema_validator.borrow(py);
+        let list_validator = match json_iter_validator(&schema_validator.validator, &schema_validator.slots, None) {
+            Some((list_validator, _)) => list_validator,
+            None => return py_error!(PyTypeError; "validate_json_iter can only be used with a list schema"),
+        };
+        let context = self.context.as_ref().map(|context| context.as_ref(py));
+        let r = list_validator.validate_item(
+            py,
+            index,
+            &item,
+            &Extra::new(self.strict, context, self.now, &schema_validator.slots),
+            &schema_validator.slots,
+            &mut RecursionGuard::new(schema_validator.max_recursion_depth),
+        );
+        r.map(Some).map_err(|e| schema_validator.prepare_validation_err(py, e))
+    }
+}
+
+/// JSON parsed by `parse_json`, validated with `SchemaValidator.validate_parsed_json`
+#[pyclass(module = "pydantic_core._pydantic_core")]
+#[derive(Debug)]
+pub struct ParsedJson {
+    json_input: JsonInput,
+}
+
+#[pymethods]
+impl ParsedJson {
+    /// the document could be huge, so only the top level type is shown, with its length if it has one
+    fn __repr__(&self) -> String {
+        let type_name = self.json_input.type_name();
+        match &self.json_input {
+            JsonInput::Array(array) => format!("ParsedJson({}, len={})", type_name, array.len()),
+            JsonInput::Object(object) => format!("ParsedJson({}, len={})", type_name, object.len()),
+            JsonInput::String(s) => format!("ParsedJson({}, len={})", type_name, s.chars().count()),
+            _ => format!("ParsedJson({})", type_name),
+        }
+    }
+}
+
+/// Parse JSON without validating it, parsing errors are raised as a `ValidationError`
+#[pyfunction(reject_duplicate_keys = "false")]
+#[pyo3(name = "parse_json")]
+pub fn py_parse_json(py: Python, input: &PyAny, reject_duplicate_keys: bool) -> PyResult<ParsedJson> {
+    match parse_json(input, reject_duplicate_keys)? {
+        Ok(json_input) => Ok(ParsedJson { json_input }),
+        Err(kind) => {
+            let err = ValError::LineErrors(vec![ValLineError::new(kind, input)]);
+            Err(ValidationError::from_val_error(py, "JSON".into_py(py), err))
+        }
+    }
+}
+
+fn parse_json(input: &PyAny, reject_duplicate_keys: bool) -> PyResult<Result<JsonInput, ErrorKind>> {
+    let json_data = match py_json_data(input)? {
+        Some(json_data) => json_data,
+        None => {
+            let input_type = input.get_type().name().unwrap_or("unknown");
+            return py_error!(PyTypeError; "JSON input must be str, bytes or bytearray, not {}", input_type);
+        }
+    };
+    Ok(parse_json_allow_threads(input.py(), &json_data, reject_duplicate_keys))
+}
+
 pub trait BuildValidator: Sized {
     const EXPECTED_TYPE: &'static str;
 
@@ -259,12 +503,14 @@ fn build_single_validator<'a, T: BuildValidator>(
     let val: CombinedValidator = if let Some(schema_ref) = schema_dict.get_as::<String>(intern!(py, "ref"))? {
         let slot_id = build_context.prepare_slot(schema_ref)?;
         let inner_val = T::build(schema_dict, config, build_context)
+            .and_then(|v| custom_error::CustomErrorValidator::wrap(v, schema_dict))
             .map_err(|err| SchemaError::new_err(format!("Error building \"{}\" validator:\n  {}", val_type, err)))?;
         let name = inner_val.get_name().to_string();
         build_context.complete_slot(slot_id, inner_val)?;
         recursive::RecursiveContainerValidator::create(slot_id, name)
     } else {
         T::build(schema_dict, config, build_context)
+            .and_then(|v| custom_error::CustomErrorValidator::wrap(v, schema_dict))
             .map_err(|err| SchemaError::new_err(format!("Error building \"{}\" validator:\n  {}", val_type, err)))?
     };
 
@@ -322,6 +568,8 @@ pub fn build_validator<'a>(
         bool::BoolValidator,
         // floats
         float::FloatValidator,
+        // decimals
+        decimal::DecimalValidator,
         // tuples
         tuple::TupleBuilder,
         // list/arrays
@@ -355,6 +603,16 @@ pub fn build_validator<'a>(
         // introspection types
         is_instance::IsInstanceValidator,
         callable::CallableValidator,
+        // default values
+        with_default::DefaultValidator,
+        // enums
+        enum_::EnumValidator,
+        // chained validators
+        chain::ChainValidator,
+        // json strings
+        json::JsonValidator,
+        // constants
+        constant::ConstantValidator,
     )
 }
 
@@ -367,20 +625,101 @@ pub struct Extra<'a> {
     pub data: Option<&'a PyDict>,
     /// The field being assigned to when validating assignment
     pub field: Option<&'a str>,
-    /// whether we're in strict or lax mode
+    /// whether we're in strict or lax mode, when set (e.g. via the `strict` argument to `validate_python`)
+    /// this takes precedence over the `strict` setting of each validator's schema
     pub strict: Option<bool>,
     /// context used in validator functions
     pub context: Option<&'a PyAny>,
+    /// the fixed time (as a duration since the unix epoch) passed as `now` to the top-level validation call,
+    /// when `None` time-relative constraints (e.g. `now_op`) read the system clock as they're checked
+    pub now: Option<Duration>,
+    /// set to collect warnings about how the input was validated, see `validate_json_with_warnings`
+    pub warnings: Option<&'a ValWarnings>,
+    /// the same slots as passed to `validate`, but shared so wrap validators can hold onto them cheaply
+    pub slots: Option<&'a Arc<[CombinedValidator]>>,
 }
 
 impl<'a> Extra<'a> {
-    pub fn new(strict: Option<bool>, context: Option<&'a PyAny>) -> Self {
+    pub fn new(
+        strict: Option<bool>,
+        context: Option<&'a PyAny>,
+        now: Option<Duration>,
+        slots: &'a Arc<[CombinedValidator]>,
+    ) -> Self {
         Extra {
             strict,
             context,
+            now,
+            slots: Some(slots),
             ..Default::default()
         }
     }
+
+    /// record a warning if the input was coerced in lax mode, `is_exact` checks whether the input
+    /// would have been valid in strict mode, it's only called if warnings are being collected
+    pub fn warn_if_coerced<'data>(
+        &self,
+        py: Python,
+        input: &'data impl Input<'data>,
+        strict: bool,
+        expected: &str,
+        is_exact: impl FnOnce() -> bool,
+    ) {
+        if let Some(warnings) = self.warnings {
+            if !strict && !is_exact() {
+                let input_type = input.as_error_value().type_name(py);
+                warnings.add(
+                    py,
+                    format!("Input of type {} coerced to {}", input_type, expected),
+                    input,
+                );
+            }
+        }
+    }
+
+    /// number of warnings recorded so far, used with `warnings_outer_location` and `truncate_warnings`
+    pub fn warnings_count(&self) -> usize {
+        self.warnings.map(|warnings| warnings.count()).unwrap_or(0)
+    }
+
+    /// add an outer location to warnings recorded since `warnings_count` returned `start`
+    pub fn warnings_outer_location(&self, start: usize, loc_item: impl Into<LocItem>) {
+        if let Some(warnings) = self.warnings {
+            warnings.with_outer_location(start, loc_item.into());
+        }
+    }
+
+    /// discard warnings recorded since `warnings_count` returned `start`
+    pub fn truncate_warnings(&self, start: usize) {
+        if let Some(warnings) = self.warnings {
+            warnings.truncate(start);
+        }
+    }
+}
+
+/// the current time as a duration since the unix epoch, used when `Extra::now` isn't set
+pub fn current_time() -> Duration {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default()
+}
+
+/// `now` may be passed to the top-level validation methods to fix the time used by time-relative constraints,
+/// mostly useful for testing, it can be a datetime or a unix timestamp, if unset the system clock is used
+fn now_from_py(now: Option<&PyAny>) -> PyResult<Option<Duration>> {
+    let now = match now {
+        Some(now) => now,
+        None => return Ok(None),
+    };
+    let timestamp: f64 = match now.cast_as::<PyDateTime>() {
+        Ok(dt) => dt.call_method0("timestamp")?.extract()?,
+        Err(_) => now.extract()?,
+    };
+    if !timestamp.is_finite() || timestamp >= u64::MAX as f64 {
+        py_error!(PyValueError; "'now' must be a finite timestamp")
+    } else if timestamp < 0.0 {
+        py_error!(PyValueError; "'now' must not be before 1970-01-01")
+    } else {
+        Ok(Some(Duration::from_secs_f64(timestamp)))
+    }
 }
 
 impl<'a> Extra<'a> {
@@ -390,6 +729,9 @@ impl<'a> Extra<'a> {
             field: self.field,
             strict: Some(true),
             context: self.context,
+            now: self.now,
+            warnings: self.warnings,
+            slots: self.slots,
         }
     }
 }
@@ -412,11 +754,15 @@ pub enum CombinedValidator {
     // integers
     Int(int::IntValidator),
     ConstrainedInt(int::ConstrainedIntValidator),
+    ConstrainedBigInt(int::ConstrainedBigIntValidator),
     // booleans
     Bool(bool::BoolValidator),
     // floats
     Float(float::FloatValidator),
     ConstrainedFloat(float::ConstrainedFloatValidator),
+    // decimals
+    Decimal(decimal::DecimalValidator),
+    ConstrainedDecimal(decimal::ConstrainedDecimalValidator),
     // lists
     List(list::ListValidator),
     // sets - unique lists
@@ -460,6 +806,18 @@ pub enum CombinedValidator {
     // introspection types
     IsInstance(is_instance::IsInstanceValidator),
     Callable(callable::CallableValidator),
+    // default values
+    WithDefault(with_default::DefaultValidator),
+    // enums
+    Enum(enum_::EnumValidator),
+    // chained validators
+    Chain(chain::ChainValidator),
+    // json strings
+    Json(json::JsonValidator),
+    // constants
+    Constant(constant::ConstantValidator),
+    // validators with a custom error, not built directly from a schema type, see `build_single_validator`
+    CustomError(custom_error::CustomErrorValidator),
 }
 
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
@@ -482,6 +840,8 @@ pub trait Validator: Send + Sync + Clone + Debug {
 
     /// this method must be implemented for any validator which holds references to other validators,
     /// it is used by `RecursiveRefValidator` to set its name
+    /// child validators are held in an `Arc`, `Arc::make_mut` gets a mutable reference to them and only copies
+    /// the child if it's shared
     fn complete(&mut self, _build_context: &BuildContext) -> PyResult<()> {
         Ok(())
     }
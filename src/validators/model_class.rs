@@ -13,6 +13,7 @@ use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 
+use super::function::convert_err;
 use super::typed_dict::TypedDictValidator;
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
@@ -22,6 +23,8 @@ pub struct ModelClassValidator {
     revalidate: bool,
     validator: TypedDictValidator,
     class: Py<PyType>,
+    /// name of a method called on the new instance with the validation context, e.g. for checks across fields
+    post_init: Option<String>,
     name: String,
 }
 
@@ -57,6 +60,7 @@ impl BuildValidator for ModelClassValidator {
             revalidate: config.get_as(intern!(py, "revalidate_models"))?.unwrap_or(false),
             validator,
             class: class.into(),
+            post_init: schema.get_as(intern!(py, "post_init"))?,
             // Get the class's `__name__`, not using `class.name()` since it uses `__qualname__`
             // which is not what we want here
             name: class.getattr(intern!(py, "__name__"))?.extract()?,
@@ -74,6 +78,11 @@ impl Validator for ModelClassValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
+        if extra.field.is_some() {
+            // we're validating assignment, the inner typed dict validates the single field and returns
+            // the updated model data
+            return self.validator.validate(py, input, extra, slots, recursion_guard);
+        }
         let class = self.class.as_ref(py);
         if input.is_type(class)? {
             if self.revalidate {
@@ -81,7 +90,8 @@ impl Validator for ModelClassValidator {
                 let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
                 let (model_dict, validation_fields_set): (&PyAny, &PyAny) = output.extract(py)?;
                 let fields_set = fields_set.unwrap_or(validation_fields_set);
-                Ok(self.create_class(py, model_dict, fields_set)?)
+                let instance = self.create_class(py, model_dict, fields_set)?;
+                self.call_post_init(py, instance, input, extra)
             } else {
                 Ok(input.to_object(py))
             }
@@ -95,7 +105,8 @@ impl Validator for ModelClassValidator {
         } else {
             let output = self.validator.validate(py, input, extra, slots, recursion_guard)?;
             let (model_dict, fields_set): (&PyAny, &PyAny) = output.extract(py)?;
-            Ok(self.create_class(py, model_dict, fields_set)?)
+            let instance = self.create_class(py, model_dict, fields_set)?;
+            self.call_post_init(py, instance, input, extra)
         }
     }
 
@@ -130,6 +141,22 @@ impl ModelClassValidator {
 
         Ok(instance)
     }
+
+    /// `ValueError` and `AssertionError` raised by `post_init` become validation errors for the whole model
+    fn call_post_init<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        instance: PyObject,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+    ) -> ValResult<'data, PyObject> {
+        if let Some(ref post_init) = self.post_init {
+            instance
+                .call_method1(py, post_init.as_str(), (extra.context,))
+                .map_err(|e| convert_err(py, e, input))?;
+        }
+        Ok(instance)
+    }
 }
 
 pub fn force_setattr<N, V>(py: Python<'_>, obj: &PyAny, attr_name: N, value: V) -> PyResult<()>
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
@@ -11,7 +13,7 @@ use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Ex
 
 #[derive(Debug, Clone)]
 pub struct NullableValidator {
-    validator: Box<CombinedValidator>,
+    validator: Arc<CombinedValidator>,
     name: String,
 }
 
@@ -24,7 +26,7 @@ impl BuildValidator for NullableValidator {
         build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
         let schema: &PyAny = schema.get_as_req(intern!(schema.py(), "schema"))?;
-        let validator = Box::new(build_validator(schema, config, build_context)?.0);
+        let validator = Arc::new(build_validator(schema, config, build_context)?.0);
         let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
         Ok(Self { validator, name }.into())
     }
@@ -50,6 +52,6 @@ impl Validator for NullableValidator {
     }
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
-        self.validator.complete(build_context)
+        Arc::make_mut(&mut self.validator).complete(build_context)
     }
 }
@@ -23,6 +23,10 @@ impl RecursiveContainerValidator {
         }
         .into()
     }
+
+    pub fn validator_id(&self) -> usize {
+        self.validator_id
+    }
 }
 
 impl Validator for RecursiveContainerValidator {
@@ -70,6 +74,12 @@ impl BuildValidator for RecursiveRefValidator {
     }
 }
 
+impl RecursiveRefValidator {
+    pub fn validator_id(&self) -> usize {
+        self.validator_id
+    }
+}
+
 impl Validator for RecursiveRefValidator {
     fn validate<'s, 'data>(
         &'s self,
@@ -94,15 +104,22 @@ impl Validator for RecursiveRefValidator {
     }
 }
 
-// see #143 this is a backup in case the identity check recursion guard fails
-// if a single validator "depth" (how many times it's called inside itself) exceeds the limit,
-// we raise a recursion error.
-const BACKUP_GUARD_LIMIT: u16 = if cfg!(PyPy) || cfg!(target_family = "wasm") {
+// see #143 as well as limiting how deeply nested input can be, this is a backup in case the identity check
+// recursion guard fails. If the total depth of recursive validators exceeds the limit we raise an error.
+// The limit can be changed with the `max_recursion_depth` config setting.
+const DEFAULT_MAX_DEPTH: u16 = if cfg!(PyPy) || cfg!(target_family = "wasm") {
     123
 } else {
     255
 };
 
+// `max_recursion_depth` can't be set above this, deeper recursion risks overflowing the stack
+pub(crate) const MAX_MAX_DEPTH: u16 = if cfg!(PyPy) || cfg!(target_family = "wasm") {
+    255
+} else {
+    1000
+};
+
 fn guard_validate<'s, 'data>(
     validator_id: usize,
     py: Python<'data>,
@@ -111,22 +128,24 @@ fn guard_validate<'s, 'data>(
     slots: &'data [CombinedValidator],
     recursion_guard: &'s mut RecursionGuard,
 ) -> ValResult<'data, PyObject> {
-    if let Some(id) = input.identity() {
+    let id = input.identity();
+    if let Some(id) = id {
         if recursion_guard.contains_or_insert(id) {
             // we don't remove id here, we leave that to the validator which originally added id to `recursion_guard`
-            Err(ValError::new(ErrorKind::RecursionLoop, input))
-        } else {
-            if recursion_guard.incr_depth() > BACKUP_GUARD_LIMIT {
-                return Err(ValError::new(ErrorKind::RecursionLoop, input));
-            }
-            let output = validate(validator_id, py, input, extra, slots, recursion_guard);
-            recursion_guard.remove(&id);
-            recursion_guard.decr_depth();
-            output
+            return Err(ValError::new(ErrorKind::RecursionLoop, input));
         }
+    }
+    let max_depth = recursion_guard.max_depth().unwrap_or(DEFAULT_MAX_DEPTH);
+    let output = if recursion_guard.incr_depth() > max_depth {
+        Err(ValError::new(ErrorKind::TooDeep { max_depth }, input))
     } else {
         validate(validator_id, py, input, extra, slots, recursion_guard)
+    };
+    recursion_guard.decr_depth();
+    if let Some(id) = id {
+        recursion_guard.remove(&id);
     }
+    output
 }
 
 fn validate<'s, 'data>(
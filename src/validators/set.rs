@@ -1,8 +1,11 @@
+use std::sync::Arc;
+
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet};
 
 use crate::build_tools::SchemaDict;
-use crate::errors::ValResult;
+use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
 use crate::input::{GenericListLike, Input};
 use crate::recursion_guard::RecursionGuard;
 
@@ -12,14 +15,15 @@ use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Ex
 #[derive(Debug, Clone)]
 pub struct SetValidator {
     strict: bool,
-    item_validator: Option<Box<CombinedValidator>>,
+    item_validator: Option<Arc<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
+    fail_fast: bool,
     name: String,
 }
 
 impl BuildValidator for SetValidator {
     const EXPECTED_TYPE: &'static str = "set";
-    generic_list_like_build!();
+    generic_list_like_build!("{}[{}]", Self::EXPECTED_TYPE, fail_fast);
 }
 
 impl Validator for SetValidator {
@@ -33,16 +37,19 @@ impl Validator for SetValidator {
     ) -> ValResult<'data, PyObject> {
         let seq = input.validate_set(extra.strict.unwrap_or(self.strict))?;
 
-        let length = seq.check_len(self.size_range, input)?;
+        seq.check_len(self.size_range, input)?;
 
         let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, length, v, extra, slots, recursion_guard)?,
+            Some(ref v) => seq.validate_to_vec(py, v, self.fail_fast, extra, slots, recursion_guard)?,
             None => match seq {
                 GenericListLike::Set(set) => return Ok(set.into_py(py)),
                 _ => seq.to_vec(py),
             },
         };
-        Ok(PySet::new(py, &output)?.into_py(py))
+        match PySet::new(py, &output) {
+            Ok(set) => Ok(set.into_py(py)),
+            Err(err) => Err(unhashable_items_error(py, err, output)),
+        }
     }
 
     fn get_name(&self) -> &str {
@@ -51,8 +58,31 @@ impl Validator for SetValidator {
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         match self.item_validator {
-            Some(ref mut v) => v.complete(build_context),
+            Some(ref mut v) => Arc::make_mut(v).complete(build_context),
             None => Ok(()),
         }
     }
 }
+
+/// Creating a set (or frozenset) fails with a `TypeError` if an item is unhashable, e.g. if the item validator
+/// returns a list, this converts that into a `set_item_not_hashable` error for each unhashable item
+pub fn unhashable_items_error<'data>(py: Python<'data>, err: PyErr, items: Vec<PyObject>) -> ValError<'data> {
+    if !err.is_instance_of::<PyTypeError>(py) {
+        return err.into();
+    }
+    let mut errors: Vec<ValLineError> = Vec::new();
+    for (index, item) in items.into_iter().enumerate() {
+        let item = item.into_ref(py);
+        match item.hash() {
+            Ok(_) => (),
+            Err(err) if err.is_instance_of::<PyTypeError>(py) => {
+                errors.push(ValLineError::new_with_loc(ErrorKind::SetItemNotHashable, item, index));
+            }
+            Err(err) => return err.into(),
+        }
+    }
+    match errors.is_empty() {
+        true => err.into(),
+        false => ValError::LineErrors(errors),
+    }
+}
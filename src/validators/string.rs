@@ -1,11 +1,13 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString};
 use regex::Regex;
 
-use crate::build_tools::{is_strict, py_error, schema_or_config};
+use ahash::AHashSet;
+
+use crate::build_tools::{is_strict, py_error, schema_or_config, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherString, Input};
 use crate::recursion_guard::RecursionGuard;
 
 use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
@@ -13,6 +15,8 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct StrValidator {
     strict: bool,
+    coerce_numbers_to_str: bool,
+    encoding: Option<String>,
 }
 
 impl BuildValidator for StrValidator {
@@ -30,6 +34,7 @@ impl BuildValidator for StrValidator {
             || schema.get_item(intern!(py, "strip_whitespace")).is_some()
             || schema.get_item(intern!(py, "to_lower")).is_some()
             || schema.get_item(intern!(py, "to_upper")).is_some()
+            || schema.get_item(intern!(py, "allowed")).is_some()
             || match config {
                 Some(config) => {
                     config.get_item(intern!(py, "str_pattern")).is_some()
@@ -46,6 +51,8 @@ impl BuildValidator for StrValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                coerce_numbers_to_str: coerce_numbers_to_str(schema, config)?,
+                encoding: build_encoding(schema)?,
             }
             .into())
         }
@@ -61,7 +68,10 @@ impl Validator for StrValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        Ok(input.validate_str(extra.strict.unwrap_or(self.strict))?.into_py(py))
+        let strict = extra.strict.unwrap_or(self.strict);
+        let either_str = validate_str(input, strict, self.coerce_numbers_to_str, self.encoding.as_deref())?;
+        extra.warn_if_coerced(py, input, strict, "str", || input.strict_str().is_ok());
+        Ok(either_str.into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -72,12 +82,19 @@ impl Validator for StrValidator {
 #[derive(Debug, Clone)]
 pub struct StrConstrainedValidator {
     strict: bool,
+    coerce_numbers_to_str: bool,
+    encoding: Option<String>,
+    /// Note: the pattern is not anchored, `is_match` behaves like python's `re.search` rather than `re.match`,
+    /// so patterns which need to match the whole string should use `^` and `$`
     pattern: Option<Regex>,
     max_length: Option<usize>,
     min_length: Option<usize>,
     strip_whitespace: bool,
     to_lower: bool,
     to_upper: bool,
+    /// checked after transformations so e.g. `to_lower` can be used for case-insensitive membership
+    allowed: Option<AHashSet<String>>,
+    allowed_repr: String,
 }
 
 impl Validator for StrConstrainedValidator {
@@ -89,18 +106,55 @@ impl Validator for StrConstrainedValidator {
         _slots: &'data [CombinedValidator],
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        let either_str = input.validate_str(extra.strict.unwrap_or(self.strict))?;
+        let strict = extra.strict.unwrap_or(self.strict);
+        let either_str = validate_str(input, strict, self.coerce_numbers_to_str, self.encoding.as_deref())?;
+        extra.warn_if_coerced(py, input, strict, "str", || input.strict_str().is_ok());
         let cow = either_str.as_cow();
         let mut str = cow.as_ref();
-        if let Some(min_length) = self.min_length {
-            if str.len() < min_length {
-                // return py_error!("{} is shorter than {}", str, min_length);
-                return Err(ValError::new(ErrorKind::StrTooShort { min_length }, input));
-            }
+
+        // transformations are applied first, in the order strip -> case-fold, so the constraints below are
+        // checked against the string which will actually be returned
+        if self.strip_whitespace {
+            str = str.trim();
         }
-        if let Some(max_length) = self.max_length {
-            if str.len() > max_length {
-                return Err(ValError::new(ErrorKind::StrTooLong { max_length }, input));
+        // `to_lower` and `to_upper` can't both be set, see `build`
+        let case_folded = if self.to_lower {
+            Some(str.to_lowercase())
+        } else if self.to_upper {
+            Some(str.to_uppercase())
+        } else {
+            None
+        }
+        // a string which was already in the right case is dropped, so the original can be returned below
+        .filter(|case_folded| case_folded != str);
+        if let Some(ref case_folded) = case_folded {
+            str = case_folded.as_str();
+        }
+
+        // length is measured in chars (unicode scalar values) rather than bytes to match python's `len(str)`
+        if self.min_length.is_some() || self.max_length.is_some() {
+            let input_length = str.chars().count();
+            if let Some(min_length) = self.min_length {
+                if input_length < min_length {
+                    return Err(ValError::new(
+                        ErrorKind::StrTooShort {
+                            min_length,
+                            input_length,
+                        },
+                        input,
+                    ));
+                }
+            }
+            if let Some(max_length) = self.max_length {
+                if input_length > max_length {
+                    return Err(ValError::new(
+                        ErrorKind::StrTooLong {
+                            max_length,
+                            input_length,
+                        },
+                        input,
+                    ));
+                }
             }
         }
         if let Some(pattern) = &self.pattern {
@@ -113,19 +167,22 @@ impl Validator for StrConstrainedValidator {
                 ));
             }
         }
-
-        if self.strip_whitespace {
-            str = str.trim();
+        if let Some(ref allowed) = self.allowed {
+            if !allowed.contains(str) {
+                return Err(ValError::new(
+                    ErrorKind::StrNotInAllowed {
+                        allowed: self.allowed_repr.clone(),
+                    },
+                    input,
+                ));
+            }
         }
 
-        let py_string = if self.to_lower {
-            PyString::new(py, &str.to_lowercase())
-        } else if self.to_upper {
-            PyString::new(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
+        // stripping only ever removes characters, so an unchanged length means nothing was stripped
+        let py_string = if case_folded.is_some() || str.len() != cow.len() {
             PyString::new(py, str)
         } else {
-            // we haven't modified the string, return the original as it might be a PyString
+            // we haven't modified the string, return the original as it might be a PyString, avoiding a copy
             either_str.as_py_string(py)
         };
         Ok(py_string.into_py(py))
@@ -161,20 +218,96 @@ impl StrConstrainedValidator {
             schema_or_config(schema, config, intern!(py, "to_lower"), intern!(py, "str_to_lower"))?.unwrap_or(false);
         let to_upper: bool =
             schema_or_config(schema, config, intern!(py, "to_upper"), intern!(py, "str_to_upper"))?.unwrap_or(false);
+        if to_lower && to_upper {
+            return py_error!("'to_lower' and 'to_upper' cannot both be set");
+        }
+
+        let (allowed, allowed_repr) = match schema.get_as::<&PyList>(intern!(py, "allowed"))? {
+            Some(list) => {
+                let allowed_vec: Vec<String> = list.extract()?;
+                if allowed_vec.is_empty() {
+                    return py_error!("'allowed' must contain at least one string");
+                }
+                let repr_args: Vec<String> = allowed_vec.iter().map(|s| format!("'{}'", s)).collect();
+                (Some(allowed_vec.into_iter().collect()), repr_args.join(", "))
+            }
+            None => (None, String::new()),
+        };
 
         Ok(Self {
             strict: is_strict(schema, config)?,
+            coerce_numbers_to_str: coerce_numbers_to_str(schema, config)?,
+            encoding: build_encoding(schema)?,
             pattern,
             min_length,
             max_length,
             strip_whitespace,
             to_lower,
             to_upper,
+            allowed,
+            allowed_repr,
         }
         .into())
     }
 }
 
+fn coerce_numbers_to_str(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
+    let py = schema.py();
+    Ok(schema_or_config(
+        schema,
+        config,
+        intern!(py, "coerce_numbers_to_str"),
+        intern!(py, "str_coerce_numbers_to_str"),
+    )?
+    .unwrap_or(true))
+}
+
+/// In lax mode ints and floats are converted to strings unless `coerce_numbers_to_str` is false, in which case
+/// they're rejected with `StrType` just as they would be in strict mode.
+/// If set, `encoding` is used to decode bytes in lax mode.
+fn validate_str<'data>(
+    input: &'data impl Input<'data>,
+    strict: bool,
+    coerce_numbers_to_str: bool,
+    encoding: Option<&str>,
+) -> ValResult<'data, EitherString<'data>> {
+    // most input is already a string, this avoids the checks for bytes and numbers below
+    if let Some(either_str) = input.exact_str() {
+        return Ok(either_str);
+    }
+    if let (false, Some(encoding)) = (strict, encoding) {
+        if let Some(result) = input.decode_str(encoding) {
+            return result;
+        }
+    }
+    if !strict && !coerce_numbers_to_str && (input.is_exact_int() || input.is_exact_float()) {
+        return Err(ValError::new(ErrorKind::StrType, input));
+    }
+    input.validate_str(strict)
+}
+
+/// Unknown encodings and codecs which don't decode to `str` (e.g. "base64") are a schema error,
+/// utf-8 (under any alias) is decoded in rust, so `None` is returned
+fn build_encoding(schema: &PyDict) -> PyResult<Option<String>> {
+    let py = schema.py();
+    let encoding: &str = match schema.get_as(intern!(py, "encoding"))? {
+        Some(encoding) => encoding,
+        None => return Ok(None),
+    };
+    let codec_info = match py.import("codecs")?.call_method1("lookup", (encoding,)) {
+        Ok(codec_info) => codec_info,
+        Err(_) => return py_error!("Unknown encoding: '{}'", encoding),
+    };
+    if !codec_info.getattr(intern!(py, "_is_text_encoding"))?.is_true()? {
+        return py_error!("Encoding '{}' is not a text encoding", encoding);
+    }
+    let codec_name: String = codec_info.getattr(intern!(py, "name"))?.extract()?;
+    match codec_name.as_str() {
+        "utf-8" => Ok(None),
+        _ => Ok(Some(codec_name)),
+    }
+}
+
 fn build_regex(pattern: &str) -> PyResult<Regex> {
     match Regex::new(pattern) {
         Ok(r) => Ok(r),
@@ -3,7 +3,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString, PyTime};
 use speedate::Time;
 
-use crate::build_tools::{is_strict, SchemaDict};
+use crate::build_tools::{is_strict, py_error, SchemaDict};
 use crate::errors::{ErrorKind, ValError, ValResult};
 use crate::input::{EitherTime, Input};
 use crate::recursion_guard::RecursionGuard;
@@ -13,9 +13,17 @@ use super::{BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 #[derive(Debug, Clone)]
 pub struct TimeValidator {
     strict: bool,
+    tz_constraint: Option<TzConstraint>,
     constraints: Option<TimeConstraints>,
 }
 
+/// from `require_tz` and `forbid_tz` in the schema
+#[derive(Debug, Clone, Copy)]
+enum TzConstraint {
+    Required,
+    Forbidden,
+}
+
 #[derive(Debug, Clone)]
 struct TimeConstraints {
     le: Option<Time>,
@@ -38,8 +46,19 @@ impl BuildValidator for TimeValidator {
             || schema.get_item(intern!(py, "ge")).is_some()
             || schema.get_item(intern!(py, "gt")).is_some();
 
+        let tz_constraint = match (
+            schema.get_as(intern!(py, "require_tz"))?.unwrap_or(false),
+            schema.get_as(intern!(py, "forbid_tz"))?.unwrap_or(false),
+        ) {
+            (false, false) => None,
+            (true, false) => Some(TzConstraint::Required),
+            (false, true) => Some(TzConstraint::Forbidden),
+            (true, true) => return py_error!("'require_tz' and 'forbid_tz' cannot be used together"),
+        };
+
         Ok(Self {
             strict: is_strict(schema, config)?,
+            tz_constraint,
             constraints: match has_constraints {
                 true => Some(TimeConstraints {
                     le: convert_pytime(schema, intern!(py, "le"))?,
@@ -64,6 +83,13 @@ impl Validator for TimeValidator {
         _recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
         let time = input.validate_time(extra.strict.unwrap_or(self.strict))?;
+        if let Some(tz_constraint) = self.tz_constraint {
+            match (tz_constraint, time.offset()?) {
+                (TzConstraint::Required, None) => return Err(ValError::new(ErrorKind::TimeTzRequired, input)),
+                (TzConstraint::Forbidden, Some(_)) => return Err(ValError::new(ErrorKind::TimeTzForbidden, input)),
+                _ => (),
+            }
+        }
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
 
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
@@ -31,7 +33,7 @@ impl BuildValidator for TupleBuilder {
 #[derive(Debug, Clone)]
 pub struct TupleVariableValidator {
     strict: bool,
-    item_validator: Option<Box<CombinedValidator>>,
+    item_validator: Option<Arc<CombinedValidator>>,
     size_range: Option<(Option<usize>, Option<usize>)>,
     name: String,
 }
@@ -51,10 +53,10 @@ impl Validator for TupleVariableValidator {
     ) -> ValResult<'data, PyObject> {
         let seq = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
 
-        let length = seq.check_len(self.size_range, input)?;
+        seq.check_len(self.size_range, input)?;
 
         let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, length, v, extra, slots, recursion_guard)?,
+            Some(ref v) => seq.validate_to_vec(py, v, false, extra, slots, recursion_guard)?,
             None => match seq {
                 GenericListLike::Tuple(tuple) => return Ok(tuple.into_py(py)),
                 _ => seq.to_vec(py),
@@ -69,7 +71,7 @@ impl Validator for TupleVariableValidator {
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
         match self.item_validator {
-            Some(ref mut v) => v.complete(build_context),
+            Some(ref mut v) => Arc::make_mut(v).complete(build_context),
             None => Ok(()),
         }
     }
@@ -79,7 +81,7 @@ impl Validator for TupleVariableValidator {
 pub struct TuplePositionalValidator {
     strict: bool,
     items_validators: Vec<CombinedValidator>,
-    extra_validator: Option<Box<CombinedValidator>>,
+    extra_validator: Option<Arc<CombinedValidator>>,
     name: String,
 }
 
@@ -104,7 +106,7 @@ impl TuplePositionalValidator {
             strict: is_strict(schema, config)?,
             items_validators: validators,
             extra_validator: match schema.get_item(intern!(py, "extra_schema")) {
-                Some(v) => Some(Box::new(build_validator(v, config, build_context)?.0)),
+                Some(v) => Some(Arc::new(build_validator(v, config, build_context)?.0)),
                 None => None,
             },
             name: format!("tuple[{}]", descr),
@@ -124,14 +126,32 @@ impl Validator for TuplePositionalValidator {
     ) -> ValResult<'data, PyObject> {
         let seq = input.validate_tuple(extra.strict.unwrap_or(self.strict))?;
         let expected_length = self.items_validators.len();
-
-        if seq.generic_len() < expected_length {
-            return Err(ValError::new(
-                ErrorKind::TooShort {
-                    min_length: expected_length,
-                },
-                input,
-            ));
+        let input_length = seq.generic_len();
+
+        match self.extra_validator {
+            // extra items are validated by the extra validator, so only the minimum length is checked
+            Some(_) => {
+                if input_length < expected_length {
+                    return Err(ValError::new(
+                        ErrorKind::TooShort {
+                            min_length: expected_length,
+                            input_length,
+                        },
+                        input,
+                    ));
+                }
+            }
+            None => {
+                if input_length != expected_length {
+                    return Err(ValError::new(
+                        ErrorKind::TupleLengthMismatch {
+                            expected_length,
+                            input_length,
+                        },
+                        input,
+                    ));
+                }
+            }
         }
         let mut output: Vec<PyObject> = Vec::with_capacity(expected_length);
         let mut errors: Vec<ValLineError> = Vec::new();
@@ -142,10 +162,12 @@ impl Validator for TuplePositionalValidator {
                         Some(ref v) => v,
                         None => match self.extra_validator {
                             Some(ref v) => v.as_ref(),
+                            // the input can grow while it's validated, e.g. if an item validator appends to it
                             None => {
                                 return Err(ValError::new(
-                                    ErrorKind::TooLong {
-                                        max_length: expected_length,
+                                    ErrorKind::TupleLengthMismatch {
+                                        expected_length,
+                                        input_length: $list_like.len(),
                                     },
                                     input,
                                 ));
@@ -153,7 +175,10 @@ impl Validator for TuplePositionalValidator {
                         },
                     };
 
-                    match validator.validate(py, item, extra, slots, recursion_guard) {
+                    let warnings_start = extra.warnings_count();
+                    let result = validator.validate(py, item, extra, slots, recursion_guard);
+                    extra.warnings_outer_location(warnings_start, index);
+                    match result {
                         Ok(item) => output.push(item),
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(
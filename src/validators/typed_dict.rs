@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFunction, PyList, PySet, PyString};
 use pyo3::{intern, PyTypeInfo};
@@ -11,6 +13,8 @@ use crate::lookup_key::LookupKey;
 use crate::recursion_guard::RecursionGuard;
 use crate::SchemaError;
 
+use super::custom_error::CustomErrorValidator;
+use super::with_default::{call_default_factory, DefaultValidator};
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
 #[derive(Debug, Clone)]
@@ -21,17 +25,44 @@ struct TypedDictField {
     required: bool,
     default: Option<PyObject>,
     default_factory: Option<PyObject>,
+    default_factory_takes_data: bool,
+    frozen: bool,
+    strict: Option<bool>,
     validator: CombinedValidator,
 }
 
+impl TypedDictField {
+    /// a field's own `strict` setting overrides the typed dict's, but not `strict` passed to the validation call
+    fn extra<'a>(&self, extra: &Extra<'a>) -> Extra<'a> {
+        Extra {
+            strict: extra.strict.or(self.strict),
+            ..*extra
+        }
+    }
+}
+
+/// the field's `default` validator if it has one, seeing through a custom error wrapper which then
+/// applies to errors from validating the default too
+fn default_validator(validator: &CombinedValidator) -> Option<(&DefaultValidator, Option<&CustomErrorValidator>)> {
+    match validator {
+        CombinedValidator::WithDefault(default_validator) => Some((default_validator, None)),
+        CombinedValidator::CustomError(custom_error) => match custom_error.validator() {
+            CombinedValidator::WithDefault(default_validator) => Some((default_validator, Some(custom_error))),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TypedDictValidator {
     fields: Vec<TypedDictField>,
     check_extra: bool,
     forbid_extra: bool,
-    extra_validator: Option<Box<CombinedValidator>>,
+    extra_validator: Option<Arc<CombinedValidator>>,
     strict: bool,
     from_attributes: bool,
+    populate_by_name: bool,
     return_fields_set: bool,
 }
 
@@ -84,7 +115,7 @@ impl BuildValidator for TypedDictValidator {
         let extra_validator = match schema.get_item(intern!(py, "extra_validator")) {
             Some(v) => {
                 if check_extra && !forbid_extra {
-                    Some(Box::new(build_validator(v, config, build_context)?.0))
+                    Some(Arc::new(build_validator(v, config, build_context)?.0))
                 } else {
                     return py_error!("extra_validator can only be used if extra_behavior=allow");
                 }
@@ -138,6 +169,11 @@ impl BuildValidator for TypedDictValidator {
                 },
                 default,
                 default_factory,
+                default_factory_takes_data: field_info
+                    .get_as(intern!(py, "default_factory_takes_data"))?
+                    .unwrap_or(false),
+                frozen: field_info.get_as(intern!(py, "frozen"))?.unwrap_or(false),
+                strict: field_info.get_as(intern!(py, "strict"))?,
             });
         }
         Ok(Self {
@@ -147,6 +183,7 @@ impl BuildValidator for TypedDictValidator {
             extra_validator,
             strict,
             from_attributes,
+            populate_by_name,
             return_fields_set,
         }
         .into())
@@ -188,11 +225,15 @@ impl Validator for TypedDictValidator {
             field: None,
             strict: extra.strict,
             context: extra.context,
+            now: extra.now,
+            warnings: extra.warnings,
+            slots: extra.slots,
         };
 
         macro_rules! process {
             ($dict:ident, $get_method:ident, $iter:block) => {{
                 for field in &self.fields {
+                    let field_extra = field.extra(&extra);
                     let op_key_value = match field.lookup_key.$get_method($dict) {
                         Ok(v) => v,
                         Err(err) => {
@@ -211,11 +252,18 @@ impl Validator for TypedDictValidator {
                             // key is "used" whether or not validation passes, since we want to skip this key in
                             // extra logic either way
                             used_keys.insert(used_key);
+                            // when the alias takes precedence, the field name is ignored rather than being
+                            // treated as an extra key
+                            if self.populate_by_name {
+                                used_keys.insert(&field.name);
+                            }
                         }
-                        match field
+                        let warnings_start = extra.warnings_count();
+                        let result = field
                             .validator
-                            .validate(py, value, &extra, slots, recursion_guard)
-                        {
+                            .validate(py, value, &field_extra, slots, recursion_guard);
+                        extra.warnings_outer_location(warnings_start, field.name.as_str());
+                        match result {
                             Ok(value) => {
                                 output_dict.set_item(&field.name_pystring, value)?;
                                 if let Some(ref mut fs) = fields_set_vec {
@@ -232,7 +280,25 @@ impl Validator for TypedDictValidator {
                     } else if let Some(ref default) = field.default {
                         output_dict.set_item(&field.name_pystring, default)?;
                     } else if let Some(ref default_factory) = field.default_factory {
-                        output_dict.set_item(&field.name_pystring, default_factory.call0(py)?)?;
+                        let takes_data = field.default_factory_takes_data;
+                        let value = call_default_factory(py, default_factory, takes_data, Some(output_dict))?;
+                        output_dict.set_item(&field.name_pystring, value)?;
+                    } else if let Some((default_validator, custom_error)) = default_validator(&field.validator) {
+                        let default = default_validator
+                            .default_value(py, &field_extra, slots, recursion_guard)
+                            .map_err(|err| match custom_error {
+                                Some(custom_error) => custom_error.convert_error(err),
+                                None => err,
+                            });
+                        match default {
+                            Ok(value) => output_dict.set_item(&field.name_pystring, value)?,
+                            Err(ValError::LineErrors(line_errors)) => {
+                                for err in line_errors {
+                                    errors.push(err.with_outer_location(field.name.clone().into()));
+                                }
+                            }
+                            Err(err) => return Err(err),
+                        }
                     } else if !field.required {
                         continue;
                     } else {
@@ -282,7 +348,10 @@ impl Validator for TypedDictValidator {
                         }
 
                         if let Some(ref validator) = self.extra_validator {
-                            match validator.validate(py, value, &extra, slots, recursion_guard) {
+                            let warnings_start = extra.warnings_count();
+                            let result = validator.validate(py, value, &extra, slots, recursion_guard);
+                            extra.warnings_outer_location(warnings_start, raw_key.as_loc_item());
+                            match result {
                                 Ok(value) => {
                                     output_dict.set_item(py_key, value)?;
                                     if let Some(ref mut fs) = fields_set_vec {
@@ -346,11 +415,12 @@ impl TypedDictValidator {
     where
         'data: 's,
     {
-        // TODO probably we should set location on errors here
         let data = match extra.data {
             Some(data) => data,
             None => unreachable!(),
         };
+        // the field's own validator mustn't think it's validating assignment, e.g. if it's a nested typed dict
+        let extra = &Extra { field: None, ..*extra };
 
         let prepare_tuple = |output: PyObject| {
             data.set_item(field, output)?;
@@ -375,7 +445,18 @@ impl TypedDictValidator {
         };
 
         if let Some(field) = self.fields.iter().find(|f| f.name == field) {
-            prepare_result(field.validator.validate(py, input, extra, slots, recursion_guard))
+            if field.frozen {
+                return Err(ValError::new_with_loc(
+                    ErrorKind::FrozenField,
+                    input,
+                    field.name.to_string(),
+                ));
+            }
+            prepare_result(
+                field
+                    .validator
+                    .validate(py, input, &field.extra(extra), slots, recursion_guard),
+            )
         } else if self.check_extra && !self.forbid_extra {
             // this is the "allow" case of extra_behavior
             match self.extra_validator {
@@ -7,18 +7,31 @@ use pyo3::types::{PyDict, PyList, PyString};
 
 use ahash::AHashMap;
 
-use crate::build_tools::{is_strict, schema_or_config, SchemaDict};
-use crate::errors::{ErrorKind, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, py_error, schema_or_config, SchemaDict};
+use crate::errors::{ErrorKind, LocItem, ValError, ValLineError, ValResult};
 use crate::input::{GenericMapping, Input};
 use crate::lookup_key::LookupKey;
 use crate::recursion_guard::RecursionGuard;
 
 use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
 
+#[derive(Debug, Clone, PartialEq)]
+enum UnionMode {
+    /// try each choice in strict mode first to find an exact type match, then try again in lax mode,
+    /// errors are located by the name of the choice's validator
+    Smart,
+    /// return the first choice which validates in the current mode, errors are located by the choice's index
+    LeftToRight,
+}
+
 #[derive(Debug, Clone)]
 pub struct UnionValidator {
     choices: Vec<CombinedValidator>,
+    // location item for each choice's errors, built once rather than for every failed validation and shared
+    // by all of the choice's errors, see `LocItem::S`
+    choice_locs: Vec<LocItem>,
     strict: bool,
+    mode: UnionMode,
     name: String,
 }
 
@@ -30,25 +43,36 @@ impl BuildValidator for UnionValidator {
         config: Option<&PyDict>,
         build_context: &mut BuildContext,
     ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
         let choices: Vec<CombinedValidator> = schema
-            .get_as_req::<&PyList>(intern!(schema.py(), "choices"))?
+            .get_as_req::<&PyList>(intern!(py, "choices"))?
             .iter()
             .map(|choice| build_validator(choice, config, build_context).map(|result| result.0))
             .collect::<PyResult<Vec<CombinedValidator>>>()?;
 
+        let mode = match schema.get_as::<&str>(intern!(py, "mode"))? {
+            Some("smart") | None => UnionMode::Smart,
+            Some("left_to_right") => UnionMode::LeftToRight,
+            Some(s) => return py_error!(r#"Invalid union mode: "{}""#, s),
+        };
+
         let descr = choices.iter().map(|v| v.get_name()).collect::<Vec<_>>().join(",");
+        let choice_locs = choice_locs(&choices, &mode);
 
         Ok(Self {
             choices,
+            choice_locs,
             strict: is_strict(schema, config)?,
+            mode,
             name: format!("{}[{}]", Self::EXPECTED_TYPE, descr),
         }
         .into())
     }
 }
 
-impl Validator for UnionValidator {
-    fn validate<'s, 'data>(
+impl UnionValidator {
+    /// try each choice in turn with `extra`, returning the first success or all errors if no choice succeeds
+    fn validate_choices<'s, 'data>(
         &'s self,
         py: Python<'data>,
         input: &'data impl Input<'data>,
@@ -56,24 +80,41 @@ impl Validator for UnionValidator {
         slots: &'data [CombinedValidator],
         recursion_guard: &'s mut RecursionGuard,
     ) -> ValResult<'data, PyObject> {
-        if extra.strict.unwrap_or(self.strict) {
-            let mut errors: Vec<ValLineError> = Vec::with_capacity(self.choices.len());
-            let strict_strict = extra.as_strict();
+        let mut errors: Vec<ValLineError> = Vec::with_capacity(self.choices.len());
 
-            for validator in &self.choices {
-                let line_errors = match validator.validate(py, input, &strict_strict, slots, recursion_guard) {
-                    Err(ValError::LineErrors(line_errors)) => line_errors,
-                    otherwise => return otherwise,
-                };
+        for (validator, loc_item) in self.choices.iter().zip(self.choice_locs.iter()) {
+            let warnings_start = extra.warnings_count();
+            let line_errors = match validator.validate(py, input, extra, slots, recursion_guard) {
+                Err(ValError::LineErrors(line_errors)) => line_errors,
+                otherwise => return otherwise,
+            };
+            // warnings from a choice which failed don't relate to the output
+            extra.truncate_warnings(warnings_start);
+
+            errors.extend(
+                line_errors
+                    .into_iter()
+                    .map(|err| err.with_outer_location(loc_item.clone())),
+            );
+        }
 
-                errors.extend(
-                    line_errors
-                        .into_iter()
-                        .map(|err| err.with_outer_location(validator.get_name().into())),
-                );
-            }
+        Err(ValError::LineErrors(errors))
+    }
+}
 
-            Err(ValError::LineErrors(errors))
+impl Validator for UnionValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if extra.strict.unwrap_or(self.strict) {
+            self.validate_choices(py, input, &extra.as_strict(), slots, recursion_guard)
+        } else if self.mode == UnionMode::LeftToRight {
+            self.validate_choices(py, input, extra, slots, recursion_guard)
         } else {
             // 1st pass: check if the value is an exact instance of one of the Union types,
             // e.g. use validate in strict mode
@@ -87,23 +128,8 @@ impl Validator for UnionValidator {
                 return res;
             }
 
-            let mut errors: Vec<ValLineError> = Vec::with_capacity(self.choices.len());
-
             // 2nd pass: check if the value can be coerced into one of the Union types, e.g. use validate
-            for validator in &self.choices {
-                let line_errors = match validator.validate(py, input, extra, slots, recursion_guard) {
-                    Err(ValError::LineErrors(line_errors)) => line_errors,
-                    success => return success,
-                };
-
-                errors.extend(
-                    line_errors
-                        .into_iter()
-                        .map(|err| err.with_outer_location(validator.get_name().into())),
-                );
-            }
-
-            Err(ValError::LineErrors(errors))
+            self.validate_choices(py, input, extra, slots, recursion_guard)
         }
     }
 
@@ -112,10 +138,24 @@ impl Validator for UnionValidator {
     }
 
     fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
-        self.choices.iter_mut().try_for_each(|v| v.complete(build_context))
+        self.choices.iter_mut().try_for_each(|v| v.complete(build_context))?;
+        // names of recursive validators aren't known until they're completed
+        self.choice_locs = choice_locs(&self.choices, &self.mode);
+        Ok(())
     }
 }
 
+fn choice_locs(choices: &[CombinedValidator], mode: &UnionMode) -> Vec<LocItem> {
+    choices
+        .iter()
+        .enumerate()
+        .map(|(index, validator)| match mode {
+            UnionMode::Smart => validator.get_name().into(),
+            UnionMode::LeftToRight => index.into(),
+        })
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 enum Discriminator {
     /// use `LookupKey` to find the tag, same as we do to find values in typed_dict aliases
@@ -220,13 +260,14 @@ impl Validator for TaggedUnionValidator {
     ) -> ValResult<'data, PyObject> {
         match self.discriminator {
             Discriminator::LookupKey(ref lookup_key) => {
+                let strict = extra.strict.unwrap_or(self.strict);
                 macro_rules! find_validator {
                     ($dict:ident, $get_method:ident) => {{
                         // note all these methods return PyResult<Option<(data, data)>>, the outer Err is just for
                         // errors when getting attributes which should be "raised"
                         match lookup_key.$get_method($dict)? {
                             Some((_, value)) => {
-                                if self.strict {
+                                if strict {
                                     value.strict_str()
                                 } else {
                                     value.lax_str()
@@ -236,7 +277,7 @@ impl Validator for TaggedUnionValidator {
                         }
                     }};
                 }
-                let dict = input.validate_typed_dict(self.strict, self.from_attributes)?;
+                let dict = input.validate_typed_dict(strict, self.from_attributes)?;
                 let tag = match dict {
                     GenericMapping::PyDict(dict) => find_validator!(dict, py_get_item),
                     GenericMapping::PyGetAttr(obj) => find_validator!(obj, py_get_attr),
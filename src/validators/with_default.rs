@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyDict};
+
+use crate::build_tools::{py_error, SchemaDict};
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::recursion_guard::RecursionGuard;
+
+use super::{build_validator, BuildContext, BuildValidator, CombinedValidator, Extra, Validator};
+
+#[derive(Debug, Clone)]
+enum DefaultType {
+    Default(PyObject),
+    // the bool is whether the factory takes the data validated so far as a `data` keyword argument
+    DefaultFactory(PyObject, bool),
+}
+
+#[derive(Debug, Clone)]
+pub struct DefaultValidator {
+    default: DefaultType,
+    on_none: bool,
+    validate_default: bool,
+    validator: Arc<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for DefaultValidator {
+    const EXPECTED_TYPE: &'static str = "default";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        build_context: &mut BuildContext,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let default = match (
+            schema.get_as(intern!(py, "default"))?,
+            schema.get_as(intern!(py, "default_factory"))?,
+        ) {
+            (Some(default), None) => DefaultType::Default(default),
+            (None, Some(default_factory)) => DefaultType::DefaultFactory(
+                default_factory,
+                schema
+                    .get_as(intern!(py, "default_factory_takes_data"))?
+                    .unwrap_or(false),
+            ),
+            (Some(_), Some(_)) => return py_error!("'default' and 'default_factory' cannot be used together"),
+            (None, None) => return py_error!("'default' or 'default_factory' must be set"),
+        };
+
+        let sub_schema: &PyAny = schema.get_as_req(intern!(py, "schema"))?;
+        let validator = Arc::new(build_validator(sub_schema, config, build_context)?.0);
+        let name = format!("{}[{}]", Self::EXPECTED_TYPE, validator.get_name());
+
+        Ok(Self {
+            default,
+            on_none: schema.get_as(intern!(py, "on_none"))?.unwrap_or(false),
+            validate_default: schema.get_as(intern!(py, "validate_default"))?.unwrap_or(false),
+            validator,
+            name,
+        }
+        .into())
+    }
+}
+
+impl Validator for DefaultValidator {
+    fn validate<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        if self.on_none && input.is_none() {
+            self.default_value(py, extra, slots, recursion_guard)
+        } else {
+            self.validator.validate(py, input, extra, slots, recursion_guard)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn complete(&mut self, build_context: &BuildContext) -> PyResult<()> {
+        Arc::make_mut(&mut self.validator).complete(build_context)
+    }
+}
+
+impl DefaultValidator {
+    /// Used when the input is missing entirely (e.g. a missing field in a typed dict), and when the input
+    /// is `None` if `on_none` is set
+    pub fn default_value<'s, 'data>(
+        &'s self,
+        py: Python<'data>,
+        extra: &Extra,
+        slots: &'data [CombinedValidator],
+        recursion_guard: &'s mut RecursionGuard,
+    ) -> ValResult<'data, PyObject> {
+        let default = match self.default {
+            DefaultType::Default(ref default) => default.clone_ref(py),
+            DefaultType::DefaultFactory(ref default_factory, takes_data) => {
+                call_default_factory(py, default_factory, takes_data, extra.data)?
+            }
+        };
+        if self.validate_default {
+            self.validator
+                .validate(py, default.into_ref(py), extra, slots, recursion_guard)
+        } else {
+            Ok(default)
+        }
+    }
+}
+
+/// Call a default factory, if `takes_data` is set, the data validated so far (e.g. earlier fields of a typed dict,
+/// fields are validated in order) is passed as the `data` keyword argument, `data` is `None` outside a typed dict.
+/// The factory gets a copy of the data, so mutating it can't change the validated output
+pub fn call_default_factory(
+    py: Python,
+    default_factory: &PyObject,
+    takes_data: bool,
+    data: Option<&PyDict>,
+) -> PyResult<PyObject> {
+    if takes_data {
+        let data = match data {
+            Some(data) => Some(data.copy()?),
+            None => None,
+        };
+        let kwargs = [("data", data.into_py(py))].into_py_dict(py);
+        default_factory.call(py, (), Some(kwargs))
+    } else {
+        default_factory.call0(py)
+    }
+}
@@ -4,6 +4,7 @@ Numerous benchmarks of specific functionality.
 import json
 import os
 import platform
+import threading
 from datetime import date, datetime, timedelta, timezone
 from typing import Dict, FrozenSet, List, Optional, Set, Union
 
@@ -161,6 +162,38 @@ def test_core_string_lax(benchmark):
     benchmark(validator.validate_python, input_str)
 
 
+@pytest.mark.benchmark(group='string')
+def test_core_string_large_strip_whitespace(benchmark):
+    # nothing is stripped, so the input is returned without being copied
+    validator = SchemaValidator({'type': 'str', 'strip_whitespace': True})
+    input_str = 'Hello ' * 100_000 + 'world'
+    assert validator.validate_python(input_str) is input_str
+
+    benchmark(validator.validate_python, input_str)
+
+
+@pytest.mark.benchmark(group='string')
+def test_core_string_model_many_fields(benchmark):
+    class MyCoreModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'model-class',
+            'class_type': MyCoreModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {f'field_{i}': {'schema': {'type': 'str'}} for i in range(20)},
+            },
+        }
+    )
+    data = {f'field_{i}': f'value {i}' for i in range(20)}
+    assert v.validate_python(data).__dict__ == data
+
+    benchmark(v.validate_python, data)
+
+
 @pytest.fixture
 def recursive_model_data():
     data = {'width': -1}
@@ -211,6 +244,32 @@ def test_recursive_model_core(recursive_model_data, benchmark):
     benchmark(v.validate_python, recursive_model_data)
 
 
+@pytest.fixture
+def recursive_dag_data():
+    # each node's children are the same object, so sub-trees are shared and validated many times but there's no cycle
+    node = {'name': 'leaf', 'children': []}
+    for i in range(8):
+        node = {'name': f'node-{i}', 'children': [node, node, node]}
+    return node
+
+
+@pytest.mark.benchmark(group='recursive DAG')
+def test_recursive_dag_core(recursive_dag_data, benchmark):
+    v = SchemaValidator(
+        {
+            'ref': 'Node',
+            'type': 'typed-dict',
+            'fields': {
+                'name': {'schema': 'str'},
+                'children': {
+                    'schema': {'type': 'list', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'Node'}}
+                },
+            },
+        }
+    )
+    benchmark(v.validate_python, recursive_dag_data)
+
+
 @skip_pydantic
 @pytest.mark.benchmark(group='List[TypedDict]')
 def test_list_of_dict_models_pyd(benchmark):
@@ -285,6 +344,94 @@ def test_list_of_ints_core_json(benchmark):
         v.validate_json(json_data[1])
 
 
+@pytest.mark.benchmark(group='List[int] too long')
+def test_list_of_ints_too_long_core_py(benchmark):
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}, 'max_items': 100})
+    input_value = list_of_ints_data[1] * 1000
+
+    @benchmark
+    def t():
+        try:
+            v.validate_python(input_value)
+        except ValidationError:
+            pass
+
+
+@pytest.mark.benchmark(group='List[int] too long')
+def test_list_of_ints_too_long_core_json(benchmark):
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}, 'max_items': 100})
+    json_data = json.dumps(list_of_ints_data[1] * 100)
+
+    @benchmark
+    def t():
+        try:
+            v.validate_json(json_data)
+        except ValidationError:
+            pass
+
+
+large_list_of_ints_data = list(range(100_000))
+
+
+@pytest.mark.benchmark(group='List[int] large')
+def test_list_of_ints_large_core_py(benchmark):
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
+    benchmark(v.validate_python, large_list_of_ints_data)
+
+
+@pytest.mark.benchmark(group='List[int] large')
+def test_list_of_ints_large_core_json(benchmark):
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
+    json_data = json.dumps(large_list_of_ints_data)
+    benchmark(v.validate_json, json_data)
+
+
+@pytest.mark.benchmark(group='List[int] large')
+def test_list_of_ints_large_core_generator(benchmark):
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'int'}})
+
+    @benchmark
+    def t():
+        v.validate_python(i for i in large_list_of_ints_data)
+
+
+@pytest.fixture(scope='module')
+def large_json_document():
+    return json.dumps(
+        [{'id': i, 'name': f'name {i}', 'tags': [f'tag {t}' for t in range(10)], 'score': i / 7} for i in range(10_000)]
+    )
+
+
+@pytest.mark.benchmark(group='JSON large threaded')
+def test_large_json_document_threads(benchmark, large_json_document):
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {
+                'type': 'typed-dict',
+                'fields': {
+                    'id': {'schema': 'int'},
+                    'name': {'schema': 'str'},
+                    'tags': {'schema': {'type': 'list', 'items_schema': 'str'}},
+                    'score': {'schema': 'float'},
+                },
+            },
+        }
+    )
+    assert len(v.validate_json(large_json_document)) == 10_000
+
+    def validate_document():
+        v.validate_json(large_json_document)
+
+    @benchmark
+    def t():
+        threads = [threading.Thread(target=validate_document) for _ in range(4)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+
 @skip_pydantic
 @pytest.mark.benchmark(group='List[Any]')
 def test_list_of_any_pyd_py(benchmark):
@@ -307,6 +454,27 @@ def test_list_of_any_core_py(benchmark):
         v.validate_python(list_of_ints_data[1])
 
 
+@pytest.mark.benchmark(group='List[Any]')
+def test_list_of_any_items_schema_core_py(benchmark):
+    v = SchemaValidator({'type': 'list', 'items_schema': 'any'})
+
+    @benchmark
+    def t():
+        v.validate_python(list_of_ints_data[0])
+        v.validate_python(list_of_ints_data[1])
+
+
+@pytest.mark.benchmark(group='List[Any] JSON')
+def test_list_of_any_core_json(benchmark):
+    v = SchemaValidator({'type': 'list', 'items_schema': 'any'})
+    json_data = [json.dumps(d) for d in list_of_ints_data]
+
+    @benchmark
+    def t():
+        v.validate_json(json_data[0])
+        v.validate_json(json_data[1])
+
+
 set_of_ints_data = ({i for i in range(1000)}, {str(i) for i in range(1000)})
 
 
@@ -497,6 +665,14 @@ def test_list_of_nullable_core(benchmark):
     benchmark(v.validate_python, list_of_nullable_data)
 
 
+@pytest.mark.benchmark(group='List[Nullable[int]] JSON')
+def test_list_of_nullable_core_json(benchmark):
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'nullable', 'schema': 'int'}})
+
+    json_data = json.dumps(list_of_nullable_data)
+    benchmark(v.validate_json, json_data)
+
+
 some_bytes = b'0' * 1000
 
 
@@ -663,6 +839,39 @@ class TestBenchmarkUnion:
 
         benchmark(PydanticModel.parse_obj, 1)  # will be True
 
+    @pytest.mark.parametrize('mode', ['smart', 'left_to_right'])
+    @pytest.mark.benchmark(group='union-all-fail')
+    def test_union_all_fail_core(self, benchmark, mode):
+        choices = ['int', 'bool', 'float', 'date', 'datetime', 'time', 'bytes', 'list', 'dict', 'set']
+        v = SchemaValidator({'type': 'union', 'choices': choices, 'mode': mode})
+
+        @benchmark
+        def t():
+            try:
+                v.validate_python('foobar')
+            except ValidationError:
+                pass
+
+    @pytest.mark.benchmark(group='tagged-union')
+    def test_tagged_union_core(self, benchmark):
+        choices = {
+            f'tag_{i}': {'type': 'typed-dict', 'fields': {'kind': {'schema': 'str'}, f'field_{i}': {'schema': 'int'}}}
+            for i in range(10)
+        }
+        v = SchemaValidator({'type': 'tagged-union', 'discriminator': 'kind', 'choices': choices})
+
+        benchmark(v.validate_python, {'kind': 'tag_9', 'field_9': 1})
+
+    @pytest.mark.benchmark(group='tagged-union')
+    def test_tagged_union_plain_union_core(self, benchmark):
+        choices = [
+            {'type': 'typed-dict', 'fields': {'kind': {'schema': 'str'}, f'field_{i}': {'schema': 'int'}}}
+            for i in range(10)
+        ]
+        v = SchemaValidator({'type': 'union', 'choices': choices})
+
+        benchmark(v.validate_python, {'kind': 'tag_9', 'field_9': 1})
+
     @pytest.mark.benchmark(group='strict-union')
     def test_strict_union_core(self, benchmark):
         v = SchemaValidator(
@@ -768,3 +977,30 @@ def test_variable_tuple(benchmark):
     assert v.validate_python((1, 2, 3, '4', 5)) == (1, 2, 3, 4, 5)
 
     benchmark(v.validate_python, (1, 2, 3, '4', 5))
+
+
+@pytest.fixture(scope='module')
+def deeply_nested_schema():
+    schema = {'type': 'typed-dict', 'fields': {'x': {'schema': 'int'}}}
+    for _ in range(10):
+        schema = {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {'schema': 'int'},
+                'items': {'schema': {'type': 'list', 'items_schema': {'type': 'dict', 'values_schema': 'str'}}},
+                'child': {'schema': {'type': 'nullable', 'schema': schema}},
+            },
+        }
+    return schema
+
+
+@pytest.mark.benchmark(group='nested-clone')
+def test_function_wrap_deeply_nested(benchmark, deeply_nested_schema):
+    def f(input_value, *, validator, **kwargs):
+        return validator(input_value)
+
+    v = SchemaValidator({'type': 'function', 'mode': 'wrap', 'function': f, 'schema': deeply_nested_schema})
+    assert v.validate_python({'x': 1, 'items': [], 'child': None}) == {'x': 1, 'items': [], 'child': None}
+
+    benchmark(v.validate_python, {'x': 1, 'items': [], 'child': None})
+
@@ -84,3 +84,49 @@ def test_try_self_schema_discriminator():
     """Trying to use self-schema when it shouldn't be used"""
     v = SchemaValidator({'type': 'tagged-union', 'choices': {'int': 'int'}, 'discriminator': 'self-schema'})
     assert 'discriminator: LookupKey' in repr(v)
+
+
+def test_schema_error_is_value_error():
+    assert issubclass(SchemaError, ValueError)
+    with pytest.raises(ValueError, match='Invalid Schema'):
+        SchemaValidator({'type': 'int', 'multiple_of': 'xxx'})
+
+
+@pytest.mark.parametrize(
+    'schema,config,expected',
+    [
+        (
+            {'type': 'int'},
+            {'strict': 'xxx'},
+            'Error building "int" validator:\n'
+            '  SchemaError: Invalid value for "strict": \'str\' object cannot be converted to \'PyBool\'',
+        ),
+        (
+            {'type': 'set', 'max_items': 4},
+            {'strict': []},
+            'Error building "set" validator:\n'
+            '  SchemaError: Invalid value for "strict": \'list\' object cannot be converted to \'PyBool\'',
+        ),
+        (
+            {'type': 'set', 'items_schema': {'type': 'int', 'ge': 1}},
+            {'strict': 1.5},
+            'Error building "int" validator:\n'
+            '  SchemaError: Invalid value for "strict": \'float\' object cannot be converted to \'PyBool\'',
+        ),
+        (
+            {'type': 'str'},
+            {'str_max_length': 'xxx'},
+            'Error building "str" validator:\n'
+            '  SchemaError: Invalid value for "str_max_length": \'str\' object cannot be interpreted as an integer',
+        ),
+    ],
+)
+def test_build_error_key(schema, config, expected):
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(schema, config)
+    assert expected in str(exc_info.value)
+
+
+def test_build_error_enum_cls():
+    with pytest.raises(SchemaError, match=r'Error building "enum" validator:\n  SchemaError: Invalid value for "cls"'):
+        SchemaValidator({'type': 'enum', 'cls': 123})
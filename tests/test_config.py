@@ -177,16 +177,16 @@ def test_sub_model_merge():
     assert exc_info.value.errors() == [
         {
             'kind': 'too_long',
-            'loc': ['f'],
+            'loc': ('f',),
             'message': 'String must have at most 4 characters',
             'input_value': 'tests',
-            'context': {'max_length': 4},
+            'context': {'max_length': 4, 'input_length': 5},
         },
         {
             'kind': 'too_short',
-            'loc': ['sub_model', 'f'],
+            'loc': ('sub_model', 'f'),
             'message': 'String must have at least 1 characters',
             'input_value': '',
-            'context': {'min_length': 1},
+            'context': {'min_length': 1, 'input_length': 0},
         },
     ]
@@ -0,0 +1,140 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from .conftest import PyAndJson
+
+
+def test_custom_error(py_and_json: PyAndJson):
+    v = py_and_json(
+        {'type': 'int', 'custom_error_kind': 'my_error', 'custom_error_message': 'this is a custom error message'}
+    )
+    assert v.validate_test(1) == 1
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('foobar')
+    assert exc_info.value.errors() == [
+        {'kind': 'my_error', 'loc': (), 'message': 'this is a custom error message', 'input_value': 'foobar'}
+    ]
+
+
+def test_custom_error_context():
+    v = SchemaValidator(
+        {
+            'type': 'str',
+            'max_length': 3,
+            'custom_error_kind': 'too_long_for_me',
+            'custom_error_message': 'at most {limit} characters please',
+            'custom_error_context': {'limit': 3},
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('abcd')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'too_long_for_me',
+            'loc': (),
+            'message': 'at most 3 characters please',
+            'input_value': 'abcd',
+            'context': {'limit': 3},
+        }
+    ]
+
+
+def test_custom_error_field():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {
+                    'schema': {'type': 'int', 'custom_error_kind': 'x_error', 'custom_error_message': 'x is wrong'}
+                },
+                'y': {'schema': 'int'},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': 'a', 'y': 'b'})
+    assert [(e['kind'], e['loc'], e['message']) for e in exc_info.value.errors()] == [
+        ('x_error', ('x',), 'x is wrong'),
+        ('int_parsing', ('y',), 'Value must be a valid integer, unable to parse string as an integer'),
+    ]
+
+
+def test_custom_error_not_nested():
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': 'int',
+            'max_items': 2,
+            'custom_error_kind': 'bad_list',
+            'custom_error_message': 'list is bad',
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'x'])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': (1,),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2, 3])
+    assert exc_info.value.errors() == [
+        {'kind': 'bad_list', 'loc': (), 'message': 'list is bad', 'input_value': [1, 2, 3]}
+    ]
+
+
+def test_custom_error_ref():
+    v = SchemaValidator(
+        {'type': 'int', 'ref': 'my-int', 'custom_error_kind': 'my_error', 'custom_error_message': 'not my int'}
+    )
+    with pytest.raises(ValidationError, match=r'not my int \[kind=my_error'):
+        v.validate_python('x')
+
+
+def test_custom_error_default_field():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {
+                    'schema': {
+                        'type': 'default',
+                        'schema': 'int',
+                        'default': 'y',
+                        'validate_default': True,
+                        'custom_error_kind': 'x_error',
+                        'custom_error_message': 'x is wrong',
+                    }
+                }
+            },
+        }
+    )
+    assert v.validate_python({'x': 1}) == {'x': 1}
+    # the default is used when the field is missing, and validating it raises the custom error
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors() == [{'kind': 'x_error', 'loc': ('x',), 'message': 'x is wrong', 'input_value': 'y'}]
+
+
+def test_custom_error_repr():
+    v = SchemaValidator({'type': 'int', 'custom_error_kind': 'my_error', 'custom_error_message': 'broken'})
+    assert repr(v).startswith('SchemaValidator(name="int"')
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        {'type': 'int', 'custom_error_kind': 'my_error'},
+        {'type': 'int', 'custom_error_message': 'broken'},
+        {'type': 'int', 'custom_error_context': {'a': 1}},
+    ],
+)
+def test_custom_error_invalid(schema):
+    with pytest.raises(SchemaError, match="'custom_error_kind' and 'custom_error_message' must be set together"):
+        SchemaValidator(schema)
@@ -3,7 +3,7 @@ from datetime import datetime, timezone
 from typing import Optional
 
 import pytest
-from dirty_equals import AnyThing, IsBytes, IsList, IsStr
+from dirty_equals import AnyThing, IsBytes, IsList, IsStr, IsTuple
 from hypothesis import given, strategies
 from typing_extensions import TypedDict
 
@@ -44,7 +44,7 @@ def test_datetime_binary(datetime_schema, data):
         assert exc.errors() == [
             {
                 'kind': 'datetime_parsing',
-                'loc': [],
+                'loc': (),
                 'message': IsStr(regex='Value must be a valid datetime, .+'),
                 'input_value': IsBytes(),
                 'context': {'error': IsStr()},
@@ -105,7 +105,7 @@ def test_recursive_cycles(recursive_schema, data):
         assert exc.errors() == [
             {
                 'kind': 'recursion_loop',
-                'loc': IsList(length=(1, None)),
+                'loc': IsTuple(length=(1, None)),
                 'message': 'Recursion error - cyclic reference detected',
                 'input_value': AnyThing(),
             }
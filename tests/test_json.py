@@ -1,6 +1,9 @@
+import json
+from concurrent.futures import ThreadPoolExecutor
+
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaValidator, ValidationError, parse_json
 
 
 @pytest.mark.parametrize(
@@ -62,6 +65,26 @@ def test_float_no_remainder():
     assert v.validate_json('123.0') == 123
 
 
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        {'$serde_json::private::Number': 'abc'},
+        {'$serde_json::private::Number': '1.5'},
+        {'$serde_json::private::Number': '123456789012345678901234567890'},
+        {'$serde_json::private::Number': 1.5},
+        {'$serde_json::private::Number': None},
+        {'$serde_json::private::Number': '1', 'foo': 'bar'},
+    ],
+)
+def test_number_token_object(input_value):
+    """
+    serde_json passes numbers it can't parse as an object with a private key, an object with the same key
+    in the JSON must still be returned as an object
+    """
+    v = SchemaValidator({'type': 'any'})
+    assert v.validate_json(json.dumps(input_value)) == input_value
+
+
 def test_error_loc():
     v = SchemaValidator(
         {
@@ -80,7 +103,7 @@ def test_error_loc():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['field_a', 2],
+            'loc': ('field_a', 2),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         }
@@ -97,6 +120,22 @@ def test_dict_any_value():
     assert v.validate_json('{"1": 1, "2": "a", "3": null}') == {'1': 1, '2': 'a', '3': None}
 
 
+@pytest.mark.parametrize(
+    'schema',
+    [{'type': 'dict'}, {'type': 'dict', 'keys_schema': 'str', 'values_schema': 'int', 'strict': True}, {'type': 'any'}],
+)
+def test_dict_key_order(schema):
+    v = SchemaValidator(schema)
+    output = v.validate_json('{"z": 1, "a": 2, "m": 3, "b": 4}')
+    assert list(output.items()) == [('z', 1), ('a', 2), ('m', 3), ('b', 4)]
+
+
+def test_dict_key_order_nested():
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'dict', 'values_schema': 'int'}})
+    output = v.validate_json('{"b": {"y": 1, "x": 2}, "a": {"d": 3, "c": 4}}')
+    assert [(k, list(d)) for k, d in output.items()] == [('b', ['y', 'x']), ('a', ['d', 'c'])]
+
+
 def test_invalid_json():
     v = SchemaValidator({'type': 'bool'})
 
@@ -105,10 +144,15 @@ def test_invalid_json():
     assert exc_info.value.errors() == [
         {
             'kind': 'invalid_json',
-            'loc': [],
+            'loc': (),
             'message': 'Invalid JSON: EOF while parsing a string at line 1 column 7',
             'input_value': '"foobar',
-            'context': {'error': 'EOF while parsing a string at line 1 column 7'},
+            'context': {
+                'error': 'EOF while parsing a string at line 1 column 7',
+                'line': 1,
+                'column': 7,
+                'position': 6,
+            },
         }
     ]
     with pytest.raises(ValidationError) as exc_info:
@@ -116,9 +160,280 @@ def test_invalid_json():
     assert exc_info.value.errors() == [
         {
             'kind': 'invalid_json',
-            'loc': [],
+            'loc': (),
             'message': 'Invalid JSON: trailing comma at line 3 column 3',
             'input_value': '[1,\n2,\n3,]',
-            'context': {'error': 'trailing comma at line 3 column 3'},
+            'context': {'error': 'trailing comma at line 3 column 3', 'line': 3, 'column': 3, 'position': 9},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'input_value,line,column,position',
+    [
+        ('[1, 2,]', 1, 7, 6),
+        (b'[1, 2,]', 1, 7, 6),
+        (bytearray(b'[1, 2,]'), 1, 7, 6),
+        ('{\n  "a": 1,\n  "b": [1, 2,]\n}', 3, 14, 25),
+    ],
+)
+def test_invalid_json_position(input_value, line, column, position):
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    context = exc_info.value.errors()[0]['context']
+    assert context['error'] == f'trailing comma at line {line} column {column}'
+    assert (context['line'], context['column'], context['position']) == (line, column, position)
+    assert input_value[position : position + 1] in (']', b']')
+
+
+@pytest.mark.parametrize(
+    'input_value,position',
+    [('{"a": 1} garbage', 9), ('{"a": 1}{"b": 2}', 8), ('[1, 2]\n]', 7), (b'1 2', 2), ('"foo"x', 5)],
+)
+def test_trailing_data(input_value, position):
+    v = SchemaValidator('any')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    error = exc_info.value.errors()[0]
+    assert error['kind'] == 'invalid_json'
+    assert error['context']['error'].startswith('trailing characters at line')
+    assert error['context']['position'] == position
+
+    with pytest.raises(ValidationError, match='kind=invalid_json'):
+        parse_json(input_value)
+    with pytest.raises(ValidationError, match='kind=invalid_json'):
+        SchemaValidator({'type': 'json'}).validate_python(input_value)
+
+
+@pytest.mark.parametrize('input_value', ['{"a": 1} ', '{"a": 1}\n', ' \t{"a": 1}\r\n\t ', b'{"a": 1}\n\n'])
+def test_trailing_whitespace(input_value):
+    assert SchemaValidator('any').validate_json(input_value) == {'a': 1}
+    assert SchemaValidator({'type': 'json'}).validate_python(input_value) == {'a': 1}
+
+
+def test_validate_json_iter():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    items = v.validate_json_iter('[1, "2", 3]')
+    assert iter(items) is items
+    assert next(items) == 1
+    assert next(items) == 2
+    assert next(items) == 3
+    with pytest.raises(StopIteration):
+        next(items)
+
+    assert list(v.validate_json_iter(b'[]')) == []
+    assert list(SchemaValidator('list').validate_json_iter('[1, "a", null]')) == [1, 'a', None]
+
+
+def test_validate_json_iter_errors():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    items = v.validate_json_iter('[1, "x", 3]')
+    assert next(items) == 1
+    with pytest.raises(ValidationError) as exc_info:
+        next(items)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': (1,),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+    # iteration can continue after an invalid item
+    assert next(items) == 3
+
+    with pytest.raises(ValidationError, match='kind=list_type'):
+        v.validate_json_iter('{"a": 1}')
+    with pytest.raises(ValidationError, match='kind=invalid_json'):
+        v.validate_json_iter('[1, 2')
+
+
+def test_validate_json_iter_constraints():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'max_items': 2})
+    with pytest.raises(ValidationError, match='kind=too_long'):
+        v.validate_json_iter('[1, 2, 3]')
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    assert list(v.validate_json_iter('[1, "2"]')) == [1, 2]
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        list(v.validate_json_iter('[1, "2"]', strict=True))
+
+
+def test_validate_json_iter_invalid_schema():
+    with pytest.raises(TypeError, match='validate_json_iter can only be used with a list schema'):
+        SchemaValidator('int').validate_json_iter('[1]')
+    with pytest.raises(TypeError, match="validate_json_iter doesn't support 'unique_items'"):
+        SchemaValidator({'type': 'list', 'unique_items': True}).validate_json_iter('[1]')
+
+
+def test_validate_json_iter_recursive():
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'ref': 'the-list',
+            'items_schema': {'type': 'union', 'choices': ['int', {'type': 'recursive-ref', 'schema_ref': 'the-list'}]},
+        }
+    )
+    assert list(v.validate_json_iter('[1, [2, [3]]]')) == [1, [2, [3]]]
+
+
+def test_validate_json_iter_custom_error():
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': 'int',
+            'custom_error_kind': 'my_list',
+            'custom_error_message': 'Not a list of ints',
+        }
+    )
+    assert list(v.validate_json_iter('[1, 2]')) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_iter('"foo"')
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('my_list', ())]
+
+    items = v.validate_json_iter('["a"]')
+    with pytest.raises(ValidationError) as exc_info:
+        next(items)
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (0,))]
+
+
+def test_validate_json_threads():
+    v = SchemaValidator({'type': 'list', 'items_schema': {'type': 'list', 'items_schema': 'int'}})
+    json_data = [json.dumps([[i] * 100 for i in range(100)]).encode(), bytearray(b'[[1, 2], [3]]')]
+
+    with ThreadPoolExecutor(max_workers=4) as executor:
+        results = list(executor.map(v.validate_json, json_data * 10))
+
+    assert results == [[[i] * 100 for i in range(100)], [[1, 2], [3]]] * 10
+
+
+def test_validate_parsed_json():
+    parsed = parse_json('{"a": "1", "b": [1, 2]}')
+    v1 = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}})
+    v2 = SchemaValidator({'type': 'dict', 'values_schema': 'any'})
+
+    assert v1.validate_parsed_json(parsed) == {'a': 1}
+    assert v2.validate_parsed_json(parsed) == {'a': '1', 'b': [1, 2]}
+    # the parsed json can be validated repeatedly
+    assert v1.validate_parsed_json(parsed) == {'a': 1}
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        v1.validate_parsed_json(parsed, strict=True)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('{"a": "1", "b": [1, 2]}', 'ParsedJson(object, len=2)'),
+        ('[' + ', '.join(['1'] * 1000) + ']', 'ParsedJson(array, len=1000)'),
+        ('"caf\u00e9"', 'ParsedJson(string, len=4)'),
+        ('123', 'ParsedJson(int)'),
+        ('null', 'ParsedJson(null)'),
+    ],
+)
+def test_parsed_json_repr(input_value, expected):
+    assert repr(parse_json(input_value)) == expected
+
+
+def test_parse_json_invalid():
+    with pytest.raises(ValidationError) as exc_info:
+        parse_json('[1, 2')
+    assert exc_info.value.title == 'JSON'
+    assert exc_info.value.errors()[0]['kind'] == 'invalid_json'
+
+    with pytest.raises(TypeError, match='JSON input must be str, bytes or bytearray, not int'):
+        parse_json(1)
+
+
+def test_duplicate_keys_last_wins():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int'})
+    assert v.validate_json('{"a": 1, "a": 2}') == {'a': 2}
+
+
+def test_duplicate_keys_rejected():
+    v = SchemaValidator({'type': 'dict', 'values_schema': 'int'}, {'json_reject_duplicate_keys': True})
+    assert v.validate_json('{"a": 1, "b": 2}') == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a":1,"a":2}')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'json_duplicate_key',
+            'loc': (),
+            'message': "Invalid JSON: duplicate key 'a' at line 1 column 10",
+            'input_value': '{"a":1,"a":2}',
+            'context': {'key': 'a', 'line': 1, 'column': 10, 'position': 9},
+        }
+    ]
+    assert v.isinstance_json('{"a":1,"a":2}') is False
+
+
+def test_duplicate_keys_nested():
+    v = SchemaValidator({'type': 'any'}, {'json_reject_duplicate_keys': True})
+    assert v.validate_json('[{"a": 1}, {"a": 2}]') == [{'a': 1}, {'a': 2}]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{\n  "x": [{"b": 1, "b": 2}]\n}')
+    context = exc_info.value.errors()[0]['context']
+    assert context == {'key': 'b', 'line': 2, 'column': 20, 'position': 21}
+
+
+def test_parse_json_duplicate_keys():
+    assert SchemaValidator('dict').validate_parsed_json(parse_json('{"a":1,"a":2}')) == {'a': 2}
+    with pytest.raises(ValidationError, match=r"duplicate key 'a' at line 1 column 10 \[kind=json_duplicate_key"):
+        parse_json('{"a":1,"a":2}', reject_duplicate_keys=True)
+
+
+def test_validate_json_with_warnings():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': {'type': 'list', 'items_schema': 'bool'}},
+                'c': {'schema': {'type': 'dict', 'keys_schema': 'int', 'values_schema': 'str'}},
+                'd': {'schema': 'float'},
+            },
+        }
+    )
+    output, warnings = v.validate_json_with_warnings('{"a": "1", "b": [true, "yes", 1], "c": {" 2 ": 3}, "d": 4}')
+    assert output == {'a': 1, 'b': [True, True, True], 'c': {2: '3'}, 'd': 4.0}
+    assert warnings == [
+        {'loc': ('a',), 'message': 'Input of type string coerced to int', 'input_value': '1'},
+        {'loc': ('b', 1), 'message': 'Input of type string coerced to bool', 'input_value': 'yes'},
+        {'loc': ('b', 2), 'message': 'Input of type int coerced to bool', 'input_value': 1},
+        {'loc': ('c', ' 2 ', '[key]'), 'message': 'Input of type string coerced to int', 'input_value': ' 2 '},
+        {'loc': ('c', ' 2 '), 'message': 'Input of type int coerced to str', 'input_value': 3},
+    ]
+
+    output, warnings = v.validate_json_with_warnings('{"a": 1, "b": [true], "c": {"2": "3"}, "d": 4.5}')
+    assert output == {'a': 1, 'b': [True], 'c': {2: '3'}, 'd': 4.5}
+    assert warnings == []
+
+
+def test_validate_json_with_warnings_errors():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(ValidationError, match='kind=int_parsing'):
+        v.validate_json_with_warnings('["1", "x"]')
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        v.validate_json_with_warnings('["1"]', strict=True)
+    with pytest.raises(ValidationError, match='kind=invalid_json'):
+        v.validate_json_with_warnings('[1,')
+    assert v.validate_json_with_warnings('[1, 2]', strict=True) == ([1, 2], [])
+
+
+def test_validate_json_with_warnings_union():
+    v = SchemaValidator(
+        {
+            'type': 'union',
+            'choices': [
+                {'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'int']},
+                {'type': 'list', 'items_schema': 'str'},
+            ],
+        }
+    )
+    # warnings from the tuple choice are discarded since it fails
+    assert v.validate_json_with_warnings('["1", "x"]') == (['1', 'x'], [])
+    assert v.validate_json_with_warnings('[1, "2"]') == (
+        (1, 2),
+        [{'loc': (1,), 'message': 'Input of type string coerced to int', 'input_value': '2'}],
+    )
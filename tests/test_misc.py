@@ -1,10 +1,17 @@
+import json
 import re
 import sys
 from pathlib import Path
 
 import pytest
 
-from pydantic_core._pydantic_core import SchemaError, SchemaValidator, ValidationError, __version__
+from pydantic_core._pydantic_core import (
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    __version__,
+    list_error_codes,
+)
 
 
 @pytest.mark.parametrize('obj', [ValidationError, SchemaValidator, SchemaError])
@@ -33,6 +40,32 @@ def test_validation_error():
     assert exc_info.value.error_count() == 1
 
 
+def test_validation_error_title():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}}}, {'title': 'User'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+
+    assert exc_info.value.title == 'User'
+    assert str(exc_info.value) == (
+        '1 validation error for User\n'
+        'a\n'
+        "  Value must be a valid integer, unable to parse string as an integer [kind=int_parsing, input_value='x', "
+        'input_type=str]'
+    )
+    # the title doesn't change the structured errors
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ('a',),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+    with pytest.raises(ValidationError, match='^1 validation error for User\n'):
+        v.validate_json('{"a": []}')
+
+
 def test_validation_error_multiple():
     class MyModel:
         # this is not required, but it avoids `__fields_set__` being included in `__dict__`
@@ -59,13 +92,13 @@ def test_validation_error_multiple():
     assert exc_info.value.errors() == [
         {
             'kind': 'float_parsing',
-            'loc': ['x'],
+            'loc': ('x',),
             'message': 'Value must be a valid number, unable to parse string as an number',
             'input_value': 'x' * 60,
         },
         {
             'kind': 'int_parsing',
-            'loc': ['y'],
+            'loc': ('y',),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'y',
         },
@@ -81,9 +114,347 @@ def test_validation_error_multiple():
     )
 
 
+def test_validation_error_loc():
+    v = SchemaValidator({'type': 'dict', 'values_schema': {'type': 'list', 'items_schema': 'int'}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'foo': [1, 'x']})
+
+    loc = exc_info.value.errors()[0]['loc']
+    assert loc == ('foo', 1)
+    assert isinstance(loc, tuple)
+    assert str(exc_info.value) == (
+        '1 validation error for dict[any,list[int]]\n'
+        'foo -> 1\n'
+        "  Value must be a valid integer, unable to parse string as an integer [kind=int_parsing, input_value='x', "
+        'input_type=str]'
+    )
+
+
+def test_grouped_errors():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': {'type': 'list', 'items_schema': 'int'}},
+                'c': {'schema': 'str'},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': [1, 'y', 'z'], 'c': None})
+
+    assert exc_info.value.error_count() == 4
+    grouped = exc_info.value.grouped_errors()
+    assert list(grouped.keys()) == ['a', 'b', 'c']
+    assert {k: len(v) for k, v in grouped.items()} == {'a': 1, 'b': 2, 'c': 1}
+    assert [e['loc'] for e in grouped['b']] == [('b', 1), ('b', 2)]
+    assert grouped['c'][0]['kind'] == 'str_type'
+    assert [e for group in grouped.values() for e in group] == exc_info.value.errors()
+
+
+def test_grouped_errors_no_loc():
+    v = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.grouped_errors() == {None: exc_info.value.errors()}
+
+
+def test_type_error_input_type():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'strict': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'list_type',
+            'loc': (),
+            'message': 'Value must be a valid list/array, input is of type `dict`',
+            'input_value': {'a': 1},
+            'context': {'input_type': 'dict'},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,input_type',
+    [('null', 'null'), ('true', 'bool'), ('1.5', 'float'), ('"x"', 'string'), ('[]', 'array'), ('{}', 'object')],
+)
+def test_type_error_input_type_json(input_value, input_type):
+    v = SchemaValidator({'type': 'int', 'strict': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value)
+    errors = exc_info.value.errors()
+    assert len(errors) == 1
+    assert errors[0]['kind'] == 'int_type'
+    assert errors[0]['message'] == f'Value must be a valid integer, input is of type `{input_type}`'
+    assert errors[0]['context'] == {'input_type': input_type}
+
+
+def test_non_type_error_no_input_type():
+    v = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert 'context' not in exc_info.value.errors()[0]
+
+
+def test_json():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': {'type': 'list', 'items_schema': {'type': 'str', 'max_length': 2}}},
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': ['abc', None]})
+
+    # like pydantic v1's `ValidationError.json()`, a list of errors with the location as an array
+    output = exc_info.value.json()
+    assert json.loads(output) == [
+        {
+            'kind': 'int_parsing',
+            'loc': ['a'],
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        },
+        {
+            'kind': 'too_long',
+            'loc': ['b', 0],
+            'message': 'String must have at most 2 characters',
+            'input_value': 'abc',
+            'context': {'max_length': 2, 'input_length': 3},
+        },
+        {
+            'kind': 'str_type',
+            'loc': ['b', 1],
+            'message': 'Value must be a valid string, input is of type `NoneType`',
+            'input_value': None,
+            'context': {'input_type': 'NoneType'},
+        },
+    ]
+    assert output.startswith('[{"kind":"int_parsing","loc":["a"],"message":')
+    errors = [{**e, 'loc': list(e['loc'])} for e in exc_info.value.errors()]
+    assert json.loads(output) == errors
+
+
+def test_json_indent():
+    v = SchemaValidator('int')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x')
+    assert exc_info.value.json(indent=2) == (
+        '[\n'
+        '  {\n'
+        '    "kind": "int_parsing",\n'
+        '    "loc": [],\n'
+        '    "message": "Value must be a valid integer, unable to parse string as an integer",\n'
+        '    "input_value": "x"\n'
+        '  }\n'
+        ']'
+    )
+    assert exc_info.value.json(indent=4) == json.dumps(json.loads(exc_info.value.json()), indent=4)
+
+
+def test_json_include_input():
+    v = SchemaValidator('int')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('secret')
+    assert 'secret' in exc_info.value.json()
+    assert json.loads(exc_info.value.json(include_input=False)) == [
+        {
+            'kind': 'int_parsing',
+            'loc': [],
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+        }
+    ]
+
+
+class Foobar:
+    def __repr__(self):
+        return 'Foobar()'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'a': 1, 'b': [True, None, 1.5]}, {'a': 1, 'b': [True, None, 1.5]}),
+        ((1, 'x'), [1, 'x']),
+        (b'bytes', 'bytes'),
+        (b'\xff', "b'\\xff'"),
+        (2**100, 2**100),
+        (float('nan'), 'nan'),
+        ({1: 'a'}, {'1': 'a'}),
+        (Foobar(), 'Foobar()'),
+        ([Foobar()], ['Foobar()']),
+    ],
+)
+def test_json_input_value(input_value, expected):
+    v = SchemaValidator({'type': 'str', 'strict': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert json.loads(exc_info.value.json())[0]['input_value'] == expected
+
+
+def test_json_recursive_input():
+    data = [1]
+    data.append(data)
+    v = SchemaValidator({'type': 'str', 'strict': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(data)
+    input_value = json.loads(exc_info.value.json())[0]['input_value']
+    depth = 0
+    while isinstance(input_value, list):
+        input_value = input_value[1]
+        depth += 1
+    assert depth == 100
+    assert input_value == '[1, [...]]'
+
+
+def test_truncated_input_value():
+    v = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('x' * 1000)
+    error = exc_info.value
+    assert error.errors()[0]['input_value'] == 'x' * 200 + '...'
+    assert error.errors(max_input_length=10)[0]['input_value'] == 'xxxxxxxxxx...'
+    assert error.errors(max_input_length=None)[0]['input_value'] == 'x' * 1000
+    assert error.grouped_errors(max_input_length=10) == {None: error.errors(max_input_length=10)}
+    assert json.loads(error.json())[0]['input_value'] == 'x' * 200 + '...'
+    assert json.loads(error.json(max_input_length=None))[0]['input_value'] == 'x' * 1000
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (list(range(10)), [0, 1, 2, '...']),
+        (tuple(range(10)), (0, 1, 2, '...')),
+        (b'abcdef', b'abc...'),
+        ('abc', 'abc'),
+        ([1, 2, 3], [1, 2, 3]),
+        ({'a': 1, 'b': 2, 'c': 3, 'd': 4}, {'a': 1, 'b': 2, 'c': 3, 'd': 4}),
+    ],
+)
+def test_truncated_input_value_types(input_value, expected):
+    v = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(max_input_length=3)[0]['input_value'] == expected
+
+
 @pytest.mark.skipif(sys.platform == 'emscripten', reason='README.md is not mounted in wasm file system')
 def test_readme(import_execute):
     this_dir = Path(__file__).parent
     readme = (this_dir / '..' / 'README.md').read_text()
     example_code = re.search(r'\n```py\n(.*?)\n```\n', readme, re.M | re.S).group(1)
     import_execute(example_code)
+
+
+def test_error_codes():
+    # error codes are the `kind` of errors, they're part of the public interface and must not change,
+    # new errors should be added here
+    assert list_error_codes() == {
+        'InvalidInput': 'invalid_input',
+        'InvalidJson': 'invalid_json',
+        'JsonDuplicateKey': 'json_duplicate_key',
+        'JsonType': 'json_type',
+        'RecursionLoop': 'recursion_loop',
+        'TooDeep': 'too_deep',
+        'DictAttributesType': 'dict_attributes_type',
+        'Missing': 'missing',
+        'ExtraForbidden': 'extra_forbidden',
+        'FrozenField': 'frozen_field',
+        'InvalidKey': 'invalid_key',
+        'GetAttributeError': 'get_attribute_error',
+        'ModelClassType': 'model_class_type',
+        'NoneRequired': 'none_required',
+        'Bool': 'bool',
+        'GreaterThan': 'greater_than',
+        'GreaterThanEqual': 'greater_than_equal',
+        'LessThan': 'less_than',
+        'LessThanEqual': 'less_than_equal',
+        'TooShort': 'too_short',
+        'TooLong': 'too_long',
+        'StrType': 'str_type',
+        'StrUnicode': 'str_unicode',
+        'StrTooShort': 'too_short',
+        'StrTooLong': 'too_long',
+        'StrPatternMismatch': 'str_pattern_mismatch',
+        'StrNotInAllowed': 'str_not_in_allowed',
+        'DictType': 'dict_type',
+        'DictFromMapping': 'dict_from_mapping',
+        'ListType': 'list_type',
+        'ListUniqueItems': 'list_unique_items',
+        'TupleType': 'tuple_type',
+        'TupleLengthMismatch': 'tuple_length_mismatch',
+        'SetType': 'set_type',
+        'SetItemNotHashable': 'set_item_not_hashable',
+        'BoolType': 'bool_type',
+        'BoolParsing': 'bool_parsing',
+        'IntType': 'int_type',
+        'IntParsing': 'int_parsing',
+        'IntFromFloat': 'int_from_float',
+        'IntNan': 'int_nan',
+        'IntOverflow': 'int_overflow',
+        'IntMultipleOf': 'multiple_of',
+        'IntGreaterThan': 'greater_than',
+        'IntGreaterThanEqual': 'greater_than_equal',
+        'IntLessThan': 'less_than',
+        'IntLessThanEqual': 'less_than_equal',
+        'FloatType': 'float_type',
+        'FloatParsing': 'float_parsing',
+        'FloatFiniteNumber': 'float_finite_number',
+        'FloatMultipleOf': 'multiple_of',
+        'FloatGreaterThan': 'greater_than',
+        'FloatGreaterThanEqual': 'greater_than_equal',
+        'FloatLessThan': 'less_than',
+        'FloatLessThanEqual': 'less_than_equal',
+        'DecimalType': 'decimal_type',
+        'DecimalParsing': 'decimal_parsing',
+        'DecimalFinite': 'decimal_finite',
+        'DecimalMaxDigits': 'decimal_max_digits',
+        'DecimalMaxPlaces': 'decimal_max_places',
+        'BytesType': 'bytes_type',
+        'BytesTooShort': 'too_short',
+        'BytesTooLong': 'too_long',
+        'ValueError': 'value_error',
+        'AssertionError': 'assertion_error',
+        'CustomError': 'custom_error',
+        'LiteralSingleError': 'literal_error',
+        'LiteralMultipleError': 'literal_error',
+        'EnumMember': 'enum_member',
+        'DateType': 'date_type',
+        'DateParsing': 'date_parsing',
+        'DateFromDatetimeParsing': 'date_from_datetime_parsing',
+        'DateFromDatetimeInexact': 'date_from_datetime_inexact',
+        'DateFromDatetimeTzAware': 'date_from_datetime_tz_aware',
+        'DateTooEarly': 'date_too_early',
+        'DateTooLate': 'date_too_late',
+        'DateInPast': 'date_in_past',
+        'DateInFuture': 'date_in_future',
+        'TimeType': 'time_type',
+        'TimeParsing': 'time_parsing',
+        'TimeTzRequired': 'time_tz_required',
+        'TimeTzForbidden': 'time_tz_forbidden',
+        'DateTimeType': 'datetime_type',
+        'DateTimeParsing': 'datetime_parsing',
+        'DateTimeObjectInvalid': 'datetime_object_invalid',
+        'DateTimeTooEarly': 'datetime_too_early',
+        'DateTimeTooLate': 'datetime_too_late',
+        'DateTimeInPast': 'datetime_in_past',
+        'DateTimeInFuture': 'datetime_in_future',
+        'TimeDeltaType': 'time_delta_type',
+        'TimeDeltaParsing': 'time_delta_parsing',
+        'FrozenSetType': 'frozen_set_type',
+        'IsInstanceOf': 'is_instance_of',
+        'CallableType': 'callable_type',
+        'UnionTagInvalid': 'union_tag_invalid',
+        'UnionTagNotFound': 'union_tag_not_found',
+    }
+
+
+def test_error_code_is_kind():
+    v = SchemaValidator({'type': 'int', 'gt': 5})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors()[0]['kind'] == list_error_codes()['IntGreaterThan']
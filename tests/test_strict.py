@@ -1,8 +1,9 @@
 import re
+from datetime import date
 
 import pytest
 
-from pydantic_core import ValidationError
+from pydantic_core import SchemaValidator, ValidationError
 
 from .conftest import Err, PyAndJson
 
@@ -27,3 +28,43 @@ def test_int_strict_argument(py_and_json: PyAndJson, strict, input_value, expect
     else:
         assert v.isinstance_test(input_value, strict) is True
         assert v.validate_test(input_value, strict) == expected
+
+
+def test_strict_argument_overrides_schema(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'int', 'strict': True})
+    with pytest.raises(ValidationError, match=re.escape('Value must be a valid integer [kind=int_type')):
+        v.validate_test('123')
+    assert v.validate_test('123', False) == 123
+
+    v = py_and_json({'type': 'int', 'strict': False})
+    assert v.validate_test('123') == 123
+    with pytest.raises(ValidationError, match=re.escape('Value must be a valid integer [kind=int_type')):
+        v.validate_test('123', True)
+
+
+def test_strict_argument_nested(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'fields': {'a': {'schema': {'type': 'list', 'items_schema': 'int'}}, 'b': {'schema': 'float'}},
+        }
+    )
+    assert v.validate_test({'a': ['1', 2], 'b': '3.5'}) == {'a': [1, 2], 'b': 3.5}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': ['1', 2], 'b': '3.5'}, True)
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_type', ('a', 0)),
+        ('float_type', ('b',)),
+    ]
+
+
+def test_strict_argument_date_from_datetime():
+    v = SchemaValidator({'type': 'date', 'strict': True})
+    with pytest.raises(ValidationError, match='kind=date_type'):
+        v.validate_python('2000-01-01T00:00:00')
+    assert v.validate_python('2000-01-01T00:00:00', strict=False) == date(2000, 1, 1)
+
+    v = SchemaValidator({'type': 'date'})
+    assert v.validate_python('2000-01-01T00:00:00') == date(2000, 1, 1)
+    with pytest.raises(ValidationError, match='kind=date_type'):
+        v.validate_python('2000-01-01T00:00:00', strict=True)
@@ -93,7 +93,7 @@ def test_schema_typing() -> None:
     SchemaValidator(schema)
     schema: Schema = {'type': 'datetime', 'ge': datetime.now()}
     SchemaValidator(schema)
-    schema: Schema = {'type': 'is-instance', 'class_': Foo}
+    schema: Schema = {'type': 'is-instance', 'cls': Foo}
     SchemaValidator(schema)
     schema: Schema = {'type': 'callable'}
     SchemaValidator(schema)
@@ -29,7 +29,8 @@ from ..conftest import Err, PyAndJson, plain_repr
         ),
         (2, Err('Value must be a valid boolean, unable to interpret input [kind=bool_parsing, input_value=2')),
         ([], Err('Value must be a valid boolean [kind=bool_type, input_value=[], input_type=list]')),
-        (1.1, Err('Value must be a valid boolean [kind=bool_type, input_value=1.1, input_type=float]')),
+        (1.1, Err('unable to interpret input [kind=bool_parsing, input_value=1.1, input_type=float]')),
+        (2.5, Err('unable to interpret input [kind=bool_parsing, input_value=2.5, input_type=float]')),
         (2, Err('unable to interpret input [kind=bool_parsing, input_value=2, input_type=int]')),
         (2.0, Err('unable to interpret input [kind=bool_parsing, input_value=2.0, input_type=float]')),
     ],
@@ -45,6 +46,23 @@ def test_bool(py_and_json: PyAndJson, input_value, expected):
         assert v.isinstance_test(input_value) is True
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        *[(s, True) for s in ('yes', 'on', 'y', '1', 't', 'true', 'YES', 'On', 'Y', 'T', 'True', 'TRUE')],
+        *[(s, False) for s in ('no', 'off', 'n', '0', 'f', 'false', 'NO', 'Off', 'N', 'F', 'False', 'FALSE')],
+        *[(s, Err('[kind=bool_parsing')) for s in ('', 'yess', 'nope', '2', '00', 'tru', 'of', 'enabled')],
+    ],
+)
+def test_bool_str_tokens(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'bool'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) is expected
+
+
 def test_bool_strict(py_and_json: PyAndJson):
     v = py_and_json({'type': 'bool', 'strict': True})
     assert v.validate_test(True) is True
@@ -67,7 +85,7 @@ def test_bool_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'bool_parsing',
-            'loc': [],
+            'loc': (),
             'message': 'Value must be a valid boolean, unable to interpret input',
             'input_value': 'wrong',
         }
@@ -76,9 +94,13 @@ def test_bool_error():
 
 def test_bool_repr():
     v = SchemaValidator({'type': 'bool'})
-    assert plain_repr(v) == 'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:false}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:false,allow_int:true,allow_str:true}))'
+    )
     v = SchemaValidator({'type': 'bool', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:true}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="bool",validator=Bool(BoolValidator{strict:true,allow_int:true,allow_str:true}))'
+    )
 
 
 def test_bool_key(py_and_json: PyAndJson):
@@ -88,3 +110,50 @@ def test_bool_key(py_and_json: PyAndJson):
     assert v.validate_test({'true': 1, 'off': 2}, strict=False) == {True: 1, False: 2}
     with pytest.raises(ValidationError, match='Value must be a valid boolean'):
         v.validate_test({'true': 1, 'off': 2}, strict=True)
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'allow_int': False}, True, True),
+        ({'allow_int': False}, 'true', True),
+        ({'allow_int': False}, 'no', False),
+        ({'allow_int': False}, 1, Err('Value must be a valid boolean [kind=bool_type, input_value=1, input_type=int]')),
+        ({'allow_int': False}, 0.0, Err('Value must be a valid boolean [kind=bool_type')),
+        ({'allow_str': False}, False, False),
+        ({'allow_str': False}, 1, True),
+        ({'allow_str': False}, 0.0, False),
+        ({'allow_str': False}, 'true', Err("Value must be a valid boolean [kind=bool_type, input_value='true'")),
+        ({'allow_int': False, 'allow_str': False}, True, True),
+        ({'allow_int': False, 'allow_str': False}, 1, Err('Value must be a valid boolean [kind=bool_type')),
+        ({'allow_int': False, 'allow_str': False}, 'true', Err('Value must be a valid boolean [kind=bool_type')),
+        ({'allow_int': True, 'allow_str': True}, 1, True),
+        ({'allow_int': True, 'allow_str': True}, 'true', True),
+        ({'allow_int': False}, [], Err('Value must be a valid boolean [kind=bool_type')),
+        ({'allow_int': False}, 'cheese', Err('unable to interpret input [kind=bool_parsing')),
+        ({'allow_str': False}, 2, Err('unable to interpret input [kind=bool_parsing')),
+    ],
+)
+def test_bool_allow(py_and_json: PyAndJson, kwargs, input_value, expected):
+    v = py_and_json({'type': 'bool', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) is expected
+
+
+@pytest.mark.parametrize('kwargs', [{'allow_int': True}, {'allow_str': True}, {'allow_int': False, 'allow_str': False}])
+def test_bool_allow_strict(py_and_json: PyAndJson, kwargs):
+    v = py_and_json({'type': 'bool', 'strict': True, **kwargs})
+    assert v.validate_test(True) is True
+    for input_value in (1, 'true'):
+        with pytest.raises(ValidationError, match=re.escape('Value must be a valid boolean [kind=bool_type')):
+            v.validate_test(input_value)
+
+
+def test_bool_allow_bytes():
+    v = SchemaValidator({'type': 'bool', 'allow_str': False})
+    with pytest.raises(ValidationError, match=re.escape('Value must be a valid boolean [kind=bool_type')):
+        v.validate_python(b'true')
+    assert SchemaValidator({'type': 'bool', 'allow_int': False}).validate_python(b'true') is True
@@ -1,9 +1,10 @@
+import base64
 import re
 from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson
 
@@ -14,10 +15,46 @@ def test_strict_bytes_validator():
     assert v.validate_python(b'foo') == b'foo'
     assert v.validate_json('"foo"') == b'foo'
 
+    assert v.validate_python(bytearray(b'foo')) == b'foo'
+
     with pytest.raises(ValidationError, match='Value must be a valid bytes'):
         v.validate_python('foo')
-    with pytest.raises(ValidationError, match='Value must be a valid bytes'):
+
+
+@pytest.mark.parametrize('schema', [{'type': 'bytes'}, {'type': 'bytes', 'max_length': 10}])
+@pytest.mark.parametrize('strict', [True, False])
+def test_bytearray_copied(schema, strict):
+    v = SchemaValidator({**schema, 'strict': strict})
+    input_value = bytearray(b'foo')
+    output = v.validate_python(input_value)
+    assert output == b'foo'
+    assert type(output) is bytes
+    input_value[0] = ord('b')
+    input_value.extend(b'bar')
+    assert output == b'foo'
+
+
+@pytest.mark.parametrize('max_length', [None, 10])
+def test_strict_bytes_type(max_length):
+    schema = {'type': 'bytes', 'strict': True, 'strict_bytes_type': True}
+    if max_length:
+        schema['max_length'] = max_length
+    v = SchemaValidator(schema)
+    assert v.validate_python(b'foo') == b'foo'
+    assert v.validate_json('"foo"') == b'foo'
+    with pytest.raises(ValidationError) as exc_info:
         v.validate_python(bytearray(b'foo'))
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'bytes_type',
+            'loc': (),
+            'message': 'Value must be a valid bytes, input is of type `bytearray`',
+            'input_value': bytearray(b'foo'),
+            'context': {'input_type': 'bytearray'},
+        }
+    ]
+    # strict_bytes_type only applies in strict mode
+    assert v.validate_python(bytearray(b'foo'), strict=False) == b'foo'
 
 
 def test_lax_bytes_validator():
@@ -39,6 +76,10 @@ def test_lax_bytes_validator():
         ({'min_length': 2}, b'foo', b'foo'),
         ({'min_length': 2}, b'f', Err('Data must have at least 2 bytes')),
         ({'min_length': 1, 'max_length': 6, 'strict': True}, b'bytes?', b'bytes?'),
+        ({'min_length': 1}, b'', Err('Data must have at least 1 bytes')),
+        ({'min_length': 1}, bytearray(), Err('Data must have at least 1 bytes')),
+        ({'min_length': 0}, b'', b''),
+        ({'max_length': 0}, b'', b''),
     ],
 )
 def test_constrained_bytes_python_bytes(opts: Dict[str, Any], input, expected):
@@ -58,6 +99,10 @@ def test_constrained_bytes_python_bytes(opts: Dict[str, Any], input, expected):
         ({'max_length': 5}, 'foobar', Err('Data must have at most 5 bytes')),
         ({'min_length': 2}, 'foo', b'foo'),
         ({'min_length': 2}, 'f', Err('Data must have at least 2 bytes')),
+        ({'min_length': 1}, '', Err('Data must have at least 1 bytes')),
+        # length is measured in bytes, not characters
+        ({'max_length': 2}, '£', b'\xc2\xa3'),
+        ({'max_length': 2}, '££', Err('Data must have at most 2 bytes')),
         ({}, 1, Err('Value must be a valid bytes')),
         ({}, 1.0, Err('Value must be a valid bytes')),
         ({}, [], Err('Value must be a valid bytes')),
@@ -88,10 +133,10 @@ def test_length_ctx():
     assert exc_info.value.errors() == [
         {
             'kind': 'too_short',
-            'loc': [],
+            'loc': (),
             'message': 'Data must have at least 2 bytes',
             'input_value': b'1',
-            'context': {'min_length': 2},
+            'context': {'min_length': 2, 'input_length': 1},
         }
     ]
 
@@ -101,9 +146,51 @@ def test_length_ctx():
     assert exc_info.value.errors() == [
         {
             'kind': 'too_long',
-            'loc': [],
+            'loc': (),
             'message': 'Data must have at most 3 bytes',
             'input_value': b'1234',
-            'context': {'max_length': 3},
+            'context': {'max_length': 3, 'input_length': 4},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (b'', ''),
+        (b'foobar', '666f6f626172'),
+        (b'\x00\xff\x10', '00ff10'),
+        ('foobar', '666f6f626172'),
+        (bytearray(b'\x01\x02'), '0102'),
+    ],
+)
+def test_output_encoding_hex(input_value, expected):
+    v = SchemaValidator({'type': 'bytes', 'output_encoding': 'hex'})
+    output = v.validate_python(input_value)
+    assert output == expected
+    assert bytes.fromhex(output) == (input_value.encode() if isinstance(input_value, str) else input_value)
+
+
+@pytest.mark.parametrize('input_value', [b'', b'f', b'fo', b'foo', b'foobar', bytes(range(256))])
+def test_output_encoding_base64(input_value):
+    v = SchemaValidator({'type': 'bytes', 'output_encoding': 'base64'})
+    output = v.validate_python(input_value)
+    assert output == base64.b64encode(input_value).decode()
+    assert base64.b64decode(output) == input_value
+
+
+def test_output_encoding_json():
+    v = SchemaValidator({'type': 'bytes', 'output_encoding': 'base64'})
+    assert v.validate_json('"foobar"') == 'Zm9vYmFy'
+
+
+def test_output_encoding_constrained():
+    v = SchemaValidator({'type': 'bytes', 'output_encoding': 'hex', 'max_length': 3, 'strict': True})
+    assert v.validate_python(b'abc') == '616263'
+    with pytest.raises(ValidationError, match='Data must have at most 3 bytes'):
+        v.validate_python(b'abcd')
+
+
+def test_output_encoding_invalid():
+    with pytest.raises(SchemaError, match="bytes -> output_encoding\n  Value must be one of: 'hex', 'base64'"):
+        SchemaValidator({'type': 'bytes', 'output_encoding': 'base32'})
@@ -26,10 +26,23 @@ def test_callable():
         v.validate_python(42)
 
     assert exc_info.value.errors() == [
-        {'kind': 'callable_type', 'loc': [], 'message': 'Input must be callable', 'input_value': 42}
+        {
+            'kind': 'callable_type',
+            'loc': (),
+            'message': 'Input must be callable, input is of type `int`',
+            'input_value': 42,
+            'context': {'input_type': 'int'},
+        }
     ]
 
 
+@pytest.mark.parametrize('input_value', ['42', '"func"', 'null', '[]', '{}'])
+def test_callable_json(input_value):
+    v = SchemaValidator({'type': 'callable'})
+    with pytest.raises(ValidationError, match=r'Input must be callable \[kind=callable_type'):
+        v.validate_json(input_value)
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -0,0 +1,104 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_chain_str_int_range(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'chain',
+            'steps': [
+                {'type': 'str', 'strip_whitespace': True},
+                {'type': 'int'},
+                {'type': 'int', 'ge': 0, 'le': 10},
+            ],
+        }
+    )
+    assert v.validate_test(' 5 ') == 5
+    assert v.validate_test('10') == 10
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('42')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'less_than_equal',
+            'loc': (2,),
+            'message': 'Value must be less than or equal to 10',
+            'input_value': 42,
+            'context': {'le': 10, 'value': 42},
+        }
+    ]
+
+
+def test_chain_step_loc():
+    v = SchemaValidator({'type': 'chain', 'steps': [{'type': 'str'}, {'type': 'int'}]})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2])
+    assert exc_info.value.errors()[0]['loc'] == (0,)
+    assert exc_info.value.errors()[0]['kind'] == 'str_type'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('wrong')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': (1,),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'wrong',
+        }
+    ]
+
+
+def test_chain_nested_loc():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'x': {'schema': {'type': 'chain', 'steps': ['str', {'type': 'int', 'multiple_of': 2}]}}},
+        }
+    )
+    assert v.validate_python({'x': '4'}) == {'x': 4}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': '3'})
+    assert exc_info.value.errors()[0]['loc'] == ('x', 1)
+
+
+def test_chain_function():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value * 2
+
+    v = SchemaValidator(
+        {'type': 'chain', 'steps': ['int', {'type': 'function', 'mode': 'plain', 'function': f}, 'float']}
+    )
+    output = v.validate_python('21')
+    assert output == 42.0
+    assert isinstance(output, float)
+    assert calls == [21]
+
+
+def test_chain_single_step():
+    v = SchemaValidator({'type': 'chain', 'steps': ['int']})
+    assert v.validate_python('1') == 1
+
+
+def test_chain_empty():
+    with pytest.raises(SchemaError, match='One or more steps are required for a chain validator'):
+        SchemaValidator({'type': 'chain', 'steps': []})
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'chain', 'steps': ['str', 'int']})
+    assert repr(v).startswith('SchemaValidator(name="chain[str,int]"')
+
+
+def test_chain_error_input():
+    v = SchemaValidator({'type': 'chain', 'steps': ['int', {'type': 'int', 'gt': 100}]})
+    with pytest.raises(ValidationError, match=re.escape('greater than 100 [kind=greater_than, input_value=5')):
+        v.validate_json('"5"')
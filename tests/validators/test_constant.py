@@ -0,0 +1,69 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+@pytest.mark.parametrize('input_value', ['foo', 'bar', 123, None, [1, 2], {'a': 1}])
+def test_constant(py_and_json: PyAndJson, input_value):
+    v = py_and_json({'type': 'constant', 'value': 'foo'})
+    assert v.validate_test(input_value) == 'foo'
+
+
+def test_constant_identity():
+    value = object()
+    v = SchemaValidator({'type': 'constant', 'value': value})
+    assert v.validate_python(42) is value
+
+
+def test_strict(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'constant', 'value': 'foo', 'strict': True})
+    assert v.validate_test('foo') == 'foo'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('bar')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'literal_error',
+            'loc': (),
+            'message': "Value must be 'foo'",
+            'input_value': 'bar',
+            'context': {'expected': "'foo'"},
+        }
+    ]
+
+
+def test_strict_validate_arg():
+    v = SchemaValidator({'type': 'constant', 'value': 1})
+    assert v.validate_python(2) == 1
+    with pytest.raises(ValidationError, match='kind=literal_error'):
+        v.validate_python(2, strict=True)
+    assert v.validate_python(1, strict=True) == 1
+
+
+@pytest.mark.parametrize('value,input_value', [(1, True), (True, 1), (1, 1.0), (0, False), (False, 0)])
+def test_strict_type_mismatch(py_and_json: PyAndJson, value, input_value):
+    v = py_and_json({'type': 'constant', 'value': value, 'strict': True})
+    assert v.validate_test(value) is value
+    with pytest.raises(ValidationError, match='kind=literal_error'):
+        v.validate_test(input_value)
+
+
+def test_typed_dict_field():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'kind': {'schema': {'type': 'constant', 'value': 'cat'}}, 'name': {'schema': 'str'}},
+        }
+    )
+    assert v.validate_python({'kind': 'dog', 'name': 'x'}) == {'kind': 'cat', 'name': 'x'}
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'constant', 'value': 'foo'})
+    assert repr(v).startswith('SchemaValidator(name="constant"')
+
+
+def test_no_value():
+    with pytest.raises(SchemaError, match='constant -> value\n  Field required'):
+        SchemaValidator({'type': 'constant'})
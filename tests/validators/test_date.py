@@ -1,5 +1,5 @@
 import re
-from datetime import date, datetime, time
+from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal
 from typing import Any, Dict
 
@@ -135,7 +135,7 @@ def test_date_strict_json_ctx():
     assert exc_info.value.errors() == [
         {
             'kind': 'date_parsing',
-            'loc': [],
+            'loc': (),
             'message': 'Value must be a valid date in the format YYYY-MM-DD, input is too short',
             'input_value': 'foobar',
             'context': {'error': 'input is too short'},
@@ -151,18 +151,18 @@ def test_date_strict_json_ctx():
         (
             {'le': date(2000, 1, 1)},
             '2000-01-02',
-            Err('Value must be less than or equal to 2000-01-01 [kind=less_than_equal,'),
+            Err('Value must be on or before 2000-01-01 [kind=date_too_late,'),
         ),
         ({'lt': '2000-01-01'}, '1999-12-31', date(1999, 12, 31)),
-        ({'lt': '2000-01-01'}, '2000-01-01', Err('Value must be less than 2000-01-01 [kind=less_than,')),
+        ({'lt': '2000-01-01'}, '2000-01-01', Err('Value must be before 2000-01-01 [kind=date_too_late,')),
         ({'ge': '2000-01-01'}, '2000-01-01', date(2000, 1, 1)),
         (
             {'ge': date(2000, 1, 1)},
             '1999-12-31',
-            Err('Value must be greater than or equal to 2000-01-01 [kind=greater_than_equal,'),
+            Err('Value must be on or after 2000-01-01 [kind=date_too_early,'),
         ),
         ({'gt': date(2000, 1, 1)}, '2000-01-02', date(2000, 1, 2)),
-        ({'gt': date(2000, 1, 1)}, '2000-01-01', Err('Value must be greater than 2000-01-01 [kind=greater_than,')),
+        ({'gt': date(2000, 1, 1)}, '2000-01-01', Err('Value must be after 2000-01-01 [kind=date_too_early,')),
     ],
 )
 def test_date_kwargs(kwargs: Dict[str, Any], input_value, expected):
@@ -175,6 +175,34 @@ def test_date_kwargs(kwargs: Dict[str, Any], input_value, expected):
         assert output == expected
 
 
+def test_date_bounds_json():
+    v = SchemaValidator({'type': 'date', 'ge': '1900-01-01', 'lt': '2000-01-01'})
+    assert v.validate_json('"1900-01-01"') == date(1900, 1, 1)
+    assert v.validate_json('"1999-12-31"') == date(1999, 12, 31)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"1899-12-31"')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'date_too_early',
+            'loc': (),
+            'message': 'Value must be on or after 1900-01-01',
+            'input_value': '1899-12-31',
+            'context': {'bound': '1900-01-01', 'inclusive': True},
+        }
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(date(2000, 1, 1))
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'date_too_late',
+            'loc': (),
+            'message': 'Value must be before 2000-01-01',
+            'input_value': date(2000, 1, 1),
+            'context': {'bound': '2000-01-01', 'inclusive': False},
+        }
+    ]
+
+
 def test_invalid_constraint():
     with pytest.raises(SchemaError, match='date -> gt\n  Value must be a valid date or datetime'):
         SchemaValidator({'type': 'date', 'gt': 'foobar'})
@@ -198,3 +226,97 @@ def test_union():
     v = SchemaValidator({'type': 'union', 'choices': ['date', 'str']})
     assert v.validate_python('2022-01-02') == '2022-01-02'
     assert v.validate_python(date(2022, 1, 2)) == date(2022, 1, 2)
+
+
+@pytest.mark.parametrize(
+    'from_datetime,input_value,expected',
+    [
+        (None, datetime(2022, 6, 8), date(2022, 6, 8)),
+        (None, datetime(2022, 6, 8, 12, 13), Err('must have zero time - e.g. be exact dates [kind=date_from_datetime')),
+        (None, datetime(2022, 6, 8, tzinfo=timezone.utc), Err('kind=date_from_datetime_inexact')),
+        ('exact', datetime(2022, 6, 8, 12, 13), Err('kind=date_from_datetime_inexact')),
+        ('truncate', datetime(2022, 6, 8, 12, 13, 14, 15), date(2022, 6, 8)),
+        ('truncate', '2022-06-08T12:13:14', date(2022, 6, 8)),
+        (
+            'truncate',
+            datetime(2022, 6, 8, 12, 13, tzinfo=timezone.utc),
+            Err(
+                'Datetimes provided to dates must not have a timezone to be truncated '
+                '[kind=date_from_datetime_tz_aware'
+            ),
+        ),
+        ('truncate', '2022-06-08T12:13:14Z', Err('kind=date_from_datetime_tz_aware')),
+        ('truncate_ignore_tz', datetime(2022, 6, 8, 12, 13), date(2022, 6, 8)),
+        ('truncate_ignore_tz', datetime(2022, 6, 8, 23, tzinfo=timezone(timedelta(hours=-5))), date(2022, 6, 8)),
+        ('truncate_ignore_tz', '2022-06-08T12:13:14+08:00', date(2022, 6, 8)),
+    ],
+)
+def test_date_from_datetime(from_datetime, input_value, expected):
+    schema = {'type': 'date'}
+    if from_datetime:
+        schema['from_datetime'] = from_datetime
+    v = SchemaValidator(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is date
+
+
+@pytest.mark.parametrize('from_datetime', ['exact', 'truncate', 'truncate_ignore_tz'])
+def test_date_from_datetime_strict(from_datetime):
+    v = SchemaValidator({'type': 'date', 'from_datetime': from_datetime, 'strict': True})
+    with pytest.raises(ValidationError, match=r'Value must be a valid date \[kind=date_type'):
+        v.validate_python(datetime(2022, 6, 8))
+
+
+def test_date_from_datetime_json():
+    v = SchemaValidator({'type': 'date', 'from_datetime': 'truncate'})
+    assert v.validate_json('"2022-06-08T12:13:14"') == date(2022, 6, 8)
+    with pytest.raises(ValidationError, match='kind=date_from_datetime_tz_aware'):
+        v.validate_json('"2022-06-08T12:13:14+01:00"')
+
+
+def test_date_from_datetime_invalid():
+    with pytest.raises(SchemaError, match="date -> from_datetime\n  Value must be one of: 'exact', 'truncate'"):
+        SchemaValidator({'type': 'date', 'from_datetime': 'wrong'})
+
+
+@pytest.mark.parametrize(
+    'now_op,input_value,expected',
+    [
+        ('past', '2000-01-01', date(2000, 1, 1)),
+        ('past', '2100-01-01', Err('Date must be in the past [kind=date_in_future')),
+        ('future', '2100-01-01', date(2100, 1, 1)),
+        ('future', '2000-01-01', Err('Date must be in the future [kind=date_in_past')),
+    ],
+)
+def test_date_now_op(py_and_json: PyAndJson, now_op, input_value, expected):
+    v = py_and_json({'type': 'date', 'now_op': now_op})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_date_now_op_today():
+    today = datetime.now(timezone.utc).date()
+    for now_op in ('past', 'future'):
+        v = SchemaValidator({'type': 'date', 'now_op': now_op, 'now_utc_offset': 0})
+        with pytest.raises(ValidationError):
+            v.validate_python(today)
+    v = SchemaValidator({'type': 'date', 'now_op': 'past', 'now_utc_offset': 0})
+    assert v.validate_python(today - timedelta(days=1)) == today - timedelta(days=1)
+
+
+def test_date_now_injected():
+    v = SchemaValidator({'type': 'date', 'now_op': 'past', 'now_utc_offset': 0})
+    now = datetime(2000, 1, 2, 12, tzinfo=timezone.utc)
+    for _ in range(2):
+        assert v.validate_python('2000-01-01', now=now) == date(2000, 1, 1)
+        with pytest.raises(ValidationError, match='kind=date_in_future'):
+            v.validate_python('2000-01-02', now=now)
+    assert v.validate_json('"2000-01-01"', now=now.timestamp()) == date(2000, 1, 1)
@@ -87,7 +87,7 @@ def test_keep_tz_bound():
     assert output.tzinfo.dst(datetime(2022, 6, 1)) == timedelta(hours=1)
     assert output.tzinfo.dst(datetime(2022, 1, 1)) == timedelta(0)
 
-    with pytest.raises(ValidationError, match=r'Value must be greater than 2022-01-01T00:00:00 \[kind=greater_than'):
+    with pytest.raises(ValidationError, match=r'Value must be after 2022-01-01T00:00:00 \[kind=datetime_too_early'):
         v.validate_python(tz.localize(datetime(2021, 6, 14)))
 
 
@@ -104,8 +104,16 @@ def test_keep_tz_bound():
             '2022-06-08T12:13:14+23:59',
             datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=23, minutes=59))),
         ),
+        ('2022-06-08T12:13:14.123456', datetime(2022, 6, 8, 12, 13, 14, 123456)),
+        (
+            '2022-06-08T12:13:14.5-02:00',
+            datetime(2022, 6, 8, 12, 13, 14, 500000, tzinfo=timezone(timedelta(hours=-2))),
+        ),
         (1655205632, datetime(2022, 6, 14, 11, 20, 32)),
         (1655205632.331557, datetime(2022, 6, 14, 11, 20, 32, microsecond=331557)),
+        (-1, datetime(1969, 12, 31, 23, 59, 59)),
+        (-1.5, datetime(1969, 12, 31, 23, 59, 58, 500000)),
+        (-86400, datetime(1969, 12, 31)),
         (
             '2022-06-08T12:13:14+24:00',
             Err('Value must be a valid datetime, timezone offset must be less than 24 hours [kind=datetime_parsing,'),
@@ -130,6 +138,12 @@ def test_datetime_json(py_and_json: PyAndJson, input_value, expected):
     [
         ('2022-06-08T12:13:14', datetime(2022, 6, 8, 12, 13, 14)),
         ('2022-06-08T12:13:14Z', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)),
+        (
+            '2022-06-08T12:13:14.123+01:00',
+            datetime(2022, 6, 8, 12, 13, 14, 123000, tzinfo=timezone(timedelta(hours=1))),
+        ),
+        # timestamps as strings are not allowed, only RFC 3339 style strings
+        ('1655205632', Err('Value must be a valid datetime, invalid date separator, expected `-`')),
         (123, Err('Value must be a valid datetime [kind=datetime_type')),
         (123.4, Err('Value must be a valid datetime [kind=datetime_type')),
         (True, Err('Value must be a valid datetime [kind=datetime_type')),
@@ -177,7 +191,7 @@ def test_tz_comparison():
     assert v == datetime(2022, 1, 1, 16, 0, 0, tzinfo=timezone(timedelta(hours=1)))
 
     # but not gt
-    with pytest.raises(ValidationError, match=r'Value must be greater than 2022-01-01T15:00:00Z \[kind=greater_than'):
+    with pytest.raises(ValidationError, match=r'Value must be after 2022-01-01T15:00:00Z \[kind=datetime_too_early'):
         SchemaValidator({'type': 'datetime', 'gt': uk_3pm}).validate_python('2022-01-01T16:00:00+01:00')
 
 
@@ -222,7 +236,7 @@ def test_custom_invalid_tz():
     assert excinfo.value.errors() == [
         {
             'kind': 'datetime_object_invalid',
-            'loc': [],
+            'loc': (),
             'message': f'Invalid datetime object, got {error_message}',
             'input_value': dt,
             'context': {'error': error_message},
@@ -257,5 +271,138 @@ def test_union():
 
 
 def test_invalid_constraint():
-    with pytest.raises(SchemaError, match='datetime -> gt\n  Value must be a valid datetime'):
+    with pytest.raises(SchemaError, match='datetime -> gt -> datetime\n  Value must be a valid datetime'):
+        SchemaValidator({'type': 'datetime', 'gt': [1]})
+    with pytest.raises(SchemaError, match='Invalid value for "gt": input is too short'):
         SchemaValidator({'type': 'datetime', 'gt': 'foobar'})
+
+
+@pytest.mark.parametrize(
+    'constraint,bound,input_value,expected',
+    [
+        ('gt', '2022-01-01T12:00:00Z', '2022-01-01T12:00:01Z', datetime(2022, 1, 1, 12, 0, 1, tzinfo=timezone.utc)),
+        ('gt', '2022-01-01T12:00:00Z', '2022-01-01T12:00:00Z', Err('Value must be after 2022-01-01T12:00:00Z')),
+        ('ge', '2022-01-01T12:00:00Z', '2022-01-01T12:00:00Z', datetime(2022, 1, 1, 12, tzinfo=timezone.utc)),
+        ('ge', '2022-01-01T12:00:00Z', '2022-01-01T11:00:00Z', Err('Value must be on or after 2022-01-01T12:00:00Z')),
+        ('lt', '2022-01-01T12:00:00Z', '2022-01-01T12:00:00Z', Err('Value must be before 2022-01-01T12:00:00Z')),
+        ('le', '2022-01-01T12:00:00Z', '2022-01-01T12:00:01Z', Err('Value must be on or before 2022-01-01T12:00:00Z')),
+        # the same instant in a different timezone
+        (
+            'le',
+            '2022-01-01T12:00:00Z',
+            '2022-01-01T14:00:00+02:00',
+            datetime(2022, 1, 1, 14, tzinfo=timezone(timedelta(hours=2))),
+        ),
+        ('lt', '2022-01-01T12:00:00Z', '2022-01-01T14:00:00+02:00', Err('Value must be before 2022-01-01T12:00:00Z')),
+        ('gt', '2022-01-01T12:00:00+01:00', '2022-01-01T11:30:00Z', datetime(2022, 1, 1, 11, 30, tzinfo=timezone.utc)),
+        ('gt', '2022-01-01T12:00:00+01:00', '2022-01-01T10:30:00Z', Err('Value must be after 2022-01-01T12:00:00+01')),
+        # naive datetimes are treated as UTC when compared with timezone-aware datetimes
+        ('gt', '2022-01-01T12:00:00', '2022-01-01T12:30:00+05:00', Err('Value must be after 2022-01-01T12:00:00')),
+        (
+            'gt',
+            '2022-01-01T12:00:00',
+            '2022-01-01T12:30:00-05:00',
+            datetime(2022, 1, 1, 12, 30, tzinfo=timezone(timedelta(hours=-5))),
+        ),
+        ('gt', '2022-01-01T12:00:00+01:00', '2022-01-01T11:30:00', datetime(2022, 1, 1, 11, 30)),
+        ('gt', '2022-01-01T12:00:00+01:00', '2022-01-01T10:30:00', Err('Value must be after 2022-01-01T12:00:00+01')),
+        ('le', '2022-01-01T12:00:00+01:00', '2022-01-01T11:00:00', datetime(2022, 1, 1, 11)),
+        ('lt', '2022-01-01T12:00:00+01:00', '2022-01-01T11:00:00', Err('Value must be before 2022-01-01T12:00:00+01')),
+        ('gt', datetime(2022, 1, 1, 12, tzinfo=timezone.utc), '2022-01-01T12:00:00Z', Err('Value must be after')),
+    ],
+)
+def test_datetime_bounds(constraint, bound, input_value, expected):
+    v = SchemaValidator({'type': 'datetime', constraint: bound})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_python(input_value)
+        errors = exc_info.value.errors()
+        assert len(errors) == 1
+        assert errors[0]['kind'] == ('datetime_too_early' if constraint in ('gt', 'ge') else 'datetime_too_late')
+        assert errors[0]['context']['inclusive'] == (constraint in ('ge', 'le'))
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'now_op,input_value,expected',
+    [
+        ('past', '2000-01-01T00:00:00', datetime(2000, 1, 1)),
+        ('past', '2000-01-01T00:00:00Z', datetime(2000, 1, 1, tzinfo=timezone.utc)),
+        ('past', '2100-01-01T00:00:00', Err('Datetime must be in the past [kind=datetime_in_future')),
+        ('future', '2100-01-01T00:00:00Z', datetime(2100, 1, 1, tzinfo=timezone.utc)),
+        ('future', '2000-01-01T00:00:00', Err('Datetime must be in the future [kind=datetime_in_past')),
+    ],
+)
+def test_datetime_now_op(py_and_json: PyAndJson, now_op, input_value, expected):
+    v = py_and_json({'type': 'datetime', 'now_op': now_op})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_datetime_now_op_aware():
+    v = SchemaValidator({'type': 'datetime', 'now_op': 'past'})
+    now = datetime.now(timezone.utc)
+    assert v.validate_python(now - timedelta(seconds=10)) == now - timedelta(seconds=10)
+    with pytest.raises(ValidationError, match='kind=datetime_in_future'):
+        v.validate_python(now + timedelta(seconds=10))
+    # the same instant in a different timezone
+    tz = timezone(timedelta(hours=-5))
+    assert v.validate_python((now - timedelta(seconds=10)).astimezone(tz)) == now - timedelta(seconds=10)
+    with pytest.raises(ValidationError, match='kind=datetime_in_future'):
+        v.validate_python((now + timedelta(seconds=10)).astimezone(tz))
+
+
+def test_datetime_now_op_naive_utc_offset():
+    # naive datetimes are interpreted as being in the timezone given by now_utc_offset
+    naive_utc_now = datetime.now(timezone.utc).replace(tzinfo=None)
+    input_value = naive_utc_now + timedelta(hours=1)
+
+    v = SchemaValidator({'type': 'datetime', 'now_op': 'past', 'now_utc_offset': 0})
+    with pytest.raises(ValidationError, match='kind=datetime_in_future'):
+        v.validate_python(input_value)
+
+    v = SchemaValidator({'type': 'datetime', 'now_op': 'past', 'now_utc_offset': 2 * 3600})
+    assert v.validate_python(input_value) == input_value
+
+
+def test_now_op_invalid():
+    with pytest.raises(SchemaError, match="datetime -> now_op\n  Value must be one of: 'past', 'future'"):
+        SchemaValidator({'type': 'datetime', 'now_op': 'present'})
+
+
+def test_now_injected():
+    v = SchemaValidator({'type': 'datetime', 'now_op': 'past', 'now_utc_offset': 0})
+    now = datetime(2000, 1, 1, 12, tzinfo=timezone.utc)
+    for _ in range(2):
+        assert v.validate_python('2000-01-01T11:59:59', now=now) == datetime(2000, 1, 1, 11, 59, 59)
+        with pytest.raises(ValidationError, match='kind=datetime_in_future'):
+            v.validate_python('2000-01-01T12:00:01', now=now)
+
+    # a unix timestamp works too, in JSON and python
+    assert v.validate_json('"2000-01-01T11:59:59Z"', now=now.timestamp()) == datetime(
+        2000, 1, 1, 11, 59, 59, tzinfo=timezone.utc
+    )
+    assert v.isinstance_json('"2000-01-01T12:00:01Z"', now=now.timestamp()) is False
+    assert v.isinstance_python('2000-01-01T11:59:59', now=now.timestamp()) is True
+
+    v = SchemaValidator({'type': 'datetime', 'now_op': 'future', 'now_utc_offset': 0})
+    assert v.validate_python('2000-01-01T12:00:01', now=now) == datetime(2000, 1, 1, 12, 0, 1)
+    with pytest.raises(ValidationError, match='kind=datetime_in_past'):
+        v.validate_python('2000-01-01T11:59:59', now=now)
+    # without `now` the system clock is used
+    with pytest.raises(ValidationError, match='kind=datetime_in_past'):
+        v.validate_python('2000-01-01T12:00:01')
+
+
+def test_now_injected_invalid():
+    v = SchemaValidator({'type': 'datetime', 'now_op': 'past'})
+    with pytest.raises(ValueError, match="'now' must not be before 1970-01-01"):
+        v.validate_python('2000-01-01T00:00:00', now=-1)
+    with pytest.raises(ValueError, match="'now' must be a finite timestamp"):
+        v.validate_python('2000-01-01T00:00:00', now=float('nan'))
+    with pytest.raises(TypeError):
+        v.validate_python('2000-01-01T00:00:00', now='tomorrow')
@@ -0,0 +1,220 @@
+import re
+from decimal import Decimal
+from typing import Any, Dict
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson, plain_repr
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (0, Decimal(0)),
+        (1, Decimal(1)),
+        (42, Decimal(42)),
+        ('42', Decimal(42)),
+        ('42.123', Decimal('42.123')),
+        (42.0, Decimal('42.0')),
+        (42.5, Decimal('42.5')),
+        (0.1, Decimal('0.1')),
+        pytest.param(
+            'wrong',
+            Err('Value must be a valid decimal, unable to parse input as a decimal [kind=decimal_parsing'),
+            id='string',
+        ),
+        pytest.param(None, Err('Value must be a valid decimal [kind=decimal_type'), id='none'),
+        pytest.param(True, Err('Value must be a valid decimal [kind=decimal_type'), id='bool'),
+        pytest.param([1, 2], Err('Value must be a valid decimal [kind=decimal_type'), id='list'),
+    ],
+)
+def test_decimal(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'decimal'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, Decimal)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Decimal('1.23'), Decimal('1.23')),
+        (b'1.23', Decimal('1.23')),
+        pytest.param(
+            b'\x81',
+            Err('Value must be a valid decimal, unable to parse input as a decimal [kind=decimal_parsing'),
+            id='invalid-unicode',
+        ),
+    ],
+)
+def test_decimal_python(input_value, expected):
+    v = SchemaValidator({'type': 'decimal'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, Decimal)
+
+
+def test_decimal_identity():
+    v = SchemaValidator({'type': 'decimal'})
+    d = Decimal('1.2345')
+    assert v.validate_python(d) is d
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (42, Decimal(42)),
+        ('42.5', Decimal('42.5')),
+        pytest.param(
+            42.5,
+            Err('Value must be a valid decimal [kind=decimal_type, input_value=42.5, input_type=float]'),
+            id='float',
+        ),
+        pytest.param(
+            True, Err('Value must be a valid decimal [kind=decimal_type, input_value=True, input_type=bool]'), id='bool'
+        ),
+    ],
+)
+def test_decimal_strict(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'decimal', 'strict': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, Decimal)
+
+
+def test_decimal_strict_python():
+    v = SchemaValidator({'type': 'decimal', 'strict': True})
+    assert v.validate_python(Decimal('1.5')) == Decimal('1.5')
+    with pytest.raises(ValidationError, match=re.escape('Value must be a valid decimal [kind=decimal_type')):
+        v.validate_python(b'1.5')
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({'max_digits': 4}, '12.34', Decimal('12.34')),
+        ({'max_digits': 4}, '123.45', Err('Value must have no more than 4 digits in total [kind=decimal_max_digits')),
+        ({'max_digits': 3}, '0.001', Decimal('0.001')),
+        ({'max_digits': 2}, '0.001', Err('Value must have no more than 2 digits in total')),
+        ({'max_digits': 3}, '100', Decimal('100')),
+        ({'max_digits': 2}, '100', Err('Value must have no more than 2 digits in total')),
+        ({'max_digits': 2}, '1E+2', Err('Value must have no more than 2 digits in total')),
+        ({'decimal_places': 2}, '1.23', Decimal('1.23')),
+        ({'decimal_places': 2}, '1.234', Err('Value must have no more than 2 decimal places [kind=decimal_max_places')),
+        ({'decimal_places': 2}, 12345, Decimal(12345)),
+        ({'max_digits': 5, 'decimal_places': 2}, '123.45', Decimal('123.45')),
+        ({'max_digits': 5, 'decimal_places': 2}, '1234.5', Decimal('1234.5')),
+        ({'max_digits': 5, 'decimal_places': 2}, '12.345', Err('Value must have no more than 2 decimal places')),
+        ({'max_digits': 5}, 'NaN', Err('Value must be a finite decimal [kind=decimal_finite')),
+        ({'max_digits': 5}, 'Infinity', Err('Value must be a finite decimal [kind=decimal_finite')),
+        ({}, 'NaN', Err('Value must be a finite decimal [kind=decimal_finite')),
+        ({}, '-Infinity', Err('Value must be a finite decimal [kind=decimal_finite')),
+        ({'allow_inf_nan': True}, 'Infinity', Decimal('Infinity')),
+        ({'allow_inf_nan': True}, '-Infinity', Decimal('-Infinity')),
+        ({'max_digits': 5, 'allow_inf_nan': True}, 'Infinity', Decimal('Infinity')),
+    ],
+    ids=repr,
+)
+def test_decimal_kwargs(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
+    v = py_and_json({'type': 'decimal', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+
+        errors = exc_info.value.errors()
+        assert len(errors) == 1
+        if 'context' in errors[0]:
+            limits = {k: v for k, v in errors[0]['context'].items() if k not in ('digits', 'decimals')}
+            assert set(limits.items()) <= set(kwargs.items())
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert isinstance(output, Decimal)
+
+
+def test_decimal_repr():
+    v = SchemaValidator({'type': 'decimal'})
+    assert plain_repr(v) == (
+        'SchemaValidator(name="decimal",validator=Decimal(DecimalValidator{strict:false,allow_inf_nan:false}))'
+    )
+    v = SchemaValidator({'type': 'decimal', 'strict': True})
+    assert plain_repr(v) == (
+        'SchemaValidator(name="decimal",validator=Decimal(DecimalValidator{strict:true,allow_inf_nan:false}))'
+    )
+    v = SchemaValidator({'type': 'decimal', 'max_digits': 7})
+    assert plain_repr(v).startswith('SchemaValidator(name="constrained-decimal",validator=ConstrainedDecimal(')
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        '12345678901234567890.0001',
+        '0.1',
+        '1.5',
+        '1.50',
+        '1.0',
+        '1e5',
+        '2.5E+3',
+        '-0.000000000000000000001',
+        '1e-30',
+        '123456789012345678901234567890',
+    ],
+)
+def test_decimal_json_exact(input_value):
+    v = SchemaValidator({'type': 'decimal'})
+    output = v.validate_json(input_value)
+    assert isinstance(output, Decimal)
+    assert output == Decimal(input_value)
+    assert str(output) == str(Decimal(input_value))
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected_context',
+    [
+        ({'max_digits': 4}, '123.45', {'max_digits': 4, 'digits': 5}),
+        ({'max_digits': 2}, '0.001', {'max_digits': 2, 'digits': 3}),
+        ({'decimal_places': 2}, '1.234', {'decimal_places': 2, 'decimals': 3}),
+    ],
+)
+def test_decimal_constraint_ctx(kwargs: Dict[str, Any], input_value, expected_context):
+    v = SchemaValidator({'type': 'decimal', **kwargs})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors()[0]['context'] == expected_context
+
+
+def test_decimal_json_max_digits():
+    v = SchemaValidator({'type': 'decimal', 'max_digits': 20})
+    assert v.validate_json('1234567890123456.0001') == Decimal('1234567890123456.0001')
+    with pytest.raises(ValidationError, match='Value must have no more than 20 digits in total'):
+        v.validate_json('12345678901234567890.0001')
+
+
+def test_float_json_big_number():
+    v = SchemaValidator({'type': 'float'})
+    assert v.validate_json('12345678901234567890.0001') == 12345678901234567890.0001
+    assert v.validate_json('123456789012345678901234567890') == 1.2345678901234568e29
+
+
+def test_decimal_allow_inf_nan_config():
+    v = SchemaValidator({'type': 'decimal'}, {'decimal_allow_inf_nan': True})
+    assert v.validate_python('NaN').is_nan()
+    assert v.validate_python(Decimal('-Infinity')) == Decimal('-Infinity')
+
+    v = SchemaValidator({'type': 'decimal', 'allow_inf_nan': False}, {'decimal_allow_inf_nan': True})
+    with pytest.raises(ValidationError, match='kind=decimal_finite'):
+        v.validate_python('NaN')
@@ -14,6 +14,7 @@ from ..conftest import Err, PyAndJson
 def test_dict(py_and_json: PyAndJson):
     v = py_and_json({'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}})
     assert v.validate_test({'1': 2, '3': 4}) == {1: 2, 3: 4}
+    assert v.validate_test({' 1 ': 2}) == {1: 2}
     v = py_and_json({'type': 'dict', 'strict': True, 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}})
     assert v.validate_test({'1': 2, '3': 4}) == {1: 2, 3: 4}
     assert v.validate_test({}) == {}
@@ -54,7 +55,7 @@ def test_dict_value_error(py_and_json: PyAndJson):
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['b'],
+            'loc': ('b',),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         }
@@ -68,7 +69,7 @@ def test_dict_error_key_int():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': [3],
+            'loc': (3,),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         }
@@ -82,7 +83,7 @@ def test_dict_error_key_other():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['(1, 2)'],
+            'loc': ('(1, 2)',),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         }
@@ -118,6 +119,37 @@ def test_mapping():
         v.validate_python(MyMapping({'1': 2, 3: '4'}))
 
 
+def test_mapping_keys_getitem():
+    class KeysMapping:
+        """
+        Not a `Mapping` and no `items()`, just the `keys()` and `__getitem__` that `dict(obj)` requires
+        """
+
+        def __init__(self, d):
+            self._d = d
+
+        def keys(self):
+            return self._d.keys()
+
+        def __getitem__(self, key):
+            return self._d[key]
+
+    assert dict(KeysMapping({'a': 1})) == {'a': 1}
+
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}})
+    assert v.validate_python(KeysMapping({'1': 2, 3: '4'})) == {1: 2, 3: 4}
+
+
+def test_mapping_getitem_only():
+    class GetItemOnly:
+        def __getitem__(self, key):
+            return key
+
+    v = SchemaValidator({'type': 'dict'})
+    with pytest.raises(ValidationError, match=re.escape('Value must be a valid dictionary [kind=dict_type')):
+        v.validate_python(GetItemOnly())
+
+
 def test_key_error():
     v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}})
     assert v.validate_python({'1': True}) == {1: 1}
@@ -126,7 +158,7 @@ def test_key_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['x', '[key]'],
+            'loc': ('x', '[key]'),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'x',
         }
@@ -151,7 +183,7 @@ def test_mapping_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'dict_from_mapping',
-            'loc': [],
+            'loc': (),
             'message': 'Unable to convert mapping to a dictionary, error: RuntimeError: intentional error',
             'input_value': HasRepr(IsStr(regex='.+BadMapping object at.+')),
             'context': {'error': 'RuntimeError: intentional error'},
@@ -180,7 +212,7 @@ def test_mapping_error_yield_1():
     assert exc_info.value.errors() == [
         {
             'kind': 'dict_from_mapping',
-            'loc': [],
+            'loc': (),
             'message': (
                 'Unable to convert mapping to a dictionary, error: '
                 'ValueError: Expected tuple of length 2, but got tuple of length 1.'
@@ -216,3 +248,92 @@ def test_dict_length_constraints(kwargs: Dict[str, Any], input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+def test_dict_key_coercion_value_error(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'1': 'x'})
+    # the key is coerced, so only the value fails, located by the input key
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ('1',),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+
+def test_dict_key_and_value_errors(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test({'a': 'b', '2': 3, '4': 'c'})
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('a', '[key]')),
+        ('int_parsing', ('a',)),
+        ('int_parsing', ('4',)),
+    ]
+
+
+def test_dict_length_constraints_json():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int', 'min_items': 1, 'max_items': 2})
+    assert v.validate_json('{"1": 2}') == {1: 2}
+    with pytest.raises(ValidationError, match='Input must have at least 1 item') as exc_info:
+        v.validate_json('{}')
+    assert exc_info.value.errors()[0]['context'] == {'min_length': 1, 'input_length': 0}
+    with pytest.raises(ValidationError, match='Input must have at most 2 items') as exc_info:
+        v.validate_json('{"1": 1, "2": 2, "3": 3}')
+    assert exc_info.value.errors()[0]['context'] == {'max_length': 2, 'input_length': 3}
+
+
+def test_json_int_keys():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'str'})
+    output = v.validate_json('{"1": "x", "-2": "y", " 3 ": "z"}')
+    assert output == {1: 'x', -2: 'y', 3: 'z'}
+    assert all(type(k) is int for k in output)
+
+
+@pytest.mark.parametrize('strict_schema', [True, False])
+def test_json_int_keys_strict(strict_schema):
+    if strict_schema:
+        v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'str'}, {'strict': True})
+        validate_json = v.validate_json
+    else:
+        v = SchemaValidator({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'str'})
+
+        def validate_json(input_value):
+            return v.validate_json(input_value, strict=True)
+
+    output = validate_json('{"1": "x", "-2": "y"}')
+    assert output == {1: 'x', -2: 'y'}
+    assert all(type(k) is int for k in output)
+
+    with pytest.raises(ValidationError) as exc_info:
+        validate_json('{"a": "x", " 3 ": "y", "4.0": "z", "+5": "w"}')
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [
+        ('int_parsing', ('a', '[key]')),
+        ('int_parsing', (' 3 ', '[key]')),
+        ('int_parsing', ('4.0', '[key]')),
+        ('int_parsing', ('+5', '[key]')),
+    ]
+
+
+@pytest.mark.parametrize(
+    'keys_schema,kind',
+    [
+        ({'type': 'literal', 'expected': [1, 2]}, 'int_type'),
+        ({'type': 'literal', 'expected': [1, 'a']}, 'literal_error'),
+    ],
+)
+def test_json_int_keys_literal(keys_schema, kind):
+    v = SchemaValidator({'type': 'dict', 'keys_schema': keys_schema, 'values_schema': 'int'})
+    for strict in (False, True):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_json('{"1": 1}', strict=strict)
+        assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [(kind, ('1', '[key]'))]
+
+
+def test_json_int_keys_bool():
+    v = SchemaValidator({'type': 'dict', 'keys_schema': {'type': 'bool', 'allow_int': False}, 'values_schema': 'int'})
+    assert v.validate_json('{"1": 1, "false": 2}') == {True: 1, False: 2}
@@ -0,0 +1,111 @@
+import re
+from enum import Enum, IntEnum
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+class Color(str, Enum):
+    RED = 'red'
+    GREEN = 'green'
+
+
+class Size(IntEnum):
+    SMALL = 1
+    LARGE = 2
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('red', Color.RED),
+        ('green', Color.GREEN),
+        (
+            'blue',
+            Err(
+                "Value must be a member of the enum, one of: 'red', 'green' "
+                "[kind=enum_member, input_value='blue', input_type=str]"
+            ),
+        ),
+        (1, Err('Value must be a member of the enum, one of: ')),
+    ],
+)
+def test_str_enum(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'enum', 'cls': Color})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output is expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (1, Size.SMALL),
+        (2, Size.LARGE),
+        (3, Err('Value must be a member of the enum, one of: 1, 2 [kind=enum_member, input_value=3, input_type=int]')),
+        ('1', Err('Value must be a member of the enum, one of: 1, 2')),
+        # only inputs of the same type as the member values match, not any which are equal
+        (True, Err('Value must be a member of the enum, one of: 1, 2 [kind=enum_member, input_value=True')),
+        (1.0, Err('Value must be a member of the enum, one of: 1, 2 [kind=enum_member, input_value=1.0')),
+    ],
+)
+def test_int_enum(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'enum', 'cls': Size})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output is expected
+
+
+def test_member_instance():
+    v = SchemaValidator({'type': 'enum', 'cls': Size, 'strict': True})
+    assert v.validate_python(Size.LARGE) is Size.LARGE
+    assert v.validate_python(Size.SMALL, strict=False) is Size.SMALL
+
+
+def test_strict_python():
+    v = SchemaValidator({'type': 'enum', 'cls': Color, 'strict': True})
+    assert v.validate_python(Color.RED) is Color.RED
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('red')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'enum_member',
+            'loc': (),
+            'message': "Value must be a member of the enum, one of: 'red', 'green'",
+            'input_value': 'red',
+            'context': {'expected': "'red', 'green'"},
+        }
+    ]
+
+    v = SchemaValidator({'type': 'enum', 'cls': Color})
+    with pytest.raises(ValidationError, match='kind=enum_member'):
+        v.validate_python('red', strict=True)
+
+
+def test_strict_json():
+    v = SchemaValidator({'type': 'enum', 'cls': Size, 'strict': True})
+    assert v.validate_json('2') is Size.LARGE
+    with pytest.raises(ValidationError, match='kind=enum_member'):
+        v.validate_json('3')
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'enum', 'cls': Color})
+    assert repr(v).startswith('SchemaValidator(name="enum[Color]"')
+
+
+def test_empty_enum():
+    class Empty(Enum):
+        pass
+
+    with pytest.raises(SchemaError, match='enum class must have at least one member'):
+        SchemaValidator({'type': 'enum', 'cls': Empty})
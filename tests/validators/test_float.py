@@ -18,12 +18,15 @@ from ..conftest import Err, PyAndJson, plain_repr
         (42, 42),
         ('42', 42),
         ('42.123', 42.123),
+        (' 42.5 ', 42.5),
+        ('\t-3\n', -3),
         (42.0, 42),
         (42.5, 42.5),
         (1e10, 1e10),
         (True, 1),
         (False, 0),
         ('wrong', Err('Value must be a valid number, unable to parse string as an number [kind=float_parsing')),
+        ('4 2', Err('Value must be a valid number, unable to parse string as an number [kind=float_parsing')),
         ([1, 2], Err('Value must be a valid number [kind=float_type, input_value=[1, 2], input_type=list]')),
     ],
 )
@@ -62,6 +65,20 @@ def test_float_strict(py_and_json: PyAndJson, input_value, expected):
         assert isinstance(output, float)
 
 
+@pytest.mark.parametrize('schema_extra', [{}, {'gt': 0}], ids=['float', 'constrained-float'])
+def test_float_strict_reject_int(py_and_json: PyAndJson, schema_extra):
+    v = py_and_json({'type': 'float', 'strict': True, 'strict_float_reject_int': True, **schema_extra})
+    assert v.validate_test(5.0) == 5.0
+    with pytest.raises(ValidationError, match=re.escape('[kind=float_type, input_value=5,')):
+        v.validate_test(5)
+    # only applies in strict mode
+    assert v.validate_test(5, strict=False) == 5.0
+
+    v = py_and_json({'type': 'float', 'strict': True, **schema_extra})
+    assert v.validate_test(5) == 5.0
+    assert v.validate_test(5.0) == 5.0
+
+
 @pytest.mark.parametrize(
     'kwargs,input_value,expected',
     [
@@ -96,7 +113,7 @@ def test_float_kwargs(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_valu
         errors = exc_info.value.errors()
         assert len(errors) == 1
         if 'context' in errors[0]:
-            assert errors[0]['context'] == kwargs
+            assert errors[0]['context'] == {**kwargs, 'value': input_value}
     else:
         output = v.validate_test(input_value)
         assert output == expected
@@ -111,14 +128,21 @@ def test_union_float(py_and_json: PyAndJson):
     assert v.validate_test(5) == 5
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test('5')
+    input_type = 'str' if v.validator_type == 'python' else 'string'
     assert exc_info.value.errors() == [
-        {'kind': 'float_type', 'loc': ['float'], 'message': 'Value must be a valid number', 'input_value': '5'},
+        {
+            'kind': 'float_type',
+            'loc': ('float',),
+            'message': f'Value must be a valid number, input is of type `{input_type}`',
+            'input_value': '5',
+            'context': {'input_type': input_type},
+        },
         {
             'kind': 'multiple_of',
-            'loc': ['constrained-float'],
+            'loc': ('constrained-float',),
             'message': 'Value must be a multiple of 7',
             'input_value': '5',
-            'context': {'multiple_of': 7.0},
+            'context': {'multiple_of': 7.0, 'value': 5.0},
         },
     ]
 
@@ -132,7 +156,7 @@ def test_union_float_simple(py_and_json: PyAndJson):
     assert exc_info.value.errors() == [
         {
             'kind': 'float_parsing',
-            'loc': ['float'],
+            'loc': ('float',),
             'message': 'Value must be a valid number, unable to parse string as an number',
             'input_value': 'xxx',
         }
@@ -141,9 +165,13 @@ def test_union_float_simple(py_and_json: PyAndJson):
 
 def test_float_repr():
     v = SchemaValidator({'type': 'float'})
-    assert plain_repr(v) == 'SchemaValidator(name="float",validator=Float(FloatValidator{strict:false}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="float",validator=Float(FloatValidator{strict:false,strict_float_reject_int:false}))'
+    )
     v = SchemaValidator({'type': 'float', 'strict': True})
-    assert plain_repr(v) == 'SchemaValidator(name="float",validator=Float(FloatValidator{strict:true}))'
+    assert plain_repr(v) == (
+        'SchemaValidator(name="float",validator=Float(FloatValidator{strict:true,strict_float_reject_int:false}))'
+    )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(name="constrained-float",validator=ConstrainedFloat(')
 
@@ -168,9 +196,84 @@ def test_float_nan(py_and_json: PyAndJson):
     assert math.isnan(r)
 
 
+@pytest.mark.parametrize(
+    'input_value,value',
+    [
+        ('nan', 'nan'),
+        ('inf', 'inf'),
+        ('-inf', '-inf'),
+        ('infinity', 'inf'),
+        ('1' * 800, 'inf'),
+        ('-' + '1' * 800, '-inf'),
+    ],
+)
+def test_float_allow_inf_nan_false(py_and_json: PyAndJson, input_value, value):
+    v = py_and_json({'type': 'float', 'allow_inf_nan': False})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(input_value)
+    assert exc_info.value.errors(max_input_length=None) == [
+        {
+            'kind': 'float_finite_number',
+            'loc': (),
+            'message': f'Value must be a finite number, got {value}',
+            'input_value': input_value,
+            'context': {'value': value},
+        }
+    ]
+    assert v.validate_test('1.5') == 1.5
+
+
+@pytest.mark.parametrize('input_value,value', [(math.nan, 'nan'), (math.inf, 'inf'), (-math.inf, '-inf')])
+def test_float_allow_inf_nan_python(input_value, value):
+    v = SchemaValidator({'type': 'float', 'allow_inf_nan': False, 'strict': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors()[0]['context'] == {'value': value}
+
+    v = SchemaValidator({'type': 'float'}, {'float_allow_inf_nan': False})
+    with pytest.raises(ValidationError, match=rf'got {value} \[kind=float_finite_number'):
+        v.validate_python(input_value)
+
+    v = SchemaValidator({'type': 'float', 'allow_inf_nan': True}, {'float_allow_inf_nan': False})
+    # compare reprs since nan != nan
+    assert repr(v.validate_python(input_value)) == repr(input_value)
+
+
 def test_float_key(py_and_json: PyAndJson):
     v = py_and_json({'type': 'dict', 'keys_schema': 'float', 'values_schema': 'int'})
     assert v.validate_test({'1': 1, '2': 2}) == {1: 1, 2: 2}
     assert v.validate_test({'1.5': 1, '2.4': 2}) == {1.5: 1, 2.4: 2}
     with pytest.raises(ValidationError, match='Value must be a valid number'):
         v.validate_test({'1.5': 1, '2.5': 2}, strict=True)
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    ['1_000', '1_000.000_1', '1_0e1_0', '-1_0.5', '_1', '1_', '1__0', '1_.0', '1._0', '1_e5', '1e_5', '1.5_'],
+)
+def test_float_underscores(py_and_json: PyAndJson, input_value):
+    v = py_and_json({'type': 'float'})
+    try:
+        expected = float(input_value)
+    except ValueError:
+        with pytest.raises(ValidationError, match=r'unable to parse string as an number \[kind=float_parsing'):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize('schema', [{'type': 'float'}, {'type': 'float', 'gt': 0}, {'type': 'float', 'strict': True}])
+def test_float_returned_unchanged(schema):
+    input_value = 123.456
+    v = SchemaValidator(schema)
+    assert v.validate_python(input_value) is input_value
+
+
+def test_float_subclass_converted():
+    class MyFloat(float):
+        pass
+
+    v = SchemaValidator({'type': 'float'})
+    output = v.validate_python(MyFloat(1.5))
+    assert output == 1.5
+    assert type(output) is float
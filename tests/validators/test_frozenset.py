@@ -30,6 +30,15 @@ def test_frozenset_any(input_value, expected):
     assert isinstance(output, frozenset)
 
 
+@pytest.mark.parametrize('strict', [False, True])
+def test_frozenset_json_duplicates(strict):
+    v = SchemaValidator({'type': 'frozenset', 'items_schema': 'int', 'strict': strict})
+    output = v.validate_json('[1, 2, 2, 3, 1]')
+    assert output == {1, 2, 3}
+    assert isinstance(output, frozenset)
+    assert len(output) == 3
+
+
 def test_no_copy():
     v = SchemaValidator('frozenset')
     input_value = frozenset([1, 2, 3])
@@ -104,12 +113,24 @@ def test_frozenset_multiple_errors():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': [0],
+            'loc': (0,),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'a',
         },
-        {'kind': 'int_type', 'loc': [1], 'message': 'Value must be a valid integer', 'input_value': (1, 2)},
-        {'kind': 'int_type', 'loc': [2], 'message': 'Value must be a valid integer', 'input_value': []},
+        {
+            'kind': 'int_type',
+            'loc': (1,),
+            'message': 'Value must be a valid integer, input is of type `tuple`',
+            'input_value': (1, 2),
+            'context': {'input_type': 'tuple'},
+        },
+        {
+            'kind': 'int_type',
+            'loc': (2,),
+            'message': 'Value must be a valid integer, input is of type `list`',
+            'input_value': [],
+            'context': {'input_type': 'list'},
+        },
     ]
 
 
@@ -164,16 +185,18 @@ def test_union_frozenset_list(input_value, expected):
                 errors=[
                     {
                         'kind': 'int_type',
-                        'loc': ['frozenset[int]', 1],
-                        'message': 'Value must be a valid integer',
+                        'loc': ('frozenset[int]', 1),
+                        'message': 'Value must be a valid integer, input is of type `str`',
                         'input_value': 'a',
+                        'context': {'input_type': 'str'},
                     },
                     # second because validation on the string choice comes second
                     {
                         'kind': 'str_type',
-                        'loc': ['frozenset[str]', 0],
-                        'message': 'Value must be a valid string',
+                        'loc': ('frozenset[str]', 0),
+                        'message': 'Value must be a valid string, input is of type `int`',
                         'input_value': 1,
+                        'context': {'input_type': 'int'},
                     },
                 ],
             ),
@@ -213,6 +236,29 @@ def test_repr():
         'SchemaValidator('
         'name="frozenset[any]",'
         'validator=FrozenSet(FrozenSetValidator{'
-        'strict:true,item_validator:None,size_range:Some((Some(42),None)),name:"frozenset[any]"'
+        'strict:true,item_validator:None,size_range:Some((Some(42),None)),fail_fast:false,name:"frozenset[any]"'
         '}))'
     )
+
+
+@pytest.mark.parametrize('fail_fast,error_count', [(False, 2), (True, 1)])
+def test_frozenset_fail_fast(py_and_json: PyAndJson, fail_fast, error_count):
+    v = py_and_json({'type': 'frozenset', 'items_schema': 'int', 'fail_fast': fail_fast})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'a', 2, 'b'])
+    assert exc_info.value.error_count() == error_count
+
+
+def test_frozenset_unhashable_item(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'frozenset', 'items_schema': {'type': 'dict', 'values_schema': 'int'}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([{'a': 1}])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'set_item_not_hashable',
+            'loc': (0,),
+            'message': 'Set items must be hashable, input is of type `dict`',
+            'input_value': {'a': 1},
+            'context': {'input_type': 'dict'},
+        }
+    ]
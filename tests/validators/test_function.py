@@ -1,6 +1,7 @@
 import platform
 import re
 from copy import deepcopy
+from datetime import date, datetime, timezone
 from typing import Type
 
 import pytest
@@ -31,7 +32,7 @@ def test_function_before_raise():
     assert exc_info.value.errors() == [
         {
             'kind': 'value_error',
-            'loc': [],
+            'loc': (),
             'message': 'Value error, foobar',
             'input_value': 'input value',
             'context': {'error': 'foobar'},
@@ -53,10 +54,10 @@ def test_function_before_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'too_long',
-            'loc': [],
+            'loc': (),
             'message': 'String must have at most 5 characters',
             'input_value': '12345x',
-            'context': {'max_length': 5},
+            'context': {'max_length': 5, 'input_length': 6},
         }
     ]
 
@@ -82,10 +83,10 @@ def test_function_before_error_model():
     assert exc_info.value.errors() == [
         {
             'kind': 'too_long',
-            'loc': ['my_field'],
+            'loc': ('my_field',),
             'message': 'String must have at most 5 characters',
             'input_value': '12345x',
-            'context': {'max_length': 5},
+            'context': {'max_length': 5, 'input_length': 6},
         }
     ]
 
@@ -106,7 +107,9 @@ def test_function_wrap_repr():
 
     v = SchemaValidator({'type': 'function', 'mode': 'wrap', 'function': f, 'schema': 'str'})
 
-    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false}))'
+    assert v.validate_python('input value') == (
+        'ValidatorCallable(Str(StrValidator{strict:false,coerce_numbers_to_str:true,encoding:None}))'
+    )
 
 
 def test_function_wrap_str():
@@ -115,7 +118,9 @@ def test_function_wrap_str():
 
     v = SchemaValidator({'type': 'function', 'mode': 'wrap', 'function': f, 'schema': 'str'})
 
-    assert v.validate_python('input value') == 'ValidatorCallable(Str(StrValidator{strict:false}))'
+    assert v.validate_python('input value') == (
+        'ValidatorCallable(Str(StrValidator{strict:false,coerce_numbers_to_str:true,encoding:None}))'
+    )
 
 
 def test_function_wrap_not_callable():
@@ -126,6 +131,19 @@ def test_function_wrap_not_callable():
         SchemaValidator({'type': 'function', 'mode': 'wrap', 'schema': 'str'})
 
 
+def test_function_wrap_now():
+    def f(input_value, *, validator, **kwargs):
+        return validator(input_value)
+
+    v = SchemaValidator(
+        {'type': 'function', 'mode': 'wrap', 'function': f, 'schema': {'type': 'date', 'now_op': 'future'}}
+    )
+    # `now` is passed through to the wrapped validator, without it the system clock is used
+    assert v.validate_python('2000-01-02', now=datetime(2000, 1, 1, tzinfo=timezone.utc)) == date(2000, 1, 2)
+    with pytest.raises(ValidationError, match='kind=date_in_past'):
+        v.validate_python('2000-01-02')
+
+
 def test_wrap_error():
     def f(input_value, *, validator, **kwargs):
         return validator(input_value) * 2
@@ -138,7 +156,7 @@ def test_wrap_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': [],
+            'loc': (),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         }
@@ -287,7 +305,13 @@ def test_class_with_validator():
         v.validate_python(True)
 
     assert exc_info.value.errors() == [
-        {'kind': 'str_type', 'loc': [], 'message': 'Value must be a valid string', 'input_value': True}
+        {
+            'kind': 'str_type',
+            'loc': (),
+            'message': 'Value must be a valid string, input is of type `bool`',
+            'input_value': True,
+            'context': {'input_type': 'bool'},
+        }
     ]
 
 
@@ -303,7 +327,7 @@ def test_raise_assertion_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'assertion_error',
-            'loc': [],
+            'loc': (),
             'message': 'Assertion failed, foobar',
             'input_value': 'input value',
             'context': {'error': 'foobar'},
@@ -323,7 +347,7 @@ def test_raise_assertion_error_plain():
     assert exc_info.value.errors() == [
         {
             'kind': 'assertion_error',
-            'loc': [],
+            'loc': (),
             'message': 'Assertion failed, Unknown error',
             'input_value': 'input value',
             'context': {'error': 'Unknown error'},
@@ -392,7 +416,7 @@ def test_pydantic_value_error_usage():
     assert exc_info.value.errors() == [
         {
             'kind': 'my_error',
-            'loc': [],
+            'loc': (),
             'message': 'this is a custom error FOOBAR 42',
             'input_value': 42,
             'context': {'foo': 'FOOBAR', 'bar': 42},
@@ -426,3 +450,111 @@ def test_pydantic_value_error_invalid_type():
 
     with pytest.raises(TypeError, match="argument 'context': 'list' object cannot be converted to 'PyDict'"):
         v.validate_python(42)
+
+
+@pytest.mark.parametrize('mode', ['before', 'after', 'wrap'])
+def test_function_context(mode):
+    f_kwargs = {}
+
+    def f(input_value, **kwargs):
+        f_kwargs.update(kwargs)
+        if mode == 'wrap':
+            return kwargs['validator'](input_value)
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': mode, 'function': f, 'schema': 'int'})
+    assert v.validate_python('1', context={'foo': 'bar'}) == 1
+    assert f_kwargs['context'] == {'foo': 'bar'}
+
+    assert v.validate_json('2', context=[1, 2]) == 2
+    assert f_kwargs['context'] == [1, 2]
+
+
+def test_function_plain_context():
+    def f(input_value, context, **kwargs):
+        return input_value * context['multiplier']
+
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f})
+    assert v.validate_python(3, context={'multiplier': 2}) == 6
+    assert v.validate_python('x', context={'multiplier': 3}) == 'xxx'
+
+
+def test_function_context_validity():
+    class Session:
+        def __init__(self, *usernames):
+            self.usernames = set(usernames)
+
+    def check_username(input_value, context, **kwargs):
+        if context is None:
+            return input_value
+        if input_value in context.usernames:
+            raise ValueError(f'username {input_value!r} is taken')
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'username': {'schema': {'type': 'function', 'mode': 'plain', 'function': check_username}}},
+        }
+    )
+    session = Session('alice')
+    assert v.validate_python({'username': 'bob'}, context=session) == {'username': 'bob'}
+    assert v.validate_json('{"username": "bob"}', context=session) == {'username': 'bob'}
+    # without a context the function doesn't check usernames
+    assert v.validate_python({'username': 'alice'}) == {'username': 'alice'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'username': 'alice'}, context=session)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'value_error',
+            'loc': ('username',),
+            'message': "Value error, username 'alice' is taken",
+            'input_value': 'alice',
+            'context': {'error': "username 'alice' is taken"},
+        }
+    ]
+
+
+def test_function_context_not_validated():
+    contexts = []
+
+    def f(input_value, context, **kwargs):
+        contexts.append(context)
+        return input_value
+
+    v = SchemaValidator({'type': 'function', 'mode': 'plain', 'function': f})
+    context = object()
+    assert v.validate_python(1, context=context) == 1
+    assert v.isinstance_python(1, context=context) is True
+    assert contexts[0] is context
+    assert contexts[1] is context
+
+
+def test_function_plain_raise():
+    def f(input_value, **kwargs):
+        if input_value < 0:
+            raise ValueError('must be positive')
+        return input_value
+
+    v = SchemaValidator(
+        {'type': 'typed-dict', 'fields': {'x': {'schema': {'type': 'function', 'mode': 'plain', 'function': f}}}}
+    )
+    assert v.validate_python({'x': 1}) == {'x': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': -1})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'value_error',
+            'loc': ('x',),
+            'message': 'Value error, must be positive',
+            'input_value': -1,
+            'context': {'error': 'must be positive'},
+        }
+    ]
+
+
+def test_function_after_transform():
+    v = SchemaValidator(
+        {'type': 'function', 'mode': 'after', 'function': lambda v, **kwargs: sorted(v), 'schema': {'type': 'set'}}
+    )
+    assert v.validate_python(['c', 'a', 'b', 'a']) == ['a', 'b', 'c']
@@ -4,7 +4,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -19,8 +19,16 @@ from ..conftest import Err, PyAndJson, plain_repr
         (1, 1),
         (42, 42),
         ('42', 42),
+        (' 42 ', 42),
+        ('\t-3\n', -3),
+        (' 42.0 ', 42),
         (42.0, 42),
         (int(1e10), int(1e10)),
+        pytest.param(
+            '4 2',
+            Err('Value must be a valid integer, unable to parse string as an integer [kind=int_parsing'),
+            id='internal-whitespace',
+        ),
         pytest.param(
             12.5,
             Err('Value must be a valid integer, got a number with a fractional part [kind=int_from_float'),
@@ -144,7 +152,7 @@ def test_int_kwargs(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value,
         errors = exc_info.value.errors()
         assert len(errors) == 1
         if 'context' in errors[0]:
-            assert errors[0]['context'] == kwargs
+            assert errors[0]['context'] == {**kwargs, 'value': input_value}
     else:
         output = v.validate_test(input_value)
         assert output == expected
@@ -158,14 +166,21 @@ def test_union_int(py_and_json: PyAndJson):
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test('5')
 
+    input_type = 'str' if v.validator_type == 'python' else 'string'
     assert exc_info.value.errors() == [
-        {'kind': 'int_type', 'loc': ['int'], 'message': 'Value must be a valid integer', 'input_value': '5'},
+        {
+            'kind': 'int_type',
+            'loc': ('int',),
+            'message': f'Value must be a valid integer, input is of type `{input_type}`',
+            'input_value': '5',
+            'context': {'input_type': input_type},
+        },
         {
             'kind': 'multiple_of',
-            'loc': ['constrained-int'],
+            'loc': ('constrained-int',),
             'message': 'Value must be a multiple of 7',
             'input_value': '5',
-            'context': {'multiple_of': 7},
+            'context': {'multiple_of': 7, 'value': 5},
         },
     ]
 
@@ -179,13 +194,29 @@ def test_union_int_simple(py_and_json: PyAndJson):
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['int'],
+            'loc': ('int',),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'xxx',
         }
     ]
 
 
+@pytest.mark.parametrize('input_value,expected', [(True, 1), (False, 0)])
+@pytest.mark.parametrize('kwargs', [{}, {'ge': 0}], ids=['int', 'constrained-int'])
+def test_int_bool(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
+    v = py_and_json({'type': 'int', **kwargs})
+    output = v.validate_test(input_value)
+    assert output == expected
+    assert type(output) is int
+
+    with pytest.raises(ValidationError, match=re.escape('Value must be a valid integer [kind=int_type')):
+        v.validate_test(input_value, True)
+
+    v = py_and_json({'type': 'int', 'strict': True, **kwargs})
+    with pytest.raises(ValidationError, match=re.escape('Value must be a valid integer [kind=int_type')):
+        v.validate_test(input_value)
+
+
 def test_int_repr():
     v = SchemaValidator({'type': 'int'})
     assert plain_repr(v) == 'SchemaValidator(name="int",validator=Int(IntValidator{strict:false}))'
@@ -204,9 +235,9 @@ def test_long_int(py_and_json: PyAndJson):
     assert exc_info.value.errors() == [
         {
             'kind': 'int_nan',
-            'loc': [],
+            'loc': (),
             'message': 'Value must be a valid integer, got infinity',
-            'input_value': '1' * 400,
+            'input_value': '1' * 200 + '...',
             'context': {'nan_value': 'infinity'},
         }
     ]
@@ -231,5 +262,312 @@ def test_int_nan(py_and_json: PyAndJson):
 def test_int_key(py_and_json: PyAndJson):
     v = py_and_json({'type': 'dict', 'keys_schema': 'int', 'values_schema': 'int'})
     assert v.validate_test({'1': 1, '2': 2}) == {1: 1, 2: 2}
-    with pytest.raises(ValidationError, match='Value must be a valid integer'):
-        v.validate_test({'1': 1, '2': 2}, strict=True)
+    if v.validator_type == 'json':
+        # JSON keys are always strings, so strict mode allows strings which are exactly an integer
+        assert v.validate_test({'1': 1, '2': 2}, strict=True) == {1: 1, 2: 2}
+        with pytest.raises(ValidationError, match='Value must be a valid integer'):
+            v.validate_test({'1.0': 1}, strict=True)
+    else:
+        with pytest.raises(ValidationError, match='Value must be a valid integer'):
+            v.validate_test({'1': 1, '2': 2}, strict=True)
+
+
+@pytest.mark.parametrize(
+    'input_value', ['1_000', '1_0_0_0', '-1_000', ' 1_000 ', '1_000.0', '_1', '1_', '1__0', '1_.0', '1._0', '_', '1 _0']
+)
+def test_int_underscores(py_and_json: PyAndJson, input_value):
+    v = py_and_json({'type': 'int'})
+    try:
+        expected = int(float(input_value))
+    except ValueError:
+        with pytest.raises(ValidationError, match=r'unable to parse string as an integer \[kind=int_parsing'):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        '+5',
+        '-5',
+        '007',
+        '-007',
+        '+007',
+        '00',
+        '-0',
+        '0_7',
+        '1_000',
+        '\t\n42\r\n',
+        '\xa042\u2003',
+        '1__000',
+        '_1',
+        '1_',
+        '+_1',
+        '4 2',
+        '+-5',
+        '--5',
+        '+ 5',
+        '+',
+        '',
+        '0x10',
+        '0b1',
+    ],
+)
+def test_int_str_matches_python(input_value):
+    v = SchemaValidator({'type': 'int'})
+    try:
+        expected = int(input_value)
+    except ValueError:
+        with pytest.raises(ValidationError, match='kind=int_parsing'):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        # whole numbers in float notation are allowed, unlike `int()`
+        ('1.0', 1),
+        ('1e3', 1000),
+        # only ASCII digits are allowed, `int()` allows any unicode decimal digits
+        ('\u0663', Err('kind=int_parsing')),
+    ],
+)
+def test_int_str_differs_from_python(input_value, expected):
+    v = SchemaValidator({'type': 'int'})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize('input_value', [2**63, -(2**63) - 1, 2**70, 18446744073709551615, -(10**30)])
+def test_big_int_python(input_value):
+    v = SchemaValidator({'type': 'int'})
+    output = v.validate_python(input_value)
+    assert output == input_value
+    assert type(output) is int
+    assert v.validate_python(input_value, strict=True) == input_value
+
+
+@pytest.mark.parametrize('input_str', ['18446744073709551615', '99999999999999999999999999', '-9223372036854775809'])
+def test_big_int_json(input_str):
+    v = SchemaValidator({'type': 'int'})
+    assert v.validate_json(input_str) == int(input_str)
+    assert v.validate_json(input_str, strict=True) == int(input_str)
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    assert v.validate_json(f'[1, {input_str}]') == [1, int(input_str)]
+
+
+def test_big_int_json_many_digits():
+    # python's `int()` limits the number of digits it will parse, JSON big ints don't go through `int()`
+    input_str = '9' * 5000
+    v = SchemaValidator({'type': 'int'})
+    output = v.validate_json(input_str)
+    assert output == 10**5000 - 1
+    assert SchemaValidator({'type': 'any'}).validate_json(f'[{input_str}]') == [output]
+
+
+def test_big_int_constrained():
+    v = SchemaValidator({'type': 'int', 'ge': 0})
+    assert v.validate_python(2**70) == 2**70
+    assert v.validate_json('99999999999999999999999999') == 99999999999999999999999999
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(-(2**70))
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'greater_than_equal',
+            'loc': (),
+            'message': 'Value must be greater than or equal to 0',
+            'input_value': -(2**70),
+            'context': {'ge': 0, 'value': -(2**70)},
+        }
+    ]
+    v = SchemaValidator({'type': 'int', 'multiple_of': 3})
+    assert v.validate_python(3**50) == 3**50
+    with pytest.raises(ValidationError, match=r'kind=multiple_of'):
+        v.validate_python(2**70)
+
+
+def test_big_int_bound():
+    v = SchemaValidator({'type': 'int', 'le': 10**30})
+    assert v.validate_python(1) == 1
+    assert v.validate_python(-(2**70)) == -(2**70)
+    assert v.validate_python(10**30) == 10**30
+    assert v.validate_json(str(10**30)) == 10**30
+    assert v.validate_python('123') == 123
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(10**30 + 1)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'less_than_equal',
+            'loc': (),
+            'message': f'Value must be less than or equal to {10**30}',
+            'input_value': 10**30 + 1,
+            'context': {'le': 10**30, 'value': 10**30 + 1},
+        }
+    ]
+    with pytest.raises(ValidationError, match='kind=less_than_equal'):
+        v.validate_json(str(10**30 + 1))
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        ({'gt': -(10**30)}, -(10**30), Err('Value must be greater than -1000000000000000000000000000000')),
+        ({'gt': -(10**30)}, 0, 0),
+        ({'ge': 2**64}, 2**64, 2**64),
+        ({'ge': 2**64}, 2**63, Err('Value must be greater than or equal to 18446744073709551616')),
+        ({'lt': 2**64, 'ge': 0}, 2**64 - 1, 2**64 - 1),
+        ({'lt': 2**64, 'ge': 0}, -1, Err('Value must be greater than or equal to 0')),
+        ({'lt': 2**64}, 2**64, Err('Value must be less than 18446744073709551616')),
+        ({'multiple_of': 2**64}, 2**65, 2**65),
+        ({'multiple_of': 2**64}, 2**64 + 1, Err('Value must be a multiple of 18446744073709551616')),
+        ({'multiple_of': 2**64}, 0, 0),
+    ],
+)
+def test_big_int_bounds(schema, input_value, expected):
+    v = SchemaValidator({'type': 'int', **schema})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is int
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        {'multiple_of': 0},
+        {'multiple_of': -3},
+        {'multiple_of': 0, 'le': 2**70},
+        {'multiple_of': -(2**70)},
+    ],
+)
+def test_multiple_of_invalid(schema):
+    with pytest.raises(SchemaError, match="'multiple_of' must be greater than 0"):
+        SchemaValidator({'type': 'int', **schema})
+
+
+def test_big_int_bound_strict():
+    v = SchemaValidator({'type': 'int', 'le': 10**30, 'strict': True})
+    assert v.validate_python(10**29) == 10**29
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        v.validate_python('123')
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        v.validate_python(True)
+
+
+def test_float_out_of_range():
+    v = SchemaValidator({'type': 'int'})
+    with pytest.raises(ValidationError, match=r'number is out of range \[kind=int_overflow'):
+        v.validate_python(1e20)
+    with pytest.raises(ValidationError, match=r'number is out of range \[kind=int_overflow'):
+        v.validate_python('1e20')
+
+
+def test_big_int_json_coercion():
+    assert SchemaValidator({'type': 'float'}).validate_json('99999999999999999999') == 1e20
+    assert SchemaValidator({'type': 'decimal'}).validate_json('99999999999999999999') == Decimal('99999999999999999999')
+    assert SchemaValidator({'type': 'str'}).validate_json('99999999999999999999') == '99999999999999999999'
+    with pytest.raises(ValidationError, match='kind=bool_parsing'):
+        SchemaValidator({'type': 'bool'}).validate_json('99999999999999999999')
+
+
+@pytest.mark.parametrize(
+    'input_value,kind',
+    [
+        (2**70, 'less_than_equal'),
+        (-(2**70), 'greater_than_equal'),
+        (str(2**70), 'less_than_equal'),
+        (1e30, 'int_overflow'),
+        ('1e30', 'int_overflow'),
+        ('not a number', 'int_parsing'),
+        (1.5, 'int_from_float'),
+        ([1], 'int_type'),
+    ],
+)
+def test_int_overflow_distinct(input_value, kind):
+    v = SchemaValidator({'type': 'int', 'ge': 0, 'le': 100})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors()[0]['kind'] == kind
+
+
+@pytest.mark.parametrize(
+    'input_str,kind',
+    [(str(2**70 + 1), 'multiple_of'), ('1e30', 'int_overflow'), ('"x"', 'int_parsing'), ('[]', 'int_type')],
+)
+def test_int_overflow_distinct_json(input_str, kind):
+    v = SchemaValidator({'type': 'int', 'multiple_of': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_str)
+    assert exc_info.value.errors()[0]['kind'] == kind
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        ({}, 2**70, 2**70),
+        ({}, -(2**70), -(2**70)),
+        ({'multiple_of': 2}, 2**70, 2**70),
+        ({'multiple_of': 2}, 2**70 + 1, 'multiple_of'),
+        ({'ge': 0, 'le': 100}, 2**70, 'less_than_equal'),
+        ({'ge': 0, 'le': 100}, -(2**70), 'greater_than_equal'),
+    ],
+)
+def test_big_int_input_types(schema, input_value, expected):
+    # python ints, strings and JSON numbers and strings all give the same result in lax mode
+    v = SchemaValidator({'type': 'int', **schema})
+    for validate, value in [
+        (v.validate_python, input_value),
+        (v.validate_python, str(input_value)),
+        (v.validate_json, str(input_value)),
+        (v.validate_json, f'"{input_value}"'),
+    ]:
+        if isinstance(expected, str):
+            with pytest.raises(ValidationError) as exc_info:
+                validate(value)
+            assert exc_info.value.errors()[0]['kind'] == expected
+        else:
+            assert validate(value) == expected
+
+
+def test_big_int_str_strict():
+    v = SchemaValidator({'type': 'int', 'strict': True})
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        v.validate_python(str(2**70))
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        v.validate_json(f'"{2**70}"')
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        ({'type': 'int'}, 12345),
+        ({'type': 'int'}, 2**70),
+        ({'type': 'int', 'strict': True}, 12345),
+        ({'type': 'int', 'gt': 0}, 12345),
+        ({'type': 'int', 'gt': 0, 'le': 2**100}, 12345),
+        ({'type': 'int', 'gt': 0, 'le': 2**100}, 2**70),
+    ],
+)
+def test_int_returned_unchanged(schema, input_value):
+    v = SchemaValidator(schema)
+    assert v.validate_python(input_value) is input_value
+
+
+def test_int_subclass_converted():
+    class MyInt(int):
+        pass
+
+    v = SchemaValidator({'type': 'int'})
+    output = v.validate_python(MyInt(12345))
+    assert output == 12345
+    assert type(output) is int
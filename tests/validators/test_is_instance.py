@@ -16,7 +16,7 @@ class Spam:
 
 
 def test_is_instance():
-    v = SchemaValidator({'type': 'is-instance', 'class_': Foo})
+    v = SchemaValidator({'type': 'is-instance', 'cls': Foo})
     foo = Foo()
     assert v.validate_python(foo) == foo
     assert v.isinstance_python(foo) is True
@@ -30,7 +30,7 @@ def test_is_instance():
     assert exc_info.value.errors() == [
         {
             'kind': 'is_instance_of',
-            'loc': [],
+            'loc': (),
             'message': 'Input must be an instance of Foo',
             'input_value': s,
             'context': {'class': 'Foo'},
@@ -59,14 +59,46 @@ def test_is_instance():
     ],
 )
 def test_is_instance_cases(schema_class, input_val, value):
-    v = SchemaValidator({'type': 'is-instance', 'class_': schema_class})
+    v = SchemaValidator({'type': 'is-instance', 'cls': schema_class})
     assert v.isinstance_python(input_val) == value
 
 
+def test_is_instance_inheritance():
+    class Baz(Bar):
+        pass
+
+    v = SchemaValidator({'type': 'is-instance', 'cls': Foo})
+    baz = Baz()
+    assert v.validate_python(baz) is baz
+    assert v.isinstance_python(Bar()) is True
+
+    v = SchemaValidator({'type': 'is-instance', 'cls': Baz})
+    assert v.isinstance_python(Bar()) is False
+    assert v.isinstance_python(baz) is True
+
+    v = SchemaValidator({'type': 'is-instance', 'cls': int})
+    assert v.validate_python(True) is True
+    with pytest.raises(ValidationError, match='Input must be an instance of int'):
+        v.validate_json('123')
+
+
+def test_is_instance_class_deprecated():
+    with pytest.warns(DeprecationWarning, match="'class_' is deprecated, use 'cls' instead"):
+        v = SchemaValidator({'type': 'is-instance', 'class_': Foo})
+    assert isinstance(v.validate_python(Foo()), Foo)
+    with pytest.raises(ValidationError, match='Input must be an instance of Foo'):
+        v.validate_python(Spam())
+
+
+def test_is_instance_cls_missing():
+    with pytest.raises(SchemaError, match='Missing required key "cls"'):
+        SchemaValidator({'type': 'is-instance'})
+
+
 @pytest.mark.parametrize('input_cls', [123, 'foo', Foo(), [], {1: 2}])
 def test_is_instance_invalid(input_cls):
     with pytest.raises(SchemaError, match="object cannot be converted to 'PyType'"):
-        SchemaValidator({'type': 'is-instance', 'class_': input_cls})
+        SchemaValidator({'type': 'is-instance', 'cls': input_cls})
 
 
 class HasIsInstanceMeta(type):
@@ -82,7 +114,7 @@ class HasIsInstance(metaclass=HasIsInstanceMeta):
 
 
 def test_instancecheck():
-    v = SchemaValidator({'type': 'is-instance', 'class_': HasIsInstance})
+    v = SchemaValidator({'type': 'is-instance', 'cls': HasIsInstance})
     assert v.validate_python('true') == 'true'
 
     with pytest.raises(ValidationError, match='kind=is_instance_of'):
@@ -93,7 +125,7 @@ def test_instancecheck():
 
 
 def test_repr():
-    v = SchemaValidator({'type': 'union', 'choices': ['int', {'type': 'is-instance', 'class_': Foo}]})
+    v = SchemaValidator({'type': 'union', 'choices': ['int', {'type': 'is-instance', 'cls': Foo}]})
     assert v.isinstance_python(4) is True
     assert v.isinstance_python(Bar()) is True
     assert v.isinstance_python('foo') is False
@@ -0,0 +1,124 @@
+import json
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('{"a": 1, "b": "2"}', {'a': 1, 'b': 2}),
+        ('{"a": 1, "b": 2, "c": 3}', {'a': 1, 'b': 2}),
+        (
+            '{"a": 1, "b": "x"}',
+            Err('Value must be a valid integer, unable to parse string as an integer [kind=int_parsing'),
+        ),
+        ('{"a": 1, ', Err('Invalid JSON: EOF while parsing a value at line 1 column 9 [kind=invalid_json')),
+        ('[1, 2]', Err('Value must be a valid dictionary [kind=dict_type')),
+    ],
+)
+def test_json_typed_dict(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(
+        {'type': 'json', 'schema': {'type': 'typed-dict', 'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'int'}}}}
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_json_errors():
+    v = SchemaValidator({'type': 'json', 'schema': {'type': 'list', 'items_schema': 'int'}})
+    assert v.validate_python('[1, 2, "3"]') == [1, 2, 3]
+    assert v.validate_python(b'[1, 2]') == [1, 2]
+    assert v.validate_python(bytearray(b'[1]')) == [1]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('[1, "x"]')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': (1,),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('[1, 2')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'invalid_json',
+            'loc': (),
+            'message': 'Invalid JSON: EOF while parsing a list at line 1 column 5',
+            'input_value': '[1, 2',
+            'context': {'error': 'EOF while parsing a list at line 1 column 5', 'line': 1, 'column': 5, 'position': 4},
+        }
+    ]
+
+
+def test_json_any(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'json'})
+    assert v.validate_test('{"a": [1, null, 2.5]}') == {'a': [1, None, 2.5]}
+    assert v.validate_test('"foo"') == 'foo'
+
+
+def test_json_not_str_strict():
+    v = SchemaValidator({'type': 'json', 'schema': {'type': 'list', 'items_schema': 'int'}, 'strict': True})
+    assert v.validate_python('[1, 2]') == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'json_type',
+            'loc': (),
+            'message': 'JSON input must be str, bytes or bytearray, input is of type `list`',
+            'input_value': [1, 2],
+            'context': {'input_type': 'list'},
+        }
+    ]
+    with pytest.raises(ValidationError, match='kind=json_type'):
+        v.validate_json('[1, 2]')
+
+
+def test_json_not_str_lax():
+    v = SchemaValidator({'type': 'json', 'schema': {'type': 'list', 'items_schema': 'int'}})
+    assert v.validate_python([1, '2']) == [1, 2]
+    assert v.validate_json('[1, 2]') == [1, 2]
+    with pytest.raises(ValidationError, match='kind=json_type'):
+        v.validate_python([1, 2], strict=True)
+
+
+def test_json_field():
+    v = SchemaValidator({'type': 'typed-dict', 'fields': {'x': {'schema': {'type': 'json', 'schema': 'int'}}}})
+    assert v.validate_python({'x': '42'}) == {'x': 42}
+    assert v.validate_json('{"x": "42"}') == {'x': 42}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"x": "4x"}')
+    assert exc_info.value.errors()[0]['loc'] == ('x',)
+    assert exc_info.value.errors()[0]['kind'] == 'invalid_json'
+
+
+def test_repr():
+    assert repr(SchemaValidator('json')).startswith('SchemaValidator(name="json[any]"')
+    v = SchemaValidator({'type': 'json', 'schema': 'int'})
+    assert repr(v).startswith('SchemaValidator(name="json[int]"')
+
+
+def test_json_duplicate_keys(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'json', 'schema': {'type': 'dict', 'values_schema': 'int'}})
+    assert v.validate_test('{"a": 1, "a": 2}') == {'a': 2}
+
+    v = SchemaValidator(
+        {'type': 'json', 'schema': {'type': 'dict', 'values_schema': 'int'}}, {'json_reject_duplicate_keys': True}
+    )
+    assert v.validate_python('{"a": 1, "b": 2}') == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError, match="Invalid JSON: duplicate key 'a' at line 1 column 10"):
+        v.validate_python('{"a":1,"a":2}')
+    with pytest.raises(ValidationError, match="Invalid JSON: duplicate key 'a' at line 1 column 10"):
+        v.validate_json(json.dumps('{"a":1,"a":2}'))
@@ -1,3 +1,4 @@
+import itertools
 import re
 from typing import Any, Dict
 
@@ -33,7 +34,13 @@ def test_list_strict():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python((1, 2, '33'))
     assert exc_info.value.errors() == [
-        {'kind': 'list_type', 'loc': [], 'message': 'Value must be a valid list/array', 'input_value': (1, 2, '33')}
+        {
+            'kind': 'list_type',
+            'loc': (),
+            'message': 'Value must be a valid list/array, input is of type `tuple`',
+            'input_value': (1, 2, '33'),
+            'context': {'input_type': 'tuple'},
+        }
     ]
 
 
@@ -51,6 +58,98 @@ def test_list_int(input_value, expected):
     assert v.validate_python(input_value) == expected
 
 
+def gen_ints():
+    yield 1
+    yield 2
+    yield '3'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (gen_ints(), [1, 2, 3]),
+        ((x for x in [1, '2']), [1, 2]),
+        (iter([1, 2, '3']), [1, 2, 3]),
+        (map(str, [1, 2]), [1, 2]),
+        ((x for x in []), []),
+    ],
+)
+def test_list_generator(input_value, expected):
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    assert v.validate_python(input_value) == expected
+
+
+def test_list_generator_any():
+    v = SchemaValidator('list')
+    assert v.validate_python(x for x in [1, 'a']) == [1, 'a']
+
+
+def test_list_generator_error():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(x for x in [1, 'x', 3])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': (1,),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+
+def test_list_generator_raises():
+    def gen():
+        yield 1
+        raise RuntimeError('broken generator')
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(RuntimeError, match='broken generator'):
+        v.validate_python(gen())
+
+
+def test_list_generator_max_items():
+    consumed = []
+
+    def gen():
+        for i in itertools.count():
+            consumed.append(i)
+            yield i
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'max_items': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(gen())
+    errors = exc_info.value.errors()
+    assert [(e['kind'], e['message'], e['context']) for e in errors] == [
+        ('too_long', 'Input must have at most 3 items', {'max_length': 3})
+    ]
+    # the infinite generator is only read one item past the maximum
+    assert consumed == [0, 1, 2, 3]
+    assert v.validate_python(x for x in [1, 2, 3]) == [1, 2, 3]
+
+    # the length of an iterator isn't known, so it's omitted from the context
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(x for x in range(10))
+    assert exc_info.value.errors()[0]['context'] == {'max_length': 3}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2, 3, 4])
+    assert exc_info.value.errors()[0]['context'] == {'max_length': 3, 'input_length': 4}
+
+
+@pytest.mark.parametrize('input_value', [(1, 2), {1, 2}, frozenset([1, 2]), gen_ints()])
+def test_list_strict_rejects(input_value):
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'strict': True})
+    with pytest.raises(ValidationError, match='Value must be a valid list/array'):
+        v.validate_python(input_value)
+
+
+@pytest.mark.parametrize('input_value', ['123', b'123', {1: 2}, {1: 2}.keys(), 123])
+def test_list_not_iterators(input_value):
+    v = SchemaValidator('list')
+    with pytest.raises(ValidationError, match='Value must be a valid list/array'):
+        v.validate_python(input_value)
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -68,6 +167,30 @@ def test_list_any(input_value, expected):
     assert output == expected
 
 
+@pytest.mark.parametrize('schema', ['list', {'type': 'list', 'items_schema': 'any'}])
+def test_list_any_no_copy(schema):
+    v = SchemaValidator(schema)
+    assert repr(v).startswith('SchemaValidator(name="list[any]"')
+    input_value = [1, '2', b'3']
+    output = v.validate_python(input_value)
+    assert output == input_value
+    assert output is input_value
+
+
+@pytest.mark.parametrize('input_value', [[1, '2', b'3'], (1, '2'), {1, '2'}, frozenset([1, '2'])])
+def test_list_items_any_same_output(input_value):
+    v_plain = SchemaValidator('list')
+    v_any = SchemaValidator({'type': 'list', 'items_schema': 'any'})
+    assert v_any.validate_python(input_value) == v_plain.validate_python(input_value)
+
+
+def test_list_items_any_json():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'any'})
+    json_data = '[1, "2", 3.5, null, [4], {"a": 5}]'
+    assert v.validate_json(json_data) == [1, '2', 3.5, None, [4], {'a': 5}]
+    assert v.validate_json(json_data) == SchemaValidator('list').validate_json(json_data)
+
+
 @pytest.mark.parametrize(
     'input_value,index',
     [
@@ -86,7 +209,7 @@ def test_list_error(input_value, index):
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': [index],
+            'loc': (index,),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         }
@@ -112,6 +235,25 @@ def test_list_length_constraints(kwargs: Dict[str, Any], input_value, expected):
         assert v.validate_python(input_value) == expected
 
 
+@pytest.mark.parametrize('input_value', [list(range(10)), tuple(range(10))])
+def test_list_too_long_items_not_validated(input_value):
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(
+        {'type': 'list', 'items_schema': {'type': 'function', 'mode': 'plain', 'function': f}, 'max_items': 5}
+    )
+    with pytest.raises(ValidationError, match=re.escape('Input must have at most 5 items [kind=too_long')):
+        v.validate_python(input_value)
+    assert calls == []
+
+    assert v.validate_python(input_value[:5]) == list(range(5))
+    assert calls == [0, 1, 2, 3, 4]
+
+
 def test_length_ctx():
     v = SchemaValidator({'type': 'list', 'min_items': 2, 'max_items': 3})
     with pytest.raises(ValidationError) as exc_info:
@@ -119,10 +261,10 @@ def test_length_ctx():
     assert exc_info.value.errors() == [
         {
             'kind': 'too_short',
-            'loc': [],
+            'loc': (),
             'message': 'Input must have at least 2 items',
             'input_value': [1],
-            'context': {'min_length': 2},
+            'context': {'min_length': 2, 'input_length': 1},
         }
     ]
 
@@ -132,10 +274,10 @@ def test_length_ctx():
     assert exc_info.value.errors() == [
         {
             'kind': 'too_long',
-            'loc': [],
+            'loc': (),
             'message': 'Input must have at most 3 items',
             'input_value': [1, 2, 3, 4],
-            'context': {'max_length': 3},
+            'context': {'max_length': 3, 'input_length': 4},
         }
     ]
 
@@ -160,14 +302,14 @@ def test_list_function_val_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'value_error',
-            'loc': [0],
+            'loc': (0,),
             'message': 'Value error, error 1',
             'input_value': 1,
             'context': {'error': 'error 1'},
         },
         {
             'kind': 'value_error',
-            'loc': [1],
+            'loc': (1,),
             'message': 'Value error, error 2',
             'input_value': 2,
             'context': {'error': 'error 2'},
@@ -184,3 +326,109 @@ def test_list_function_internal_error():
     with pytest.raises(RuntimeError, match='^error 1$') as exc_info:
         v.validate_python([1, 2])
     assert exc_info.value.args[0] == 'error 1'
+
+
+@pytest.mark.parametrize(
+    'items_schema,input_value,expected',
+    [
+        ('int', [1, 2, 3], [1, 2, 3]),
+        ('int', [], []),
+        ('int', [1, 2, 1], Err('List must have unique items, item 2 is a duplicate [kind=list_unique_items')),
+        ('int', [1, '1'], Err('List must have unique items, item 1 is a duplicate [kind=list_unique_items')),
+        ('str', ['a', 'b', 'c', 'b', 'a'], Err('item 3 is a duplicate')),
+    ],
+)
+def test_unique_items(py_and_json: PyAndJson, items_schema, input_value, expected):
+    v = py_and_json({'type': 'list', 'items_schema': items_schema, 'unique_items': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize('items_schema', [None, 'any'])
+def test_unique_items_no_validator(py_and_json: PyAndJson, items_schema):
+    schema = {'type': 'list', 'unique_items': True}
+    if items_schema:
+        schema['items_schema'] = items_schema
+    v = py_and_json(schema)
+    assert v.validate_test([1, 'a', None, 2.5]) == [1, 'a', None, 2.5]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'a', None, 'a'])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'list_unique_items',
+            'loc': (),
+            'message': 'List must have unique items, item 3 is a duplicate',
+            'input_value': [1, 'a', None, 'a'],
+            'context': {'index': 3},
+        }
+    ]
+
+
+def test_unique_items_unhashable(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'list', 'unique_items': True})
+    assert v.validate_test([[1], [2], {'a': 1}, {'a': 2}, 1]) == [[1], [2], {'a': 1}, {'a': 2}, 1]
+    with pytest.raises(ValidationError, match='item 3 is a duplicate'):
+        v.validate_test([{'a': 1}, [1], 1, {'a': 1}])
+    with pytest.raises(ValidationError, match='item 2 is a duplicate'):
+        v.validate_test([1, [1], 1])
+
+
+def test_unique_items_not_set():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int'})
+    assert v.validate_python([1, 1, '1']) == [1, 1, 1]
+
+
+def test_coerce_single_to_list(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'list', 'items_schema': 'int', 'coerce_single_to_list': True})
+    assert v.validate_test(1) == [1]
+    assert v.validate_test('2') == [2]
+    assert v.validate_test([1, '2']) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('x')
+    # the input isn't a list so there's no index in the location
+    assert exc_info.value.errors()[0]['kind'] == 'int_parsing'
+    assert exc_info.value.errors()[0]['loc'] == ()
+
+    # strict mode ignores coerce_single_to_list
+    with pytest.raises(ValidationError, match='kind=list_type'):
+        v.validate_test(1, strict=True)
+
+    v = py_and_json({'type': 'list', 'items_schema': 'int'})
+    with pytest.raises(ValidationError, match='kind=list_type'):
+        v.validate_test(1)
+
+
+def test_coerce_single_to_list_any():
+    v = SchemaValidator({'type': 'list', 'coerce_single_to_list': True})
+    assert v.validate_python('abc') == ['abc']
+    assert v.validate_python({'a': 1}) == [{'a': 1}]
+    assert v.validate_python(None) == [None]
+
+
+def test_coerce_single_to_list_size():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'coerce_single_to_list': True, 'min_items': 2})
+    with pytest.raises(ValidationError, match='kind=too_short'):
+        v.validate_python(1)
+
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'coerce_single_to_list': True, 'max_items': 0})
+    with pytest.raises(ValidationError, match='kind=too_long'):
+        v.validate_python(1)
+
+
+def test_coerce_single_to_list_long_generator():
+    v = SchemaValidator({'type': 'list', 'items_schema': 'int', 'coerce_single_to_list': True, 'max_items': 3})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(x for x in range(10))
+    assert [(e['kind'], e['context']) for e in exc_info.value.errors()] == [('too_long', {'max_length': 3})]
+
+
+@pytest.mark.parametrize('fail_fast,error_count', [(False, 2), (True, 1)])
+def test_list_fail_fast(py_and_json: PyAndJson, fail_fast, error_count):
+    v = py_and_json({'type': 'list', 'items_schema': 'int', 'fail_fast': fail_fast})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'a', 2, 'b'])
+    assert exc_info.value.error_count() == error_count
+    assert exc_info.value.errors()[0]['loc'] == (1,)
@@ -66,6 +66,34 @@ def test_model_class_setattr():
     assert setattr_calls == []
 
 
+def test_model_class_init_not_called():
+    class MyModel:
+        field_a: str
+
+        def __init__(self):
+            raise RuntimeError('__init__ should not be called')
+
+    v = SchemaValidator(
+        {
+            'type': 'model-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {
+                    'field_a': {'schema': {'type': 'str'}},
+                    'field_b': {'schema': {'type': 'int'}, 'default': 1},
+                },
+            },
+        }
+    )
+    m = v.validate_python({'field_a': 'test'})
+    assert type(m) is MyModel
+    assert m.field_a == 'test'
+    assert m.field_b == 1
+    assert m.__fields_set__ == {'field_a'}
+
+
 def test_model_class_root_validator():
     class MyModel:
         pass
@@ -104,7 +132,7 @@ def test_model_class_bad_model():
 
 
 def test_model_class_not_type():
-    with pytest.raises(SchemaError, match=re.escape("TypeError: 'int' object cannot be converted to 'PyType'")):
+    with pytest.raises(SchemaError, match=re.escape('"class_type": \'int\' object cannot be converted to \'PyType\'')):
         SchemaValidator(
             {
                 'type': 'model-class',
@@ -225,7 +253,7 @@ def test_model_class_strict():
     assert exc_info.value.errors() == [
         {
             'kind': 'model_class_type',
-            'loc': [],
+            'loc': (),
             'message': 'Value must be an instance of MyModel',
             'input_value': {'field_a': 'test', 'field_b': 12},
             'context': {'class_name': 'MyModel'},
@@ -288,7 +316,7 @@ def test_revalidate():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['field_b'],
+            'loc': ('field_b',),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'not int',
         }
@@ -328,3 +356,168 @@ def test_revalidate_extra():
     assert m3 is not m2
     assert m3.__dict__ == {'field_a': 'x', 'field_b': 42, 'another': 42.5}
     assert m3.__fields_set__ == {'field_a', 'field_b', 'another'}
+
+
+@pytest.mark.parametrize(
+    'extra_behavior,expected_dict',
+    [('ignore', {'field_a': 'test'}), ('allow', {'field_a': 'test', 'extra_key': 1}), ('forbid', None)],
+)
+def test_model_class_extra_behavior(extra_behavior, expected_dict):
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'model-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'extra_behavior': extra_behavior,
+                'fields': {'field_a': {'schema': 'str'}},
+            },
+        }
+    )
+    for validate, input_value in [
+        (v.validate_python, {'field_a': 'test', 'extra_key': 1}),
+        (v.validate_json, '{"field_a": "test", "extra_key": 1}'),
+    ]:
+        if expected_dict is None:
+            with pytest.raises(ValidationError) as exc_info:
+                validate(input_value)
+            assert exc_info.value.errors()[0]['kind'] == 'extra_forbidden'
+            assert exc_info.value.errors()[0]['loc'] == ('extra_key',)
+        else:
+            m = validate(input_value)
+            assert isinstance(m, MyModel)
+            assert m.__dict__ == expected_dict
+            assert m.__fields_set__ == set(expected_dict)
+
+
+def test_model_class_validate_assignment():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+        field_a: str
+        field_b: int
+
+    v = SchemaValidator(
+        {
+            'type': 'model-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {'field_a': {'schema': {'type': 'str'}}, 'field_b': {'schema': {'type': 'int'}}},
+            },
+        }
+    )
+    m = v.validate_python({'field_a': 'test', 'field_b': 12})
+
+    model_dict, fields_set = v.validate_assignment('field_b', '34', m.__dict__)
+    assert model_dict == {'field_a': 'test', 'field_b': 34}
+    assert fields_set == {'field_b'}
+    assert m.field_b == 34
+    assert m.field_a == 'test'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment('field_b', 'wrong', m.__dict__)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ('field_b',),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'wrong',
+        }
+    ]
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 34}
+
+
+def test_model_class_fields_set_defaults():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+    v = SchemaValidator(
+        {
+            'type': 'model-class',
+            'class_type': MyModel,
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {'field_a': {'schema': 'str'}, 'field_b': {'schema': 'int', 'default': 1}},
+            },
+        }
+    )
+    m = v.validate_python({'field_a': 'test'})
+    assert m.__dict__ == {'field_a': 'test', 'field_b': 1}
+    assert m.__fields_set__ == {'field_a'}
+
+    m = v.validate_python({'field_a': 'test', 'field_b': 1})
+    assert m.__fields_set__ == {'field_a', 'field_b'}
+
+
+def test_model_class_post_init():
+    calls = []
+
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def call_me_maybe(self, context):
+            calls.append((self.__dict__, context))
+            if self.a > self.b:
+                raise ValueError('a must not be greater than b')
+
+    v = SchemaValidator(
+        {
+            'type': 'model-class',
+            'class_type': MyModel,
+            'post_init': 'call_me_maybe',
+            'schema': {
+                'type': 'typed-dict',
+                'return_fields_set': True,
+                'fields': {'a': {'schema': 'int'}, 'b': {'schema': 'int'}},
+            },
+        }
+    )
+    m = v.validate_python({'a': 1, 'b': '2'}, context={'x': 'y'})
+    assert isinstance(m, MyModel)
+    assert m.__dict__ == {'a': 1, 'b': 2}
+    assert calls == [({'a': 1, 'b': 2}, {'x': 'y'})]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 3, 'b': 2})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'value_error',
+            'loc': (),
+            'message': 'Value error, a must not be greater than b',
+            'input_value': {'a': 3, 'b': 2},
+            'context': {'error': 'a must not be greater than b'},
+        }
+    ]
+    assert calls[-1] == ({'a': 3, 'b': 2}, None)
+
+
+def test_model_class_post_init_nested():
+    class MyModel:
+        __slots__ = '__dict__', '__fields_set__'
+
+        def post_init(self, context):
+            assert self.a != 0, 'a must not be zero'
+
+    v = SchemaValidator(
+        {
+            'type': 'list',
+            'items_schema': {
+                'type': 'model-class',
+                'class_type': MyModel,
+                'post_init': 'post_init',
+                'schema': {'type': 'typed-dict', 'return_fields_set': True, 'fields': {'a': {'schema': 'int'}}},
+            },
+        }
+    )
+    assert len(v.validate_python([{'a': 1}])) == 1
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'a': 1}, {'a': 0}])
+    assert [(e['kind'], e['loc'], e['message']) for e in exc_info.value.errors()] == [
+        ('assertion_error', (1,), 'Assertion failed, a must not be zero')
+    ]
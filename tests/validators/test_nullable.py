@@ -2,6 +2,8 @@ import pytest
 
 from pydantic_core import SchemaValidator, ValidationError
 
+from ..conftest import PyAndJson
+
 
 def test_nullable():
     v = SchemaValidator({'type': 'nullable', 'schema': {'type': 'int'}})
@@ -13,7 +15,7 @@ def test_nullable():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': [],
+            'loc': (),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'hello',
         }
@@ -33,3 +35,42 @@ def test_union_nullable_bool_int():
     assert v.validate_python(None) is None
     assert v.validate_python(True) is True
     assert v.validate_python(1) == 1
+
+
+def test_nullable_repr():
+    v = SchemaValidator({'type': 'nullable', 'schema': {'type': 'int'}})
+    assert repr(v).startswith('SchemaValidator(name="nullable[int]", validator=Nullable(')
+
+
+@pytest.mark.parametrize('inner_schema', ['int', 'float', 'str', 'bool', 'bytes', {'type': 'list'}])
+def test_none_not_coerced(py_and_json: PyAndJson, inner_schema):
+    # None must never reach the inner validator, e.g. it would be coerced to `0` or `""`
+    v = py_and_json({'type': 'nullable', 'schema': inner_schema})
+    assert v.validate_test(None) is None
+
+
+def test_nullable_list(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'list', 'items_schema': {'type': 'nullable', 'schema': {'type': 'int'}}})
+    assert v.validate_test([1, None, '3', None]) == [1, None, 3, None]
+
+
+@pytest.mark.parametrize('items_schema', [{'type': 'nullable', 'schema': 'int'}, {'type': 'none'}])
+@pytest.mark.parametrize('container', ['list', 'tuple', 'set', 'frozenset'])
+def test_container_of_none(py_and_json: PyAndJson, items_schema, container):
+    v = py_and_json({'type': container, 'items_schema': items_schema})
+    output = v.validate_test([None, None])
+    assert list(output) in ([None, None], [None])
+
+
+def test_nullable_list_errors(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'list', 'items_schema': {'type': 'nullable', 'schema': 'int'}})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([None, 'x', None])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': (1,),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
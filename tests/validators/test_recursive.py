@@ -1,7 +1,8 @@
+import platform
 from typing import Optional
 
 import pytest
-from dirty_equals import AnyThing, HasAttributes, IsList, IsPartialDict, IsStr
+from dirty_equals import AnyThing, HasAttributes, IsList, IsPartialDict, IsStr, IsTuple
 
 from pydantic_core import SchemaError, SchemaValidator, ValidationError
 
@@ -62,13 +63,13 @@ def test_nullable_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'none_required',
-            'loc': ['sub_branch', 'none'],
+            'loc': ('sub_branch', 'none'),
             'message': 'Value must be None/null',
             'input_value': {'width': 'wrong'},
         },
         {
             'kind': 'int_parsing',
-            'loc': ['sub_branch', 'typed-dict', 'width'],
+            'loc': ('sub_branch', 'typed-dict', 'width'),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         },
@@ -275,7 +276,7 @@ def test_recursion_branch():
     assert exc_info.value.errors() == [
         {
             'kind': 'recursion_loop',
-            'loc': ['branch'],
+            'loc': ('branch',),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': {'name': 'recursive', 'branch': IsPartialDict(name='recursive')},
         }
@@ -288,7 +289,7 @@ def test_recursion_branch():
     assert exc_info.value.errors() == [
         {
             'kind': 'recursion_loop',
-            'loc': ['branch'],
+            'loc': ('branch',),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': HasAttributes(name='root', branch=AnyThing()),
         }
@@ -310,13 +311,29 @@ def test_recursive_list():
     assert exc_info.value.errors() == [
         {
             'kind': 'recursion_loop',
-            'loc': [0],
+            'loc': (0,),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': [IsList(length=1)],
         }
     ]
 
 
+def test_recursive_list_indirect_cycle():
+    v = SchemaValidator(
+        {'type': 'list', 'ref': 'the-list', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'the-list'}}
+    )
+    a = []
+    b = [a]
+    a.append(b)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(a)
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('recursion_loop', (0, 0))]
+
+    # the same list appearing twice without a cycle is fine
+    c = []
+    assert v.validate_python([c, c]) == [[], []]
+
+
 @pytest.fixture(scope='module')
 def multiple_tuple_schema() -> SchemaValidator:
     return SchemaValidator(
@@ -376,6 +393,32 @@ def test_multiple_tuple_repeat(multiple_tuple_schema: SchemaValidator):
     }
 
 
+def test_shared_sub_trees():
+    v = SchemaValidator(
+        {
+            'ref': 'Node',
+            'type': 'typed-dict',
+            'fields': {
+                'name': {'schema': 'str'},
+                'children': {
+                    'schema': {'type': 'list', 'items_schema': {'type': 'recursive-ref', 'schema_ref': 'Node'}}
+                },
+            },
+        }
+    )
+    # shared (but not cyclic) sub-trees must not be mistaken for a recursion loop
+    node = {'name': 'leaf', 'children': []}
+    for i in range(5):
+        node = {'name': str(i), 'children': [node, node]}
+    output = v.validate_python(node)
+    assert output['name'] == '4'
+    leaf = output
+    for _ in range(5):
+        assert leaf['children'][0] == leaf['children'][1]
+        leaf = leaf['children'][1]
+    assert leaf == {'name': 'leaf', 'children': []}
+
+
 def test_multiple_tuple_recursion(multiple_tuple_schema: SchemaValidator):
     data = [1]
     data.append(data)
@@ -385,13 +428,13 @@ def test_multiple_tuple_recursion(multiple_tuple_schema: SchemaValidator):
     assert exc_info.value.errors() == [
         {
             'kind': 'recursion_loop',
-            'loc': ['f1', 1],
+            'loc': ('f1', 1),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': [1, IsList(length=2)],
         },
         {
             'kind': 'recursion_loop',
-            'loc': ['f2', 1],
+            'loc': ('f2', 1),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': [1, IsList(length=2)],
         },
@@ -407,13 +450,13 @@ def test_multiple_tuple_recursion_once(multiple_tuple_schema: SchemaValidator):
     assert exc_info.value.errors() == [
         {
             'kind': 'recursion_loop',
-            'loc': ['f1', 1],
+            'loc': ('f1', 1),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': [1, IsList(length=2)],
         },
         {
             'kind': 'recursion_loop',
-            'loc': ['f2', 1],
+            'loc': ('f2', 1),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': [1, IsList(length=2)],
         },
@@ -449,7 +492,7 @@ def test_recursive_wrap():
     assert exc_info.value.errors() == [
         {
             'kind': 'recursion_loop',
-            'loc': [1],
+            'loc': (1,),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': IsList(positions={0: 1}, length=2),
         }
@@ -478,8 +521,20 @@ def test_union_ref_strictness():
         v.validate_python({'a': 1, 'b': []})
 
     assert exc_info.value.errors() == [
-        {'kind': 'int_type', 'loc': ['b', 'int'], 'message': 'Value must be a valid integer', 'input_value': []},
-        {'kind': 'str_type', 'loc': ['b', 'str'], 'message': 'Value must be a valid string', 'input_value': []},
+        {
+            'kind': 'int_type',
+            'loc': ('b', 'int'),
+            'message': 'Value must be a valid integer, input is of type `list`',
+            'input_value': [],
+            'context': {'input_type': 'list'},
+        },
+        {
+            'kind': 'str_type',
+            'loc': ('b', 'str'),
+            'message': 'Value must be a valid string, input is of type `list`',
+            'input_value': [],
+            'context': {'input_type': 'list'},
+        },
     ]
 
 
@@ -500,8 +555,20 @@ def test_union_container_strictness():
         v.validate_python({'a': 1, 'b': []})
 
     assert exc_info.value.errors() == [
-        {'kind': 'int_type', 'loc': ['b', 'int'], 'message': 'Value must be a valid integer', 'input_value': []},
-        {'kind': 'str_type', 'loc': ['b', 'str'], 'message': 'Value must be a valid string', 'input_value': []},
+        {
+            'kind': 'int_type',
+            'loc': ('b', 'int'),
+            'message': 'Value must be a valid integer, input is of type `list`',
+            'input_value': [],
+            'context': {'input_type': 'list'},
+        },
+        {
+            'kind': 'str_type',
+            'loc': ('b', 'str'),
+            'message': 'Value must be a valid string, input is of type `list`',
+            'input_value': [],
+            'context': {'input_type': 'list'},
+        },
     ]
 
 
@@ -536,7 +603,7 @@ def test_union_cycle(strict: bool):
     assert exc_info.value.errors() == [
         {
             'kind': 'recursion_loop',
-            'loc': ['typed-dict', 'foobar', 0],
+            'loc': ('typed-dict', 'foobar', 0),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': {'foobar': [{'foobar': IsList(length=1)}]},
         }
@@ -571,13 +638,13 @@ def test_function_name():
     assert exc_info.value.errors() == [
         {
             'kind': 'recursion_loop',
-            'loc': ['function-after[...]'],
+            'loc': ('function-after[...]',),
             'message': 'Recursion error - cyclic reference detected',
             'input_value': 'input value',
         },
         {
             'kind': 'int_parsing',
-            'loc': ['int'],
+            'loc': ('int',),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'input value',
         },
@@ -611,10 +678,11 @@ def test_function_change_id(strict: bool):
 
     assert exc_info.value.errors() == [
         {
-            'kind': 'recursion_loop',
-            'loc': IsList(length=(1, 255)),
-            'message': 'Recursion error - cyclic reference detected',
+            'kind': 'too_deep',
+            'loc': IsTuple(length=(1, 255)),
+            'message': 'Recursion error - maximum depth of 255 exceeded',
             'input_value': IsStr(regex=r'f-\d+'),
+            'context': {'max_depth': 255},
         }
     ]
 
@@ -647,3 +715,81 @@ def test_many_uses_of_ref():
 
     long_input = {'name': 'Anne', 'other_names': [f'p-{i}' for i in range(300)]}
     assert v.validate_python(long_input) == long_input
+
+
+@pytest.fixture(scope='module')
+def nested_list_schema():
+    return {
+        'type': 'list',
+        'ref': 'nested-list',
+        'items_schema': {'type': 'recursive-ref', 'schema_ref': 'nested-list'},
+    }
+
+
+def deeply_nested_list(depth: int):
+    data = []
+    for _ in range(depth):
+        data = [data]
+    return data
+
+
+def test_max_recursion_depth(nested_list_schema):
+    v = SchemaValidator(nested_list_schema, {'max_recursion_depth': 50})
+    data = deeply_nested_list(40)
+    assert v.validate_python(data) == data
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(deeply_nested_list(2000))
+    errors = exc_info.value.errors()
+    assert len(errors) == 1
+    assert errors[0]['kind'] == 'too_deep'
+    assert errors[0]['message'] == 'Recursion error - maximum depth of 50 exceeded'
+    assert errors[0]['context'] == {'max_depth': 50}
+    assert len(errors[0]['loc']) == 50
+
+
+@pytest.mark.skipif(platform.python_implementation() == 'PyPy', reason='lower ceiling on pypy')
+def test_max_recursion_depth_ceiling(nested_list_schema):
+    with pytest.raises(SchemaError, match='max_recursion_depth must be no greater than 1000, got 1001'):
+        SchemaValidator(nested_list_schema, {'max_recursion_depth': 1001})
+
+    # the largest allowed limit still raises a clean error rather than overflowing the stack
+    v = SchemaValidator(nested_list_schema, {'max_recursion_depth': 1000})
+    data = deeply_nested_list(900)
+    assert v.validate_python(data) == data
+    with pytest.raises(ValidationError, match=r'maximum depth of 1000 exceeded \[kind=too_deep'):
+        v.validate_python(deeply_nested_list(5000))
+
+
+def test_max_recursion_depth_default(nested_list_schema):
+    v = SchemaValidator(nested_list_schema)
+    data = deeply_nested_list(200)
+    assert v.validate_python(data) == data
+
+    with pytest.raises(ValidationError, match=r'maximum depth of \d+ exceeded \[kind=too_deep'):
+        v.validate_python(deeply_nested_list(2000))
+
+
+def test_max_recursion_depth_json(nested_list_schema):
+    v = SchemaValidator(nested_list_schema, {'max_recursion_depth': 10})
+    assert v.validate_json('[[[[]]]]') == [[[[]]]]
+
+    with pytest.raises(ValidationError, match=r'maximum depth of 10 exceeded \[kind=too_deep'):
+        v.validate_json('[' * 20 + ']' * 20)
+
+
+def test_deeply_nested_json(nested_list_schema):
+    # the JSON parser limits nesting to 128 levels, so very deep documents fail cleanly before validation
+    v = SchemaValidator(nested_list_schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[' * 5000 + ']' * 5000)
+    errors = exc_info.value.errors(max_input_length=10)
+    assert len(errors) == 1
+    assert errors[0]['kind'] == 'invalid_json'
+    assert errors[0]['context']['error'] == 'recursion limit exceeded at line 1 column 128'
+
+    # below the parser's limit, the depth guard applies to JSON as it does to python input
+    v = SchemaValidator(nested_list_schema, {'max_recursion_depth': 100})
+    assert v.validate_json('[' * 100 + ']' * 100) == deeply_nested_list(99)
+    with pytest.raises(ValidationError, match=r'maximum depth of 100 exceeded \[kind=too_deep'):
+        v.validate_json('[' * 120 + ']' * 120)
@@ -91,12 +91,24 @@ def test_set_multiple_errors():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': [0],
+            'loc': (0,),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'a',
         },
-        {'kind': 'int_type', 'loc': [1], 'message': 'Value must be a valid integer', 'input_value': (1, 2)},
-        {'kind': 'int_type', 'loc': [2], 'message': 'Value must be a valid integer', 'input_value': []},
+        {
+            'kind': 'int_type',
+            'loc': (1,),
+            'message': 'Value must be a valid integer, input is of type `tuple`',
+            'input_value': (1, 2),
+            'context': {'input_type': 'tuple'},
+        },
+        {
+            'kind': 'int_type',
+            'loc': (2,),
+            'message': 'Value must be a valid integer, input is of type `list`',
+            'input_value': [],
+            'context': {'input_type': 'list'},
+        },
     ]
 
 
@@ -148,16 +160,18 @@ def test_union_set_list(input_value, expected):
                 errors=[
                     {
                         'kind': 'int_type',
-                        'loc': ['set[int]', 1],
-                        'message': 'Value must be a valid integer',
+                        'loc': ('set[int]', 1),
+                        'message': 'Value must be a valid integer, input is of type `str`',
                         'input_value': 'a',
+                        'context': {'input_type': 'str'},
                     },
                     # second because validation on the string choice comes second
                     {
                         'kind': 'str_type',
-                        'loc': ['set[str]', 0],
-                        'message': 'Value must be a valid string',
+                        'loc': ('set[str]', 0),
+                        'message': 'Value must be a valid string, input is of type `int`',
                         'input_value': 1,
+                        'context': {'input_type': 'int'},
                     },
                 ],
             ),
@@ -187,3 +201,41 @@ def test_set_as_dict_keys(py_and_json: PyAndJson):
     v = py_and_json({'type': 'dict', 'keys_schema': {'type': 'set'}, 'values_schema': 'int'})
     with pytest.raises(ValidationError, match=re.escape('Value must be a valid set')):
         v.validate_test({'foo': 'bar'})
+
+
+@pytest.mark.parametrize('fail_fast,error_count', [(False, 2), (True, 1)])
+def test_set_fail_fast(py_and_json: PyAndJson, fail_fast, error_count):
+    v = py_and_json({'type': 'set', 'items_schema': 'int', 'fail_fast': fail_fast})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([1, 'a', 2, 'b'])
+    assert exc_info.value.error_count() == error_count
+
+
+def test_set_unhashable_item(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'set', 'items_schema': {'type': 'list', 'items_schema': 'int'}})
+    assert v.validate_test([]) == set()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test([[1, 2], [3]])
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'set_item_not_hashable',
+            'loc': (0,),
+            'message': 'Set items must be hashable, input is of type `list`',
+            'input_value': [1, 2],
+            'context': {'input_type': 'list'},
+        },
+        {
+            'kind': 'set_item_not_hashable',
+            'loc': (1,),
+            'message': 'Set items must be hashable, input is of type `list`',
+            'input_value': [3],
+            'context': {'input_type': 'list'},
+        },
+    ]
+
+
+def test_set_unhashable_item_any():
+    v = SchemaValidator({'type': 'set'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, {'a': 1}, 2])
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('set_item_not_hashable', (1,))]
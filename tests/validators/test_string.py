@@ -1,5 +1,6 @@
 import re
 from decimal import Decimal
+from enum import Enum, IntEnum
 from typing import Any, Dict
 
 import pytest
@@ -35,10 +36,19 @@ def test_str(py_and_json: PyAndJson, input_value, expected):
         ('foobar', 'foobar'),
         (b'foobar', 'foobar'),
         (bytearray(b'foobar'), 'foobar'),
-        (b'\x81', Err('Value must be a valid string, unable to parse raw data as a unicode string [kind=str_unicode')),
+        (
+            b'\x81',
+            Err(
+                'Value must be a valid string, unable to parse raw data as a unicode string at position 0 '
+                '[kind=str_unicode'
+            ),
+        ),
         (
             bytearray(b'\x81'),
-            Err('Value must be a valid string, unable to parse raw data as a unicode string [kind=str_unicode'),
+            Err(
+                'Value must be a valid string, unable to parse raw data as a unicode string at position 0 '
+                '[kind=str_unicode'
+            ),
         ),
         # null bytes are very annoying, but we can't really block them here
         (b'\x00', '\x00'),
@@ -55,6 +65,95 @@ def test_str_not_json(input_value, expected):
         assert v.validate_python(input_value) == expected
 
 
+def test_str_unicode_position():
+    v = SchemaValidator({'type': 'str'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'caf\xe9 au lait')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'str_unicode',
+            'loc': (),
+            'message': 'Value must be a valid string, unable to parse raw data as a unicode string at position 3',
+            'input_value': b'caf\xe9 au lait',
+            'context': {'position': 3},
+        }
+    ]
+
+
+@pytest.mark.parametrize('schema_extra', [{}, {'max_length': 100}])
+def test_str_encoding(schema_extra):
+    v = SchemaValidator({'type': 'str', 'encoding': 'latin-1', **schema_extra})
+    assert v.validate_python(b'caf\xe9') == 'caf\xe9'
+    assert v.validate_python(bytearray(b'caf\xe9')) == 'caf\xe9'
+    assert v.validate_python('caf\xe9') == 'caf\xe9'
+    assert v.validate_json('"caf\xe9"') == 'caf\xe9'
+    with pytest.raises(ValidationError, match='kind=str_type'):
+        v.validate_python(b'caf\xe9', strict=True)
+
+
+def test_str_encoding_error():
+    v = SchemaValidator({'type': 'str', 'encoding': 'ascii'})
+    assert v.validate_python(b'cafe') == 'cafe'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'caf\xe9')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'str_unicode',
+            'loc': (),
+            'message': 'Value must be a valid string, unable to parse raw data as a unicode string at position 3',
+            'input_value': b'caf\xe9',
+            'context': {'position': 3},
+        }
+    ]
+
+
+def test_str_encoding_utf8():
+    v = SchemaValidator({'type': 'str', 'encoding': 'UTF8'})
+    assert v.validate_python('caf\xe9'.encode()) == 'caf\xe9'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'ab\xff')
+    assert exc_info.value.errors()[0]['context'] == {'position': 2}
+
+
+def test_str_encoding_unknown():
+    with pytest.raises(SchemaError, match="Unknown encoding: 'klingon'"):
+        SchemaValidator({'type': 'str', 'encoding': 'klingon'})
+
+
+@pytest.mark.parametrize('encoding', ['base64', 'hex', 'zlib', 'rot13'])
+def test_str_encoding_not_text(encoding):
+    with pytest.raises(SchemaError, match=f"Encoding '{encoding}' is not a text encoding"):
+        SchemaValidator({'type': 'str', 'encoding': encoding})
+
+
+@pytest.mark.parametrize('input_value', [b'hi', bytearray(b'hi'), 123, 1.5, Decimal('1')])
+def test_str_strict_python(input_value):
+    v = SchemaValidator({'type': 'str', 'strict': True})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors()[0]['kind'] == 'str_type'
+
+    v = SchemaValidator({'type': 'str'})
+    with pytest.raises(ValidationError, match=r'Value must be a valid string.*\[kind=str_type'):
+        v.validate_python(input_value, strict=True)
+
+
+def test_str_strict_bytes_error():
+    v = SchemaValidator({'type': 'str', 'strict': True})
+    assert v.validate_python('hi') == 'hi'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'hi')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'str_type',
+            'loc': (),
+            'message': 'Value must be a valid string, input is of type `bytes`',
+            'input_value': b'hi',
+            'context': {'input_type': 'bytes'},
+        }
+    ]
+
+
 @pytest.mark.parametrize(
     'kwargs,input_value,expected',
     [
@@ -69,14 +168,26 @@ def test_str_not_json(input_value, expected):
         ({'min_length': 5}, '1234', Err('String must have at least 5 characters [kind=too_short')),
         ({'max_length': 5}, '12345', '12345'),
         ({'max_length': 5}, '123456', Err('String must have at most 5 characters [kind=too_long')),
+        # lengths are counted in characters, not bytes
+        ({'min_length': 3}, 'ñüé', 'ñüé'),
+        ({'min_length': 4}, 'ñüé', Err('String must have at least 4 characters [kind=too_short')),
+        ({'max_length': 5}, '🐈🐈🐈🐈🐈', '🐈🐈🐈🐈🐈'),
+        ({'max_length': 5}, '🐈🐈🐈🐈🐈🐈', Err('String must have at most 5 characters [kind=too_long')),
         ({'pattern': r'^\d+$'}, '12345', '12345'),
         ({'pattern': r'\d+$'}, 'foobar 123', 'foobar 123'),
         ({'pattern': r'^\d+$'}, '12345a', Err("String must match pattern '^\\d+$' [kind=str_pattern_mismatch")),
-        # strip comes after length check
-        ({'max_length': 5, 'strip_whitespace': True}, '1234  ', Err('String must have at most 5 characters')),
-        # to_upper and strip comes after pattern check
-        ({'to_upper': True, 'pattern': 'abc'}, 'abc', 'ABC'),
-        ({'strip_whitespace': True, 'pattern': r'\d+$'}, 'foobar 123 ', Err("String must match pattern '\\d+$'")),
+        # strip comes before length check
+        ({'max_length': 5, 'strip_whitespace': True}, '1234  ', '1234'),
+        ({'min_length': 5, 'strip_whitespace': True}, ' 1234 ', Err('String must have at least 5 characters')),
+        # case-folding comes before length check, 'ß'.upper() is 'SS'
+        ({'max_length': 1, 'to_upper': True}, 'ß', Err('String must have at most 1 characters')),
+        ({'max_length': 2, 'to_upper': True}, 'ß', 'SS'),
+        # to_upper, to_lower and strip come before the pattern check
+        ({'to_upper': True, 'pattern': 'ABC'}, 'abc', 'ABC'),
+        ({'to_upper': True, 'pattern': 'abc'}, 'abc', Err("String must match pattern 'abc'")),
+        ({'to_lower': True, 'pattern': '^abc$'}, 'ABC', 'abc'),
+        ({'strip_whitespace': True, 'pattern': r'\d+$'}, 'foobar 123 ', 'foobar 123'),
+        ({'strip_whitespace': True, 'to_lower': True, 'pattern': r'^foo$', 'max_length': 3}, ' FOO ', 'foo'),
     ],
 )
 def test_constrained_str(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
@@ -96,6 +207,22 @@ def test_str_constrained():
         v.validate_python('test long')
 
 
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected_context',
+    [
+        ({'min_length': 5}, '1234', {'min_length': 5, 'input_length': 4}),
+        ({'max_length': 5}, '123456', {'max_length': 5, 'input_length': 6}),
+        ({'max_length': 2}, 'ñüé', {'max_length': 2, 'input_length': 3}),
+        ({'min_length': 5, 'strip_whitespace': True}, ' 1234 ', {'min_length': 5, 'input_length': 4}),
+    ],
+)
+def test_str_length_ctx(kwargs: Dict[str, Any], input_value, expected_context):
+    v = SchemaValidator({'type': 'str', **kwargs})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors()[0]['context'] == expected_context
+
+
 def test_str_constrained_config():
     v = SchemaValidator({'type': 'str'}, {'str_max_length': 5})
     assert v.validate_python('test') == 'test'
@@ -104,6 +231,61 @@ def test_str_constrained_config():
         v.validate_python('test long')
 
 
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        ({}, 123, '123'),
+        ({}, 1.5, '1.5'),
+        ({'coerce_numbers_to_str': True}, 123, '123'),
+        ({'coerce_numbers_to_str': True}, 2**70, '1180591620717411303424'),
+        ({'coerce_numbers_to_str': False}, 'foobar', 'foobar'),
+        ({'coerce_numbers_to_str': False}, 123, Err('Value must be a valid string [kind=str_type')),
+        ({'coerce_numbers_to_str': False}, 1.5, Err('Value must be a valid string [kind=str_type')),
+        ({'coerce_numbers_to_str': False}, 2**64, Err('Value must be a valid string [kind=str_type')),
+        ({'coerce_numbers_to_str': False}, True, Err('Value must be a valid string [kind=str_type')),
+        ({'coerce_numbers_to_str': False, 'max_length': 5}, 'test', 'test'),
+        ({'coerce_numbers_to_str': False, 'max_length': 5}, 123, Err('Value must be a valid string [kind=str_type')),
+        ({'coerce_numbers_to_str': True, 'max_length': 5}, 123, '123'),
+    ],
+)
+def test_coerce_numbers_to_str(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
+    v = py_and_json({'type': 'str', **kwargs})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_coerce_numbers_to_str_config():
+    v = SchemaValidator({'type': 'str'}, {'str_coerce_numbers_to_str': False})
+    assert v.validate_python('123') == '123'
+    assert v.validate_python(b'123') == '123'
+    with pytest.raises(ValidationError, match='kind=str_type'):
+        v.validate_python(123)
+    with pytest.raises(ValidationError, match='kind=str_type'):
+        v.validate_json('123')
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,unchanged',
+    [
+        ({}, 'foobar', True),
+        ({'max_length': 10}, 'foobar', True),
+        ({'strip_whitespace': True}, 'foobar', True),
+        ({'strip_whitespace': True}, ' foobar ', False),
+        ({'to_lower': True}, 'foobar', True),
+        ({'to_lower': True}, 'FooBar', False),
+        ({'to_upper': True}, 'FOOBAR', True),
+        ({'to_upper': True}, 'foobar', False),
+    ],
+)
+def test_str_returned_unchanged(kwargs: Dict[str, Any], input_value, unchanged):
+    # the input string is returned as is unless a transformation actually changed it
+    v = SchemaValidator({'type': 'str', **kwargs})
+    assert (v.validate_python(input_value) is input_value) == unchanged
+
+
 def test_invalid_regex():
     # TODO uncomment and fix once #150 is done
     # with pytest.raises(SchemaError) as exc_info:
@@ -122,6 +304,20 @@ def test_invalid_regex():
     )
 
 
+def test_regex_not_anchored():
+    # like `re.search`, the pattern can match anywhere in the string unless it's anchored with `^` and `$`
+    v = SchemaValidator({'type': 'str', 'pattern': r'\d{3}'})
+    assert v.validate_python('abc 123 def') == 'abc 123 def'
+    assert v.validate_python('123') == '123'
+    with pytest.raises(ValidationError, match='String must match pattern'):
+        v.validate_python('abc 12 def')
+
+    v = SchemaValidator({'type': 'str', 'pattern': r'^\d{3}$'})
+    assert v.validate_python('123') == '123'
+    with pytest.raises(ValidationError, match='String must match pattern'):
+        v.validate_python('abc 123 def')
+
+
 def test_regex_error():
     v = SchemaValidator({'type': 'str', 'pattern': '11'})
     with pytest.raises(ValidationError) as exc_info:
@@ -129,9 +325,128 @@ def test_regex_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'str_pattern_mismatch',
-            'loc': [],
+            'loc': (),
             'message': "String must match pattern '11'",
             'input_value': '12',
             'context': {'pattern': '11'},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('red', 'red'),
+        ('RED', 'red'),
+        (' Green ', 'green'),
+        ('blue', Err("String must be one of: 'red', 'green' [kind=str_not_in_allowed, input_value='blue'")),
+        ('reddish', Err("String must be one of: 'red', 'green' [kind=str_not_in_allowed")),
+    ],
+)
+def test_allowed_case_normalized(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'str', 'allowed': ['red', 'green'], 'to_lower': True, 'strip_whitespace': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_allowed_errors():
+    v = SchemaValidator({'type': 'str', 'allowed': ['a', 'b']})
+    assert v.validate_python('a') == 'a'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('A')
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'str_not_in_allowed',
+            'loc': (),
+            'message': "String must be one of: 'a', 'b'",
+            'input_value': 'A',
+            'context': {'allowed': "'a', 'b'"},
+        }
+    ]
+
+
+def test_allowed_number_coercion():
+    v = SchemaValidator({'type': 'str', 'allowed': ['1', '2']})
+    assert v.validate_python(1) == '1'
+    with pytest.raises(ValidationError, match='kind=str_not_in_allowed'):
+        v.validate_python(3)
+
+
+def test_allowed_empty():
+    with pytest.raises(SchemaError, match="'allowed' must contain at least one string"):
+        SchemaValidator({'type': 'str', 'allowed': []})
+
+
+@pytest.mark.parametrize(
+    'schema,config',
+    [
+        ({'to_lower': True, 'to_upper': True}, None),
+        ({'to_lower': True}, {'str_to_upper': True}),
+        ({}, {'str_to_lower': True, 'str_to_upper': True}),
+    ],
+)
+def test_to_lower_and_to_upper(schema, config):
+    with pytest.raises(SchemaError, match="'to_lower' and 'to_upper' cannot both be set"):
+        SchemaValidator({'type': 'str', **schema}, config)
+
+
+class Colour(Enum):
+    RED = 'red'
+    ONE = 1
+
+
+class StrColour(str, Enum):
+    BLUE = 'blue'
+
+
+class Number(IntEnum):
+    TWO = 2
+
+
+@pytest.mark.parametrize('schema', [{'type': 'str'}, {'type': 'str', 'max_length': 10}])
+def test_enum_value(schema):
+    v = SchemaValidator(schema)
+    output = v.validate_python(Colour.RED)
+    assert output == 'red'
+    assert type(output) is str
+    # str subclasses are validated as before
+    assert v.validate_python(StrColour.BLUE) is StrColour.BLUE
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Colour.ONE)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'str_type',
+            'loc': (),
+            'message': 'Value must be a valid string, input is of type `Colour`',
+            'input_value': Colour.ONE,
+            'context': {'input_type': 'Colour'},
+        }
+    ]
+    # int enum members have a non-str value so are rejected rather than stringified
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Number.TWO)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'str_type',
+            'loc': (),
+            'message': 'Value must be a valid string, input is of type `Number`',
+            'input_value': Number.TWO,
+            'context': {'input_type': 'Number'},
+        }
+    ]
+
+
+def test_enum_value_strict():
+    v = SchemaValidator({'type': 'str', 'strict': True})
+    with pytest.raises(ValidationError, match='kind=str_type'):
+        v.validate_python(Colour.RED)
+
+
+def test_str_validate_identity():
+    v = SchemaValidator({'type': 'str'})
+    input_value = 'hello world'
+    assert v.validate_python(input_value) is input_value
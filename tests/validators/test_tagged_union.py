@@ -1,4 +1,5 @@
 import pytest
+from dirty_equals import IsStr
 
 from pydantic_core import SchemaValidator, ValidationError
 
@@ -18,7 +19,7 @@ from .test_typed_dict import Cls
                 [
                     {
                         'kind': 'int_parsing',
-                        'loc': ['apple', 'bar'],
+                        'loc': ('apple', 'bar'),
                         'message': 'Value must be a valid integer, unable to parse string as an integer',
                         'input_value': 'wrong',
                     }
@@ -32,7 +33,7 @@ from .test_typed_dict import Cls
                 [
                     {
                         'kind': 'missing',
-                        'loc': ['banana', 'spam'],
+                        'loc': ('banana', 'spam'),
                         'message': 'Field required',
                         'input_value': {'foo': 'banana'},
                     }
@@ -46,7 +47,7 @@ from .test_typed_dict import Cls
                 [
                     {
                         'kind': 'union_tag_invalid',
-                        'loc': [],
+                        'loc': (),
                         'message': (
                             "Input tag 'other' found using 'foo' does not match any "
                             "of the expected tags: 'apple', 'banana'"
@@ -64,7 +65,7 @@ from .test_typed_dict import Cls
                 [
                     {
                         'kind': 'union_tag_not_found',
-                        'loc': [],
+                        'loc': (),
                         'message': "Unable to extract tag using discriminator 'foo'",
                         'input_value': {},
                         'context': {'discriminator': "'foo'"},
@@ -79,9 +80,11 @@ from .test_typed_dict import Cls
                 [
                     {
                         'kind': 'dict_type',
-                        'loc': [],
-                        'message': 'Value must be a valid dictionary',
+                        'loc': (),
+                        'message': IsStr(regex='Value must be a valid dictionary, input is of type `str(ing)?`'),
                         'input_value': 'not a dict',
+                        # the input type is "str" for python input and "string" for JSON input
+                        'context': {'input_type': IsStr(regex='str(ing)?')},
                     }
                 ],
             ),
@@ -134,7 +137,7 @@ def test_discriminator_path(py_and_json: PyAndJson):
     assert exc_info.value.errors() == [
         {
             'kind': 'union_tag_not_found',
-            'loc': [],
+            'loc': (),
             'message': "Unable to extract tag using discriminator 'food' | 'menu'.1",
             'input_value': {},
             'context': {'discriminator': "'food' | 'menu'.1"},
@@ -154,7 +157,7 @@ def test_discriminator_path(py_and_json: PyAndJson):
                 [
                     {
                         'kind': 'literal_error',
-                        'loc': ['str'],
+                        'loc': ('str',),
                         'message': "Value must be one of: 'foo', 'bar'",
                         'input_value': 'baz',
                         'context': {'expected': "'foo', 'bar'"},
@@ -169,7 +172,7 @@ def test_discriminator_path(py_and_json: PyAndJson):
                 [
                     {
                         'kind': 'union_tag_not_found',
-                        'loc': [],
+                        'loc': (),
                         'message': 'Unable to extract tag using discriminator discriminator_function()',
                         'input_value': None,
                         'context': {'discriminator': 'discriminator_function()'},
@@ -184,7 +187,7 @@ def test_discriminator_path(py_and_json: PyAndJson):
                 [
                     {
                         'kind': 'union_tag_invalid',
-                        'loc': [],
+                        'loc': (),
                         'message': (
                             "Input tag 'other' found using discriminator_function() "
                             "does not match any of the expected tags: 'str', 'int'"
@@ -269,3 +272,26 @@ def test_downcast_error():
     v = SchemaValidator({'type': 'tagged-union', 'discriminator': lambda x: 123, 'choices': {'str': 'str'}})
     with pytest.raises(TypeError, match="'int' object cannot be converted to 'PyString'"):
         v.validate_python('x')
+
+
+def test_only_tagged_choice_called():
+    calls = []
+
+    def make_choice(tag):
+        def f(input_value, **kwargs):
+            calls.append(tag)
+            return tag
+
+        return {'type': 'function', 'mode': 'plain', 'function': f}
+
+    tags = [f'tag_{i}' for i in range(20)]
+    v = SchemaValidator(
+        {'type': 'tagged-union', 'discriminator': 'kind', 'choices': {tag: make_choice(tag) for tag in tags}}
+    )
+    assert v.validate_python({'kind': 'tag_17'}) == 'tag_17'
+    assert v.validate_json('{"kind": "tag_3"}') == 'tag_3'
+    assert calls == ['tag_17', 'tag_3']
+
+    with pytest.raises(ValidationError, match='kind=union_tag_invalid'):
+        v.validate_python({'kind': 'tag_20'})
+    assert calls == ['tag_17', 'tag_3']
@@ -1,5 +1,5 @@
 import re
-from datetime import date, datetime, time
+from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal
 from typing import Any, Dict
 
@@ -159,7 +159,7 @@ def test_time_bound_ctx():
     assert exc_info.value.errors() == [
         {
             'kind': 'greater_than',
-            'loc': [],
+            'loc': (),
             'message': 'Value must be greater than 12:13:14.123456',
             'input_value': '12:13',
             'context': {'gt': '12:13:14.123456'},
@@ -190,3 +190,65 @@ def test_union():
     v = SchemaValidator({'type': 'union', 'choices': ['time', 'str']})
     assert v.validate_python('12:01:02') == '12:01:02'
     assert v.validate_python(time(12, 1, 2)) == time(12, 1, 2)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected_offset',
+    [
+        ('12:13:14', None),
+        ('12:13:14Z', timedelta(0)),
+        ('12:13:14+05:00', timedelta(hours=5)),
+        ('12:13:14.123-0130', -timedelta(hours=1, minutes=30)),
+        ('12:13+02', timedelta(hours=2)),
+    ],
+)
+def test_time_tz(py_and_json: PyAndJson, input_value, expected_offset):
+    v = py_and_json({'type': 'time'})
+    output = v.validate_test(input_value)
+    assert output.utcoffset() == expected_offset
+
+
+@pytest.mark.parametrize('input_value', ['12:13:14+24:00', '12:13:14+05:60', '12:13:14+5', '12:13:14Z+01', '12:13:14+'])
+def test_time_tz_invalid(py_and_json: PyAndJson, input_value):
+    v = py_and_json({'type': 'time'})
+    with pytest.raises(ValidationError, match='Value must be in a valid time format, invalid timezone offset'):
+        v.validate_test(input_value)
+
+
+@pytest.mark.parametrize(
+    'schema_extra,input_value,expected',
+    [
+        ({'require_tz': True}, '12:13:14+05:00', time(12, 13, 14, tzinfo=timezone(timedelta(hours=5)))),
+        ({'require_tz': True}, '12:13:14', Err('Time must have timezone information [kind=time_tz_required')),
+        ({'forbid_tz': True}, '12:13:14', time(12, 13, 14)),
+        ({'forbid_tz': True}, '12:13:14Z', Err('Time must not have timezone information [kind=time_tz_forbidden')),
+        ({}, '12:13:14Z', time(12, 13, 14, tzinfo=timezone.utc)),
+        ({}, '12:13:14', time(12, 13, 14)),
+    ],
+)
+def test_time_tz_constraint(py_and_json: PyAndJson, schema_extra, input_value, expected):
+    v = py_and_json({'type': 'time', **schema_extra})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert output.utcoffset() == expected.utcoffset()
+
+
+def test_time_tz_constraint_python():
+    v = SchemaValidator({'type': 'time', 'require_tz': True})
+    aware = time(12, 13, 14, tzinfo=timezone.utc)
+    assert v.validate_python(aware) is aware
+    with pytest.raises(ValidationError, match='kind=time_tz_required'):
+        v.validate_python(time(12, 13, 14))
+
+    v = SchemaValidator({'type': 'time', 'forbid_tz': True})
+    with pytest.raises(ValidationError, match='kind=time_tz_forbidden'):
+        v.validate_python(aware)
+
+
+def test_time_tz_constraint_both():
+    with pytest.raises(SchemaError, match="'require_tz' and 'forbid_tz' cannot be used together"):
+        SchemaValidator({'type': 'time', 'require_tz': True, 'forbid_tz': True})
@@ -51,6 +51,17 @@ def test_timedelta(input_value, expected):
     [
         ('"P0Y0M3D2WT1H2M3.5S"', timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3, milliseconds=500)),
         ('"errordata"', Err('Value must be a valid timedelta, invalid digit in duration [kind=time_delta_parsing')),
+        ('"-P1DT2.5S"', -timedelta(days=1, seconds=2.5)),
+        ('"PT1M0.000001S"', timedelta(minutes=1, microseconds=1)),
+        (
+            '"P3Y6M4DT12H30M5S"',
+            Err(
+                'Value must be a valid timedelta, years and months are not supported since their length varies '
+                '[kind=time_delta_parsing'
+            ),
+        ),
+        ('"P1M"', Err('years and months are not supported')),
+        ('"P0.5Y"', Err('years and months are not supported')),
         ('true', Err('Value must be a valid timedelta [kind=time_delta_type')),
         ('3601', timedelta(hours=1, seconds=1)),
         ('3601.123456', timedelta(hours=1, seconds=1, microseconds=123456)),
@@ -98,6 +109,10 @@ def test_timedelta_strict(input_value, expected):
         ('"P0Y0M3D2WT1H2M3.5S"', timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3, milliseconds=500)),
         ('"12345"', Err('Value must be a valid timedelta')),
         ('true', Err('Value must be a valid timedelta [kind=time_delta_type')),
+        ('3601', Err('Value must be a valid timedelta [kind=time_delta_type')),
+        ('3601.5', Err('Value must be a valid timedelta [kind=time_delta_type')),
+        ('"-PT3601.5S"', -timedelta(seconds=3601.5)),
+        ('"P3Y"', Err('years and months are not supported since their length varies [kind=time_delta_parsing')),
     ],
 )
 def test_timedelta_strict_json(input_value, expected):
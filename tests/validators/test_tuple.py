@@ -60,6 +60,18 @@ def test_tuple_strict_passes_with_tuple(mode, items, input_value, expected):
     assert v.validate_python(input_value) == expected
 
 
+@pytest.mark.parametrize(
+    'mode,items', [('variable', {'type': 'int'}), ('positional', [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}])]
+)
+def test_tuple_strict_json_array(mode, items):
+    # JSON has no tuples, so arrays are accepted even in strict mode while python lists are only accepted in lax mode
+    v = SchemaValidator({'type': 'tuple', 'mode': mode, 'items_schema': items, 'strict': True})
+    assert v.validate_json('[1, 2, 3]') == (1, 2, 3)
+    with pytest.raises(ValidationError, match='kind=tuple_type'):
+        v.validate_python([1, 2, 3])
+    assert v.validate_python([1, 2, 3], strict=False) == (1, 2, 3)
+
+
 @pytest.mark.parametrize(
     'mode,items', [('variable', {'type': 'int'}), ('positional', [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}])]
 )
@@ -71,9 +83,10 @@ def test_tuple_strict_fails_without_tuple(wrong_coll_type: Type[Any], mode, item
     assert exc_info.value.errors() == [
         {
             'kind': 'tuple_type',
-            'loc': [],
-            'message': 'Value must be a valid tuple',
+            'loc': (),
+            'message': f'Value must be a valid tuple, input is of type `{wrong_coll_type.__name__}`',
             'input_value': wrong_coll_type([1, 2, '33']),
+            'context': {'input_type': wrong_coll_type.__name__},
         }
     ]
 
@@ -131,7 +144,7 @@ def test_tuple_var_len_errors(input_value, index):
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': [index],
+            'loc': (index,),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         }
@@ -160,7 +173,7 @@ def test_tuple_fix_len_errors(input_value, items, index):
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': [index],
+            'loc': (index,),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         }
@@ -170,15 +183,33 @@ def test_tuple_fix_len_errors(input_value, items, index):
 @pytest.mark.parametrize(
     'items,input_value,expected',
     [
-        ([{'type': 'int'}], [1, 2, 3], Err('Input must have at most 1 items [kind=too_long')),
-        ([{'type': 'int'}, {'type': 'int'}], [1], Err('Input must have at least 2 items [kind=too_short')),
+        (
+            [{'type': 'int'}],
+            [1, 2, 3],
+            Err('Tuple must have exactly 1 items, got 3 [kind=tuple_length_mismatch'),
+        ),
+        (
+            [{'type': 'int'}, {'type': 'int'}],
+            [1],
+            Err('Tuple must have exactly 2 items, got 1 [kind=tuple_length_mismatch'),
+        ),
+        ([{'type': 'int'}, {'type': 'int'}], [], Err('Tuple must have exactly 2 items, got 0')),
     ],
-    ids=['input too long', 'input too short'],
+    ids=['input too long', 'input too short', 'input empty'],
 )
-def test_tuple_fix_len_input_and_schemas_len_mismatch(items, input_value, expected):
-    v = SchemaValidator({'type': 'tuple', 'mode': 'positional', 'items_schema': items})
+def test_tuple_fix_len_input_and_schemas_len_mismatch(py_and_json: PyAndJson, items, input_value, expected):
+    v = py_and_json({'type': 'tuple', 'mode': 'positional', 'items_schema': items})
     with pytest.raises(ValidationError, match=re.escape(expected.message)):
-        v.validate_python(input_value)
+        v.validate_test(input_value)
+
+
+@pytest.mark.parametrize('input_value', [(1, 'a', 1.5), [1, 'a', 1.5], [1, '2', '3.5']])
+def test_tuple_fix_len_correct_arity(py_and_json: PyAndJson, input_value):
+    v = py_and_json({'type': 'tuple', 'mode': 'positional', 'items_schema': ['int', 'str', 'float']})
+    output = v.validate_test(input_value)
+    assert output[0] == 1
+    assert isinstance(output, tuple)
+    assert len(output) == 3
 
 
 def test_tuple_fix_len_schema_error():
@@ -206,16 +237,18 @@ def test_union_tuple_list(input_value, expected):
                     {
                         # first of all, not a tuple of ints ..
                         'kind': 'tuple_type',
-                        'loc': ['tuple[int, ...]'],
-                        'message': 'Value must be a valid tuple',
+                        'loc': ('tuple[int, ...]',),
+                        'message': 'Value must be a valid tuple, input is of type `list`',
                         'input_value': [5],
+                        'context': {'input_type': 'list'},
                     },
                     # .. and not a tuple of strings, either
                     {
                         'kind': 'tuple_type',
-                        'loc': ['tuple[str, ...]'],
-                        'message': 'Value must be a valid tuple',
+                        'loc': ('tuple[str, ...]',),
+                        'message': 'Value must be a valid tuple, input is of type `list`',
                         'input_value': [5],
+                        'context': {'input_type': 'list'},
                     },
                 ],
             ),
@@ -254,15 +287,17 @@ def test_union_tuple_var_len(input_value, expected):
                 errors=[
                     {
                         'kind': 'tuple_type',
-                        'loc': ['tuple[int, int, int]'],
-                        'message': 'Value must be a valid tuple',
+                        'loc': ('tuple[int, int, int]',),
+                        'message': 'Value must be a valid tuple, input is of type `list`',
                         'input_value': [5, '1', 1],
+                        'context': {'input_type': 'list'},
                     },
                     {
                         'kind': 'tuple_type',
-                        'loc': ['tuple[str, str, str]'],
-                        'message': 'Value must be a valid tuple',
+                        'loc': ('tuple[str, str, str]',),
+                        'message': 'Value must be a valid tuple, input is of type `list`',
                         'input_value': [5, '1', 1],
+                        'context': {'input_type': 'list'},
                     },
                 ],
             ),
@@ -305,11 +340,39 @@ def test_tuple_fix_error():
 
     assert exc_info.value.errors() == [
         {
-            'kind': 'too_short',
-            'loc': [],
-            'message': 'Input must have at least 2 items',
+            'kind': 'tuple_length_mismatch',
+            'loc': (),
+            'message': 'Tuple must have exactly 2 items, got 1',
             'input_value': [1],
-            'context': {'min_length': 2},
+            'context': {'expected_length': 2, 'input_length': 1},
+        }
+    ]
+
+
+def test_tuple_fix_input_grows():
+    input_value = [1, 2]
+
+    def f(input_value_, **kwargs):
+        input_value.append(input_value_)
+        return input_value_
+
+    v = SchemaValidator(
+        {
+            'type': 'tuple',
+            'mode': 'positional',
+            'items_schema': ['int', {'type': 'function', 'mode': 'plain', 'function': f}],
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'tuple_length_mismatch',
+            'loc': (),
+            'message': 'Tuple must have exactly 2 items, got 3',
+            'input_value': [1, 2, 2],
+            'context': {'expected_length': 2, 'input_length': 3},
         }
     ]
 
@@ -320,8 +383,9 @@ def test_tuple_fix_extra():
     assert v.validate_python((1, 'a')) == (1, 'a')
     assert v.validate_python((1, 'a', 'b')) == (1, 'a', 'b')
     assert v.validate_python([1, 'a', 'b', 'c', 'd']) == (1, 'a', 'b', 'c', 'd')
-    with pytest.raises(ValidationError, match='Input must have at least 2 items'):
+    with pytest.raises(ValidationError, match='Input must have at least 2 items') as exc_info:
         v.validate_python([1])
+    assert exc_info.value.errors()[0]['context'] == {'min_length': 2, 'input_length': 1}
 
 
 def test_tuple_fix_extra_any():
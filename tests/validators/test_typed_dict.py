@@ -63,8 +63,20 @@ def test_strict():
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python({'field_a': 123, 'field_b': '123'})
     assert exc_info.value.errors() == [
-        {'kind': 'str_type', 'loc': ['field_a'], 'message': 'Value must be a valid string', 'input_value': 123},
-        {'kind': 'int_type', 'loc': ['field_b'], 'message': 'Value must be a valid integer', 'input_value': '123'},
+        {
+            'kind': 'str_type',
+            'loc': ('field_a',),
+            'message': 'Value must be a valid string, input is of type `int`',
+            'input_value': 123,
+            'context': {'input_type': 'int'},
+        },
+        {
+            'kind': 'int_type',
+            'loc': ('field_b',),
+            'message': 'Value must be a valid integer, input is of type `str`',
+            'input_value': '123',
+            'context': {'input_type': 'str'},
+        },
     ]
 
 
@@ -198,13 +210,32 @@ def test_allow_extra_validate():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_from_float',
-            'loc': ['other_value'],
+            'loc': ('other_value',),
             'message': 'Value must be a valid integer, got a number with a fractional part',
             'input_value': 12.5,
         }
     ]
 
 
+@pytest.mark.parametrize(
+    'schema_extra,expected',
+    [
+        ({'extra_behavior': 'ignore'}, {'field_a': 'x'}),
+        ({'extra_behavior': 'allow'}, {'field_a': 'x', 'unknown': '42'}),
+        ({'extra_behavior': 'allow', 'extra_validator': 'int'}, {'field_a': 'x', 'unknown': 42}),
+        ({'extra_behavior': 'forbid'}, Err('unknown\n  Extra values are not permitted [kind=extra_forbidden')),
+    ],
+)
+def test_extra_behavior_unexpected_key(py_and_json: PyAndJson, schema_extra, expected):
+    v = py_and_json({'type': 'typed-dict', 'fields': {'field_a': {'schema': 'str'}}, **schema_extra})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test({'field_a': 'x', 'unknown': '42'})
+        assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('extra_forbidden', ('unknown',))]
+    else:
+        assert v.validate_test({'field_a': 'x', 'unknown': '42'}) == expected
+
+
 def test_allow_extra_invalid():
     with pytest.raises(SchemaError, match='extra_validator can only be used if extra_behavior=allow'):
         SchemaValidator(
@@ -212,6 +243,48 @@ def test_allow_extra_invalid():
         )
 
 
+@pytest.mark.parametrize(
+    'extra_behavior,input_value,expected',
+    [
+        ('ignore', {'a': 1}, {'a': 1}),
+        ('ignore', {'a': 1, 'b': 2, 'c': 3}, {'a': 1, 'b': 2}),
+        ('allow', {'a': 1}, {'a': 1}),
+        ('allow', {'a': 1, 'b': 2, 'c': 3}, {'a': 1, 'b': 2, 'c': 3}),
+        ('forbid', {'a': 1}, {'a': 1}),
+        ('forbid', {'a': 1, 'b': 2}, {'a': 1, 'b': 2}),
+        (
+            'forbid',
+            {'a': 1, 'c': 3},
+            Err('Extra values are not permitted [kind=extra_forbidden', [('extra_forbidden', ('c',))]),
+        ),
+    ],
+)
+@pytest.mark.parametrize('missing_required', [False, True])
+def test_extra_behavior_required(extra_behavior, input_value, expected, missing_required):
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'extra_behavior': extra_behavior,
+            'fields': {'a': {'schema': 'int', 'required': True}, 'b': {'schema': 'int', 'required': False}},
+        }
+    )
+    if missing_required:
+        input_value = {k: v for k, v in input_value.items() if k != 'a'}
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(input_value)
+        missing = {'kind': 'missing', 'loc': ('a',), 'message': 'Field required', 'input_value': input_value}
+        assert exc_info.value.errors()[0] == missing
+        if isinstance(expected, Err):
+            assert [(e['kind'], e['loc']) for e in exc_info.value.errors()[1:]] == expected.errors
+    elif isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_python(input_value)
+        assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == expected.errors
+    else:
+        # optional fields which are missing are omitted from the output
+        assert v.validate_python(input_value) == expected
+
+
 def test_allow_extra_wrong():
     with pytest.raises(SchemaError, match='Invalid extra_behavior: "wrong"'):
         SchemaValidator({'type': 'typed-dict', 'fields': {}}, {'typed_dict_extra_behavior': 'wrong'})
@@ -237,6 +310,117 @@ def test_validate_assignment():
     assert v.validate_assignment('field_a', 456, {'field_a': 'test'}) == ({'field_a': '456'}, {'field_a'})
 
 
+def test_validate_assignment_frozen():
+    calls = []
+
+    def f(input_value, **kwargs):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'field_a': {'schema': 'str'},
+                'field_b': {'schema': {'type': 'function', 'mode': 'plain', 'function': f}, 'frozen': True},
+            },
+        }
+    )
+    assert v.validate_python({'field_a': 'test', 'field_b': 1}) == {'field_a': 'test', 'field_b': 1}
+    assert calls == [1]
+
+    data = {'field_a': 'test', 'field_b': 1}
+    assert v.validate_assignment('field_a', 456, data) == {'field_a': '456', 'field_b': 1}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment('field_b', 2, data)
+    assert exc_info.value.errors() == [
+        {'kind': 'frozen_field', 'loc': ('field_b',), 'message': 'Field is frozen', 'input_value': 2}
+    ]
+    # the field's validator isn't called and the data is unchanged
+    assert calls == [1]
+    assert data == {'field_a': '456', 'field_b': 1}
+
+
+def test_field_strict():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'id': {'schema': 'int', 'strict': True}, 'age': {'schema': 'int'}},
+        }
+    )
+    assert v.validate_python({'id': 1, 'age': '42'}) == {'id': 1, 'age': 42}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'id': '1', 'age': '42'})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_type',
+            'loc': ('id',),
+            'message': 'Value must be a valid integer, input is of type `str`',
+            'input_value': '1',
+            'context': {'input_type': 'str'},
+        }
+    ]
+    assert v.validate_json('{"id": 1, "age": "42"}') == {'id': 1, 'age': 42}
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        v.validate_json('{"id": "1", "age": "42"}')
+
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        v.validate_assignment('id', '2', {'id': 1, 'age': 42})
+    assert v.validate_assignment('age', '43', {'id': 1, 'age': 42}) == {'id': 1, 'age': 43}
+
+
+def test_field_strict_overrides():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'id': {'schema': 'int', 'strict': False}, 'age': {'schema': 'int'}},
+        }
+    )
+    assert v.validate_python({'id': '1', 'age': 42}) == {'id': 1, 'age': 42}
+    # strict passed to the validation call takes precedence over the field setting
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'id': '1', 'age': '42'}, strict=True)
+    assert [(e['kind'], e['loc']) for e in exc_info.value.errors()] == [('int_type', ('id',)), ('int_type', ('age',))]
+
+
+def test_field_strict_call_lax():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'id': {'schema': 'int', 'strict': True}, 'age': {'schema': 'int'}},
+        }
+    )
+    with pytest.raises(ValidationError, match='kind=int_type'):
+        v.validate_python({'id': '1', 'age': '42'})
+    assert v.validate_python({'id': '1', 'age': '42'}, strict=False) == {'id': 1, 'age': 42}
+
+
+def test_validate_assignment_nested():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'field_a': {'schema': 'str'},
+                'sub': {'schema': {'type': 'typed-dict', 'fields': {'field_b': {'schema': 'int'}}}},
+            },
+        }
+    )
+    data = {'field_a': 'test', 'sub': {'field_b': 1}}
+    assert v.validate_assignment('sub', {'field_b': '2'}, data) == {'field_a': 'test', 'sub': {'field_b': 2}}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment('sub', {'field_b': 'x'}, data)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ('sub', 'field_b'),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'x',
+        }
+    ]
+
+
 def test_validate_assignment_functions():
     calls = []
 
@@ -291,7 +475,7 @@ def test_validate_assignment_ignore_extra():
     assert exc_info.value.errors() == [
         {
             'kind': 'extra_forbidden',
-            'loc': ['other_field'],
+            'loc': ('other_field',),
             'message': 'Extra values are not permitted',
             'input_value': 456,
         }
@@ -325,7 +509,7 @@ def test_validate_assignment_allow_extra_validate():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['other_field'],
+            'loc': ('other_field',),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'xyz',
         }
@@ -342,7 +526,7 @@ def test_json_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['field_a', 1],
+            'loc': ('field_a', 1),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'wrong',
         }
@@ -366,7 +550,7 @@ def test_fields_required_by_default():
         assert v.validate_python({'x': 'pika'})
 
     assert exc_info.value.errors() == [
-        {'kind': 'missing', 'loc': ['y'], 'message': 'Field required', 'input_value': {'x': 'pika'}}
+        {'kind': 'missing', 'loc': ('y',), 'message': 'Field required', 'input_value': {'x': 'pika'}}
     ]
 
 
@@ -415,7 +599,13 @@ def test_all_optional_fields():
         assert v.validate_python({'x': 123})
 
     assert exc_info.value.errors() == [
-        {'kind': 'str_type', 'loc': ['x'], 'message': 'Value must be a valid string', 'input_value': 123}
+        {
+            'kind': 'str_type',
+            'loc': ('x',),
+            'message': 'Value must be a valid string, input is of type `int`',
+            'input_value': 123,
+            'context': {'input_type': 'int'},
+        }
     ]
 
 
@@ -439,7 +629,7 @@ def test_all_optional_fields_with_required_fields():
         assert v.validate_python({'y': 'chu'}) == ({'y': 'chu'}, {'y'})
 
     assert exc_info.value.errors() == [
-        {'kind': 'missing', 'loc': ['x'], 'message': 'Field required', 'input_value': {'y': 'chu'}}
+        {'kind': 'missing', 'loc': ('x',), 'message': 'Field required', 'input_value': {'y': 'chu'}}
     ]
 
 
@@ -489,6 +679,50 @@ def test_alias_allow_pop(py_and_json: PyAndJson):
         assert v.validate_test({'foobar': '123'})
 
 
+@pytest.mark.parametrize('extra_behavior', ['ignore', 'allow', 'forbid'])
+def test_populate_by_name_alias_and_name(py_and_json: PyAndJson, extra_behavior):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'populate_by_name': True,
+            'extra_behavior': extra_behavior,
+            'fields': {'field_a': {'alias': 'FieldA', 'schema': 'int'}},
+        }
+    )
+    # the alias takes precedence, the field name is ignored rather than being treated as an extra key
+    assert v.validate_test({'FieldA': '1', 'field_a': '2'}) == {'field_a': 1}
+    assert v.validate_test({'field_a': '2'}) == {'field_a': 2}
+
+
+@pytest.mark.parametrize(
+    'populate_by_name,input_value,expected',
+    [
+        (False, {'FieldA': '1'}, {'field_a': 1}),
+        (False, {'field-a': '2'}, {'field_a': 2}),
+        # aliases are tried in order
+        (False, {'field-a': '2', 'FieldA': '1'}, {'field_a': 1}),
+        (False, {'field_a': '3'}, Err(r'field_a\n +Field required \[kind=missing,')),
+        # the field name is tried after all aliases
+        (True, {'field_a': '3'}, {'field_a': 3}),
+        (True, {'field_a': '3', 'field-a': '2'}, {'field_a': 2}),
+    ],
+)
+def test_alias_choices(py_and_json: PyAndJson, populate_by_name, input_value, expected):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'populate_by_name': populate_by_name,
+            'fields': {'field_a': {'alias': [['FieldA'], ['field-a']], 'schema': 'int'}},
+        }
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message):
+            v.validate_test(input_value)
+    else:
+        # the output key is always the field name
+        assert v.validate_test(input_value) == expected
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -649,7 +883,7 @@ def test_model_deep():
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['field_b', 'field_d', 'field_f'],
+            'loc': ('field_b', 'field_d', 'field_f'),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'xx',
         }
@@ -707,6 +941,43 @@ def test_from_attributes(input_value, expected):
         assert output == expected
 
 
+class ClassWithSlots:
+    __slots__ = 'a', 'b', 'c'
+
+    def __init__(self, **kwargs):
+        for k, v in kwargs.items():
+            setattr(self, k, v)
+
+
+def test_from_attributes_slots():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'return_fields_set': True,
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'int'},
+                'c': {'schema': {'type': 'default', 'schema': 'str', 'default': 'spam'}},
+            },
+            'from_attributes': True,
+        }
+    )
+    assert v.validate_python(ClassWithSlots(a=1, b='2', c='ham')) == ({'a': 1, 'b': 2, 'c': 'ham'}, {'a', 'b', 'c'})
+    # unset slots raise AttributeError so behave like missing keys
+    assert v.validate_python(ClassWithSlots(a=1, b=2)) == ({'a': 1, 'b': 2, 'c': 'spam'}, {'a', 'b'})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ClassWithSlots(b=2))
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'missing',
+            'loc': ('a',),
+            'message': 'Field required',
+            'input_value': HasRepr(IsStr(regex='.+ClassWithSlots object at.+')),
+        }
+    ]
+
+
 def test_from_attributes_type_error():
     v = SchemaValidator(
         {
@@ -721,9 +992,10 @@ def test_from_attributes_type_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'dict_attributes_type',
-            'loc': [],
-            'message': 'Value must be a valid dictionary or instance to extract fields from',
+            'loc': (),
+            'message': 'Value must be a valid dictionary or instance to extract fields from, input is of type `str`',
             'input_value': '123',
+            'context': {'input_type': 'str'},
         }
     ]
 
@@ -762,7 +1034,7 @@ def test_from_attributes_missing():
     assert exc_info.value.errors() == [
         {
             'kind': 'missing',
-            'loc': ['c'],
+            'loc': ('c',),
             'message': 'Field required',
             'input_value': HasRepr(IsStr(regex='.+Foobar object at.+')),
         }
@@ -788,7 +1060,7 @@ def test_from_attributes_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'get_attribute_error',
-            'loc': ['b'],
+            'loc': ('b',),
             'message': 'Error extracting attribute: RuntimeError: intentional error',
             'input_value': HasRepr(IsStr(regex='.+Foobar object at.+')),
             'context': {'error': 'RuntimeError: intentional error'},
@@ -895,7 +1167,7 @@ def test_from_attributes_error_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'get_attribute_error',
-            'loc': ['x'],
+            'loc': ('x',),
             'message': IsStr(regex=r'Error extracting attribute: \S+\.<locals>\.BadError: <exception str\(\) failed>'),
             'input_value': HasRepr(IsStr(regex='.+Foobar object at.+')),
             'context': {'error': IsStr(regex=r'\S+\.<locals>\.BadError: <exception str\(\) failed>')},
@@ -913,7 +1185,7 @@ def test_from_attributes_error_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'get_attribute_error',
-            'loc': ['x'],
+            'loc': ('x',),
             'message': 'Error extracting attribute: RuntimeError',
             'input_value': HasRepr(IsStr(regex='.+UnInitError object at.+')),
             'context': {'error': 'RuntimeError'},
@@ -975,7 +1247,7 @@ def test_from_attributes_path_error():
     assert exc_info.value.errors() == [
         {
             'kind': 'get_attribute_error',
-            'loc': ['my_field'],
+            'loc': ('my_field',),
             'message': 'Error extracting attribute: RuntimeError: intentional error',
             'input_value': HasRepr(IsStr(regex='.+PropertyError object at.+')),
             'context': {'error': 'RuntimeError: intentional error'},
@@ -1001,7 +1273,7 @@ def test_alias_extra(py_and_json: PyAndJson):
     assert exc_info.value.errors() == [
         {
             'kind': 'int_parsing',
-            'loc': ['field_a'],
+            'loc': ('field_a',),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': '...',
         }
@@ -1092,3 +1364,112 @@ def test_bad_default_factory(default_factory, error_message):
     )
     with pytest.raises(TypeError, match=re.escape(error_message)):
         v.validate_python({})
+
+
+def test_default_factory_takes_data():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'start': {'schema': 'int'},
+                'end': {
+                    'schema': 'int',
+                    'default_factory': lambda data: data['start'] + 1,
+                    'default_factory_takes_data': True,
+                },
+            },
+        }
+    )
+    assert v.validate_python({'start': '1'}) == {'start': 1, 'end': 2}
+    assert v.validate_python({'start': 1, 'end': 5}) == {'start': 1, 'end': 5}
+
+
+def test_default_factory_takes_data_field_order():
+    calls = []
+
+    def factory(*, data):
+        calls.append(dict(data))
+        return 'default'
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'str', 'default_factory': factory, 'default_factory_takes_data': True},
+                'c': {'schema': 'int'},
+            },
+        }
+    )
+    assert v.validate_python({'a': 1, 'c': 2}) == {'a': 1, 'b': 'default', 'c': 2}
+    # fields are validated in order, so only earlier fields are available, and invalid fields are omitted
+    with pytest.raises(ValidationError):
+        v.validate_python({'a': 'x', 'c': 2})
+    assert calls == [{'a': 1}, {}]
+
+
+def test_default_factory_takes_data_mutated():
+    def factory(*, data):
+        data['a'] = 'changed'
+        data['c'] = 3
+        return 'default'
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'str', 'default_factory': factory, 'default_factory_takes_data': True},
+                'c': {
+                    'schema': {
+                        'type': 'default',
+                        'schema': 'str',
+                        'default_factory': factory,
+                        'default_factory_takes_data': True,
+                    }
+                },
+            },
+        }
+    )
+    # the factory gets a copy of the data, so the output isn't changed
+    assert v.validate_python({'a': 1}) == {'a': 1, 'b': 'default', 'c': 'default'}
+
+
+def test_default_factory_takes_data_default_schema():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'start': {'schema': 'int'},
+                'end': {
+                    'schema': {
+                        'type': 'default',
+                        'schema': 'int',
+                        'default_factory': lambda data: data['start'] * 2,
+                        'default_factory_takes_data': True,
+                        'validate_default': True,
+                    }
+                },
+            },
+        }
+    )
+    assert v.validate_python({'start': 3}) == {'start': 3, 'end': 6}
+
+
+def test_fields_set_excludes_defaults(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'return_fields_set': True,
+            'fields': {
+                'a': {'schema': 'int'},
+                'b': {'schema': 'int', 'default': 2},
+                'c': {'schema': 'list', 'default_factory': list},
+                'd': {'schema': 'int', 'required': False},
+            },
+        }
+    )
+    assert v.validate_test({'a': 1}) == ({'a': 1, 'b': 2, 'c': []}, {'a'})
+    assert v.validate_test({'a': 1, 'b': 3, 'd': 4}) == ({'a': 1, 'b': 3, 'c': [], 'd': 4}, {'a', 'b', 'd'})
+    # explicitly providing the default value still counts as set
+    assert v.validate_test({'a': 1, 'b': 2, 'c': []}) == ({'a': 1, 'b': 2, 'c': []}, {'a', 'b', 'c'})
@@ -101,9 +101,9 @@ class TestModelClass:
         with pytest.raises(ValidationError) as exc_info:
             schema_validator.validate_python({'a': 2})
         assert exc_info.value.errors() == [
-            {'kind': 'missing', 'loc': ['ModelA', 'b'], 'message': 'Field required', 'input_value': {'a': 2}},
-            {'kind': 'missing', 'loc': ['ModelB', 'c'], 'message': 'Field required', 'input_value': {'a': 2}},
-            {'kind': 'missing', 'loc': ['ModelB', 'd'], 'message': 'Field required', 'input_value': {'a': 2}},
+            {'kind': 'missing', 'loc': ('ModelA', 'b'), 'message': 'Field required', 'input_value': {'a': 2}},
+            {'kind': 'missing', 'loc': ('ModelB', 'c'), 'message': 'Field required', 'input_value': {'a': 2}},
+            {'kind': 'missing', 'loc': ('ModelB', 'd'), 'message': 'Field required', 'input_value': {'a': 2}},
         ]
 
 
@@ -180,10 +180,10 @@ def test_nullable_via_union():
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python('hello')
     assert exc_info.value.errors() == [
-        {'kind': 'none_required', 'loc': ['none'], 'message': 'Value must be None/null', 'input_value': 'hello'},
+        {'kind': 'none_required', 'loc': ('none',), 'message': 'Value must be None/null', 'input_value': 'hello'},
         {
             'kind': 'int_parsing',
-            'loc': ['int'],
+            'loc': ('int',),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'hello',
         },
@@ -208,13 +208,13 @@ def test_union_list_bool_int():
     assert exc_info.value.errors() == [
         {
             'kind': 'bool_parsing',
-            'loc': ['list[bool]', 0],
+            'loc': ('list[bool]', 0),
             'message': 'Value must be a valid boolean, unable to interpret input',
             'input_value': 3,
         },
         {
             'kind': 'int_parsing',
-            'loc': ['list[int]', 1],
+            'loc': ('list[int]', 1),
             'message': 'Value must be a valid integer, unable to parse string as an integer',
             'input_value': 'true',
         },
@@ -241,6 +241,102 @@ def test_strict_union():
         v.validate_python('123')
 
     assert exc_info.value.errors() == [
-        {'kind': 'bool_type', 'loc': ['bool'], 'message': 'Value must be a valid boolean', 'input_value': '123'},
-        {'kind': 'int_type', 'loc': ['int'], 'message': 'Value must be a valid integer', 'input_value': '123'},
+        {
+            'kind': 'bool_type',
+            'loc': ('bool',),
+            'message': 'Value must be a valid boolean, input is of type `str`',
+            'input_value': '123',
+            'context': {'input_type': 'str'},
+        },
+        {
+            'kind': 'int_type',
+            'loc': ('int',),
+            'message': 'Value must be a valid integer, input is of type `str`',
+            'input_value': '123',
+            'context': {'input_type': 'str'},
+        },
     ]
+
+
+@pytest.mark.parametrize(
+    'mode,input_value,expected',
+    [
+        ('smart', 'hello', 'hello'),
+        ('left_to_right', 'hello', 'hello'),
+        ('smart', 123, 123),
+        ('left_to_right', 123, 123),
+        # smart mode prefers the exact type match of `str`, left_to_right returns the first choice which succeeds
+        ('smart', '123', '123'),
+        ('left_to_right', '123', 123),
+    ],
+)
+def test_union_mode(mode, input_value, expected):
+    v = SchemaValidator({'type': 'union', 'mode': mode, 'choices': [{'type': 'int'}, {'type': 'str'}]})
+    assert v.validate_python(input_value) == expected
+
+
+def test_left_to_right_second_choice():
+    v = SchemaValidator(
+        {'type': 'union', 'mode': 'left_to_right', 'choices': [{'type': 'int', 'gt': 10}, {'type': 'bool'}]}
+    )
+    assert v.validate_python(11) == 11
+    assert v.validate_python(1) is True
+    assert v.validate_python('yes') is True
+
+
+def test_left_to_right_errors():
+    v = SchemaValidator(
+        {'type': 'union', 'mode': 'left_to_right', 'choices': [{'type': 'int'}, {'type': 'bool'}, {'type': 'int'}]}
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('hello')
+
+    # the location is the index of the choice, not its name
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': (0,),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'hello',
+        },
+        {
+            'kind': 'bool_parsing',
+            'loc': (1,),
+            'message': 'Value must be a valid boolean, unable to interpret input',
+            'input_value': 'hello',
+        },
+        {
+            'kind': 'int_parsing',
+            'loc': (2,),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'hello',
+        },
+    ]
+
+
+def test_left_to_right_strict():
+    v = SchemaValidator({'type': 'union', 'mode': 'left_to_right', 'choices': [{'type': 'int'}, {'type': 'str'}]})
+    assert v.validate_python('123', strict=True) == '123'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(b'123', strict=True)
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_type',
+            'loc': (0,),
+            'message': 'Value must be a valid integer, input is of type `bytes`',
+            'input_value': b'123',
+            'context': {'input_type': 'bytes'},
+        },
+        {
+            'kind': 'str_type',
+            'loc': (1,),
+            'message': 'Value must be a valid string, input is of type `bytes`',
+            'input_value': b'123',
+            'context': {'input_type': 'bytes'},
+        },
+    ]
+
+
+def test_invalid_mode():
+    with pytest.raises(SchemaError, match='union -> mode'):
+        SchemaValidator({'type': 'union', 'mode': 'wrong', 'choices': [{'type': 'int'}]})
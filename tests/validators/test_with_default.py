@@ -0,0 +1,134 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError
+
+from ..conftest import PyAndJson
+
+
+def test_typed_dict_default():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {'schema': 'str'},
+                'y': {'schema': {'type': 'default', 'schema': 'str', 'default': '[default]'}},
+            },
+        }
+    )
+    assert v.validate_python({'x': 'x', 'y': 'y'}) == {'x': 'x', 'y': 'y'}
+    assert v.validate_python({'x': 'x'}) == {'x': 'x', 'y': '[default]'}
+
+
+def test_typed_dict_default_factory():
+    calls = []
+
+    def factory():
+        calls.append(1)
+        return [len(calls)]
+
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'x': {'schema': {'type': 'default', 'schema': 'list', 'default_factory': factory}}},
+        }
+    )
+    assert v.validate_python({}) == {'x': [1]}
+    assert v.validate_python({}) == {'x': [2]}
+    assert v.validate_python({'x': [42]}) == {'x': [42]}
+    assert len(calls) == 2
+
+
+def test_default_not_validated():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {'x': {'schema': {'type': 'default', 'schema': 'int', 'default': 'not an int'}}},
+        }
+    )
+    assert v.validate_python({}) == {'x': 'not an int'}
+
+
+def test_validate_default(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {'schema': {'type': 'default', 'schema': 'int', 'default': '42', 'validate_default': True}}
+            },
+        }
+    )
+    assert v.validate_test({}) == {'x': 42}
+    assert v.validate_test({'x': '1'}) == {'x': 1}
+
+
+def test_validate_default_error():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'x': {'schema': {'type': 'default', 'schema': 'int', 'default': 'wrong', 'validate_default': True}}
+            },
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors() == [
+        {
+            'kind': 'int_parsing',
+            'loc': ('x',),
+            'message': 'Value must be a valid integer, unable to parse string as an integer',
+            'input_value': 'wrong',
+        }
+    ]
+
+
+def test_on_none(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'default', 'schema': 'int', 'default': 42, 'on_none': True})
+    assert v.validate_test(None) == 42
+    assert v.validate_test(1) == 1
+
+    v = py_and_json({'type': 'default', 'schema': 'int', 'default': 42})
+    assert v.validate_test(1) == 1
+    with pytest.raises(ValidationError, match='Value must be a valid integer'):
+        v.validate_test(None)
+
+
+def test_on_none_validate_default_factory():
+    v = SchemaValidator(
+        {
+            'type': 'default',
+            'schema': {'type': 'list', 'items_schema': 'int'},
+            'default_factory': lambda: ['1', '2'],
+            'on_none': True,
+            'validate_default': True,
+        }
+    )
+    assert v.validate_python(None) == [1, 2]
+
+
+def test_repr():
+    v = SchemaValidator({'type': 'default', 'schema': 'int', 'default': 42})
+    assert repr(v).startswith('SchemaValidator(name="default[int]"')
+
+
+def test_default_and_factory():
+    with pytest.raises(SchemaError, match="'default' and 'default_factory' cannot be used together"):
+        SchemaValidator({'type': 'default', 'schema': 'int', 'default': 1, 'default_factory': lambda: 1})
+
+
+def test_no_default():
+    with pytest.raises(SchemaError, match="'default' or 'default_factory' must be set"):
+        SchemaValidator({'type': 'default', 'schema': 'int'})
+
+
+def test_default_factory_takes_data_no_data():
+    v = SchemaValidator(
+        {
+            'type': 'default',
+            'schema': 'int',
+            'default_factory': lambda data: 0 if data is None else 1,
+            'default_factory_takes_data': True,
+            'on_none': True,
+        }
+    )
+    assert v.validate_python(None) == 0